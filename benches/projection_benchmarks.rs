@@ -1,5 +1,5 @@
-use criterion::{Criterion, criterion_group, criterion_main};
-use plane_projection::PlaneProjection;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use plane_projection::{PlaneProjection, PlaneProjectionF32, ProjectedPolyline};
 use std::hint::black_box;
 
 fn bench_distance(c: &mut Criterion) {
@@ -85,10 +85,136 @@ fn bench_heading(c: &mut Criterion) {
     });
 }
 
+fn bench_polyline_length(c: &mut Criterion) {
+    let line = (0..1000)
+        .map(|i| {
+            (
+                55.60 + i as f64 * 0.001,
+                13.5 + (i as f64 * 0.1).sin() * 0.01,
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+
+    c.bench_function("polyline length", |b| {
+        let projection = PlaneProjection::new(55.65);
+        b.iter(|| black_box(projection.polyline_length(black_box(&line))));
+    });
+}
+
+fn bench_distance_matrix(c: &mut Criterion) {
+    let points = (0..100)
+        .map(|i| {
+            let i = i as f64;
+            (
+                55.60 + (i * 0.618).fract() * 0.1,
+                13.5 + (i * 0.414).fract() * 0.1,
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+
+    c.bench_function("distance matrix 100x100", |b| {
+        let projection = PlaneProjection::new(55.65);
+        b.iter(|| black_box(projection.distance_matrix(black_box(&points), black_box(&points))));
+    });
+}
+
+fn bench_distances(c: &mut Criterion) {
+    let points = (0..10_000)
+        .map(|i| {
+            let i = i as f64;
+            (
+                55.60 + (i * 0.618).fract() * 0.1,
+                13.5 + (i * 0.414).fract() * 0.1,
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+    let mut out = vec![0.0; points.len()];
+
+    // the batch kernels next to the plain loop they should beat
+    let mut group = c.benchmark_group("distances 10k");
+    group.throughput(Throughput::Elements(points.len() as u64));
+    let projection = PlaneProjection::new(55.65);
+    group.bench_function("batch", |b| {
+        b.iter(|| {
+            projection.distances(black_box((55.65, 13.55)), black_box(&points), &mut out);
+            black_box(&out);
+        });
+    });
+
+    let lats = points.iter().map(|p| p.0).collect::<Vec<_>>();
+    let lons = points.iter().map(|p| p.1).collect::<Vec<_>>();
+    group.bench_function("columnar", |b| {
+        b.iter(|| {
+            projection.distances_columnar(
+                black_box((55.65, 13.55)),
+                black_box(&lats),
+                black_box(&lons),
+                &mut out,
+            );
+            black_box(&out);
+        });
+    });
+
+    group.bench_function("distance in loop", |b| {
+        b.iter(|| {
+            let origin = black_box((55.65, 13.55));
+            for (point, out) in black_box(&points).iter().zip(out.iter_mut()) {
+                *out = projection.distance(origin, *point);
+            }
+            black_box(&out);
+        });
+    });
+    group.finish();
+
+    let points_f32 = points
+        .iter()
+        .map(|&(lat, lon)| (lat as f32, lon as f32))
+        .collect::<Vec<_>>();
+    let mut out_f32 = vec![0.0; points.len()];
+    c.bench_function("distances f32 10k", |b| {
+        let projection = PlaneProjectionF32::from(PlaneProjection::new(55.65));
+        b.iter(|| {
+            projection.distances(
+                black_box((55.65, 13.55)),
+                black_box(&points_f32),
+                &mut out_f32,
+            );
+            black_box(&out_f32);
+        });
+    });
+}
+
+fn bench_nearest_segment(c: &mut Criterion) {
+    let line = (0..10_000)
+        .map(|i| {
+            (
+                55.60 + i as f64 * 0.0001,
+                13.5 + (i as f64 * 0.1).sin() * 0.01,
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+    let point = (55.9, 13.51);
+
+    c.bench_function("point on line 10k", |b| {
+        let projection = PlaneProjection::new(55.65);
+        b.iter(|| black_box(projection.point_on_line(black_box(point), black_box(&line))));
+    });
+
+    c.bench_function("projected polyline nearest segment 10k", |b| {
+        let projection = PlaneProjection::new(55.65);
+        let projected = ProjectedPolyline::new(&projection, &line);
+        b.iter(|| black_box(projected.nearest_segment(black_box(point))));
+    });
+}
+
 criterion_group!(
     benches,
     bench_distance,
     bench_distance_to_segment,
     bench_heading,
+    bench_polyline_length,
+    bench_distance_matrix,
+    bench_distances,
+    bench_nearest_segment,
 );
 criterion_main!(benches);
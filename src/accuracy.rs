@@ -0,0 +1,273 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{BBox, Ellipsoid, Float, LatLon, PlaneProjection, haversine_distance};
+
+/// Distances in meters between the same pair of points calculated by different methods,
+/// to audit the plane projection accuracy on real data, see [`PlaneProjection::compare_distance()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DistanceComparison {
+    /// Distance calculated by [`PlaneProjection::distance()`].
+    pub plane: f64,
+    /// Great-circle distance calculated by [`haversine_distance()`].
+    pub haversine: f64,
+    /// Exact geodesic distance calculated by [`geodesic_distance()`](crate::geodesic_distance).
+    #[cfg(feature = "geodesic")]
+    pub geodesic: f64,
+}
+
+impl DistanceComparison {
+    /// The most accurate of the calculated distances, which is the geodesic one if the `geodesic` feature
+    /// is enabled and the haversine one otherwise.
+    #[inline(always)]
+    pub fn reference(&self) -> f64 {
+        #[cfg(feature = "geodesic")]
+        return self.geodesic;
+        #[cfg(not(feature = "geodesic"))]
+        return self.haversine;
+    }
+
+    /// Relative difference between the plane projection distance and the [`DistanceComparison::reference()`] one,
+    /// so `0.001` means that the plane projection distance is 0.1% longer.
+    #[inline(always)]
+    pub fn plane_error(&self) -> f64 {
+        relative_difference(self.plane, self.reference())
+    }
+
+    /// Relative difference between the haversine distance and the geodesic one.
+    #[cfg(feature = "geodesic")]
+    #[inline(always)]
+    pub fn haversine_error(&self) -> f64 {
+        relative_difference(self.haversine, self.geodesic)
+    }
+}
+
+/// Relative difference between `value` and a non-negative `reference`, which is 0.0 if both are zero.
+#[inline(always)]
+fn relative_difference(value: f64, reference: f64) -> f64 {
+    if value == reference {
+        0.0
+    } else {
+        (value - reference) / reference
+    }
+}
+
+impl<T: Float> PlaneProjection<T> {
+    /// Radius in meters around any point at the projection latitude, within which the distances between points
+    /// stay under the `target_rel_error` relative error according to [`PlaneProjection::error_estimate()`],
+    /// so `0.001` means 0.1%. Useful to assert that query radii stay within the projection comfort zone.
+    /// Returns 0.0 at the poles. The `Debug` output of the projection includes this radius for 0.1%.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let radius = proj.recommended_max_distance(0.001);
+    /// assert!(radius > 4_000.0 && radius < 5_000.0);
+    /// assert!(PlaneProjection::new(0.0).recommended_max_distance(0.001) > 100_000.0);
+    /// ```
+    pub fn recommended_max_distance(&self, target_rel_error: f64) -> f64 {
+        let latitude = self.latitude.to_f64();
+        let lat_scale = self.lat_scale.to_f64();
+        // Both points are within `radius` from the center, so the distance between them is up to `2 * radius`
+        // and their midpoint is up to `radius` away from the projection latitude
+        let error = |radius: f64| {
+            PlaneProjection::error_estimate(latitude, radius / lat_scale, 2.0 * radius)
+        };
+
+        // The error grows monotonically with the radius, so the bisection converges to the boundary
+        let (mut low, mut high) = (0.0, Ellipsoid::SPHERE.equatorial_radius);
+        if error(high) <= target_rel_error {
+            return high;
+        }
+        for _ in 0..64 {
+            let middle = (low + high) * 0.5;
+            if error(middle) <= target_rel_error {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+}
+
+impl PlaneProjection {
+    /// Calculates the distance between the points with the plane projection, haversine and, if the `geodesic`
+    /// feature is enabled, the exact geodesic methods, together with their relative differences.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let comparison = proj.compare_distance((55.7041417, 13.1913041), (55.6033090, 13.0019737));
+    /// assert_eq!(comparison.plane, proj.distance((55.7041417, 13.1913041), (55.6033090, 13.0019737)));
+    /// assert!(comparison.plane_error().abs() < 0.005);
+    /// ```
+    pub fn compare_distance(&self, a: LatLon, b: LatLon) -> DistanceComparison {
+        DistanceComparison {
+            plane: self.distance(a, b),
+            haversine: haversine_distance(a, b),
+            #[cfg(feature = "geodesic")]
+            geodesic: crate::geodesic_distance(a, b),
+        }
+    }
+
+    /// Estimates the worst-case relative error of [`PlaneProjection::distance()`] between any points within
+    /// the `bbox`, see [`PlaneProjection::error_estimate()`].
+    ///
+    /// ```
+    /// use plane_projection::{BBox, PlaneProjection};
+    ///
+    /// let bbox = BBox { min: (55.0, 12.0), max: (56.0, 14.0) };
+    /// // 1.3% for the centered projection and twice as much for the one at the box edge
+    /// assert!(PlaneProjection::new(55.5).bbox_error_estimate(bbox) < 0.015);
+    /// assert!(PlaneProjection::new(55.0).bbox_error_estimate(bbox) > 0.025);
+    /// ```
+    pub fn bbox_error_estimate(&self, bbox: BBox) -> f64 {
+        // Midpoints of the point pairs are anywhere in the latitude extent of the box
+        let lat_offset = (bbox.min.0 - self.latitude)
+            .abs()
+            .max((bbox.max.0 - self.latitude).abs());
+        let (width, height) = (self.bbox_width(bbox), self.bbox_height(bbox));
+        Self::error_estimate(
+            self.latitude,
+            lat_offset,
+            (width * width + height * height).sqrt(),
+        )
+    }
+
+    /// Estimates the worst-case relative error of [`PlaneProjection::distance()`] versus the geodesic distance,
+    /// so `0.001` means 0.1%. Useful to decide at runtime when to fall back to a slower but exact method.
+    ///
+    /// - `latitude` - latitude in degrees the projection was created for,
+    /// - `lat_offset` - difference in degrees between `latitude` and the latitude of the measured points midpoint,
+    /// - `distance` - distance in meters between the measured points.
+    ///
+    /// The error consists of the longitude scale mismatch when the points are far from the projection latitude
+    /// and the difference between the plane and the curved surface that grows with the distance.
+    /// Returns infinity at the poles.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// // The projection is created for the midpoint latitude, as recommended
+    /// assert!(PlaneProjection::error_estimate(65.0, 0.0, 500_000.0) < 0.002);
+    /// // But not when it is reused for points 2 degrees to the North
+    /// assert!(PlaneProjection::error_estimate(65.0, 2.0, 500_000.0) > 0.05);
+    /// ```
+    pub fn error_estimate(latitude: f64, lat_offset: f64, distance: f64) -> f64 {
+        let (sin_lat, cos_lat) = latitude.to_radians().sin_cos();
+        let (sin_offset, cos_offset) = lat_offset.to_radians().sin_cos();
+        if cos_lat.abs() < f64::EPSILON {
+            return f64::INFINITY;
+        }
+        let tan_lat = sin_lat / cos_lat;
+
+        // Longitude scale is proportional to cos(lat) and
+        // cos(lat + offset) / cos(lat) = cos(offset) - tan(lat) * sin(offset)
+        let scale_error = tan_lat.abs() * sin_offset.abs() + (1.0 - cos_offset);
+
+        // Parallels are not the shortest paths and the scale changes along the segment,
+        // both are quadratic in the angular distance
+        let angle = distance / Ellipsoid::SPHERE.equatorial_radius;
+        let curvature_error = angle * angle * (1.0 + tan_lat * tan_lat) / 24.0;
+
+        scale_error + curvature_error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    #[test]
+    fn recommended_max_distance_test() {
+        let proj = PlaneProjection::new(55.65);
+        let radius = proj.recommended_max_distance(0.001);
+        let estimate = |radius: f64| {
+            PlaneProjection::error_estimate(55.65, radius / proj.lat_scale(), 2.0 * radius)
+        };
+        assert!((estimate(radius) - 0.001).abs() < 1e-9);
+        assert!(proj.recommended_max_distance(0.0001) < radius);
+        assert_eq!(proj.recommended_max_distance(0.0), 0.0);
+        assert_eq!(
+            proj.cast::<f32>().recommended_max_distance(0.001) as u32,
+            radius as u32
+        );
+        assert_eq!(
+            PlaneProjection::new(90.0).recommended_max_distance(0.001),
+            0.0
+        );
+
+        // the same comfort zone to the North and to the South
+        assert!(
+            (PlaneProjection::new(-55.65).recommended_max_distance(0.001) - radius).abs() < 1e-6
+        );
+        assert!(PlaneProjection::new(65.0).recommended_max_distance(0.001) < radius);
+        assert!(PlaneProjection::new(0.0).recommended_max_distance(0.001) > radius);
+    }
+
+    #[test]
+    fn compare_distance_test() {
+        let proj = PlaneProjection::new(55.65);
+        let comparison = proj.compare_distance(MALMO_C, MALMO_C);
+        assert_eq!(comparison.plane, 0.0);
+        assert_eq!(comparison.plane_error(), 0.0);
+
+        let comparison = proj.compare_distance(MALMO_C, LUND_C);
+        assert_eq!(comparison.plane, proj.distance(MALMO_C, LUND_C));
+        assert_eq!(comparison.haversine, haversine_distance(MALMO_C, LUND_C));
+        assert_eq!(
+            comparison.plane_error(),
+            (comparison.plane - comparison.reference()) / comparison.reference()
+        );
+
+        // the projection far from the points
+        let comparison = PlaneProjection::new(0.0).compare_distance(MALMO_C, STOCKHOLM_C);
+        assert!(comparison.plane_error() > 0.3);
+
+        #[cfg(feature = "geodesic")]
+        {
+            let comparison = proj.compare_distance(MALMO_C, LUND_C);
+            assert_eq!(comparison.reference(), comparison.geodesic);
+            // the plane projection is more accurate than haversine for local pairs
+            assert!(comparison.plane_error().abs() < 1e-4);
+            assert!(comparison.haversine_error().abs() > 1e-3);
+        }
+    }
+
+    #[test]
+    fn error_estimate_test() {
+        assert!(PlaneProjection::error_estimate(55.65, 0.0, 16_374.0) < 1e-5);
+        assert_eq!(
+            PlaneProjection::error_estimate(90.0, 0.0, 1.0),
+            f64::INFINITY
+        );
+        assert_eq!(PlaneProjection::error_estimate(0.0, 0.0, 0.0), 0.0);
+        // symmetric
+        assert_eq!(
+            PlaneProjection::error_estimate(-40.0, -1.0, 1000.0),
+            PlaneProjection::error_estimate(40.0, 1.0, 1000.0)
+        );
+        // `lat_offset` dominates the error
+        assert!(
+            PlaneProjection::error_estimate(55.0, 1.0, 1000.0)
+                > 100.0 * PlaneProjection::error_estimate(55.0, 0.0, 1000.0)
+        );
+
+        // Geodesic distance is between Malmo and Stockholm is 513_861m, the estimates should bound the actual errors
+        let geodesic = 513_861.0;
+        let midpoint = (MALMO_C.0 + STOCKHOLM_C.0) * 0.5;
+        for latitude in [midpoint, MALMO_C.0, STOCKHOLM_C.0, LUND_C.0] {
+            let proj = PlaneProjection::new(latitude);
+            let error = (proj.distance(MALMO_C, STOCKHOLM_C) - geodesic).abs() / geodesic;
+            let estimate = PlaneProjection::error_estimate(latitude, midpoint - latitude, geodesic);
+            assert!(error < estimate, "{latitude}: {error} >= {estimate}");
+            assert!(
+                estimate < 10.0 * error.max(1e-3),
+                "{latitude}: {estimate} is too pessimistic"
+            );
+        }
+    }
+}
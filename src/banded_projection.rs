@@ -0,0 +1,151 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{LatLon, PlaneProjection};
+use alloc::vec::Vec;
+
+/// A set of plane projections for consecutive latitude bands, that picks the projection closest to
+/// the queried points. This keeps the plane projection speed while bounding the error for datasets
+/// spanning many degrees of latitude, which a single projection can't do.
+///
+/// ```
+/// use plane_projection::BandedProjection;
+///
+/// // Sweden spans from 55° to 69° North
+/// let proj = BandedProjection::new(55.0, 69.0, 1.0);
+/// let distance = proj.distance((55.60330902847681, 13.001973666557435), (59.33036105663399, 18.058682977850953));
+/// assert_eq!(distance as u32, 514_007); // the geodesic distance is 513_861m
+/// ```
+#[derive(Clone)]
+pub struct BandedProjection {
+    min_latitude: f64,
+    band_size: f64,
+    bands: Vec<PlaneProjection>,
+}
+
+impl BandedProjection {
+    /// Creates projections for the bands of `band_size` degrees covering latitudes from `min_latitude`
+    /// to `max_latitude`, each created for the middle of its band. Points outside the range use the closest band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `band_size` is not positive or `min_latitude` is greater than `max_latitude`.
+    pub fn new(min_latitude: f64, max_latitude: f64, band_size: f64) -> Self {
+        assert!(band_size > 0.0, "`band_size` must be positive");
+        assert!(
+            min_latitude <= max_latitude,
+            "`min_latitude` is greater than `max_latitude`"
+        );
+        let count = (((max_latitude - min_latitude) / band_size).ceil() as usize).max(1);
+        let bands = (0..count)
+            .map(|i| PlaneProjection::new(min_latitude + (i as f64 + 0.5) * band_size))
+            .collect();
+        Self {
+            min_latitude,
+            band_size,
+            bands,
+        }
+    }
+
+    /// The projection of the band that contains the `latitude`.
+    #[inline(always)]
+    pub fn projection(&self, latitude: f64) -> &PlaneProjection {
+        let band = ((latitude - self.min_latitude) / self.band_size).floor();
+        // Float to int casts saturate, so negative values become 0
+        let band = (band as usize).min(self.bands.len() - 1);
+        &self.bands[band]
+    }
+
+    /// The projection of the band that contains the midpoint of two points.
+    #[inline(always)]
+    fn pair_projection(&self, a: LatLon, b: LatLon) -> &PlaneProjection {
+        self.projection((a.0 + b.0) * 0.5)
+    }
+
+    /// Square distance in meters between two points, see [`PlaneProjection::square_distance()`].
+    #[inline(always)]
+    pub fn square_distance(&self, a: LatLon, b: LatLon) -> f64 {
+        self.pair_projection(a, b).square_distance(a, b)
+    }
+
+    /// Distance in meters between two points, see [`PlaneProjection::distance()`].
+    #[inline(always)]
+    pub fn distance(&self, a: LatLon, b: LatLon) -> f64 {
+        self.pair_projection(a, b).distance(a, b)
+    }
+
+    /// Distance in meters from point to the segment, using the band of the point,
+    /// see [`PlaneProjection::distance_to_segment()`].
+    #[inline(always)]
+    pub fn distance_to_segment(&self, point: LatLon, segment: (LatLon, LatLon)) -> f64 {
+        self.projection(point.0).distance_to_segment(point, segment)
+    }
+
+    /// Heading in degrees from point `a` to point `b`, see [`PlaneProjection::heading()`].
+    #[inline(always)]
+    pub fn heading(&self, a: LatLon, b: LatLon) -> f32 {
+        self.pair_projection(a, b).heading(a, b)
+    }
+
+    /// Length in meters of the polyline, with every segment measured in its own band.
+    pub fn polyline_length(&self, points: &[LatLon]) -> f64 {
+        points
+            .windows(2)
+            .map(|segment| self.distance(segment[0], segment[1]))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    #[test]
+    fn banded_projection_test() {
+        let proj = BandedProjection::new(55.0, 69.0, 1.0);
+        assert_eq!(proj.bands.len(), 14);
+        assert_eq!(
+            proj.distance(MALMO_C, LUND_C),
+            PlaneProjection::new(55.5).distance(MALMO_C, LUND_C)
+        );
+        assert_eq!(
+            proj.heading(MALMO_C, LUND_C),
+            PlaneProjection::new(55.5).heading(MALMO_C, LUND_C)
+        );
+        assert_eq!(
+            proj.distance_to_segment(STOCKHOLM_C, (MALMO_C, LUND_C)),
+            PlaneProjection::new(59.5).distance_to_segment(STOCKHOLM_C, (MALMO_C, LUND_C))
+        );
+
+        // points outside of the range use the closest band
+        let (north, south) = ((70.0, 20.0), (50.0, 10.0));
+        assert_eq!(proj.distance(north, north), 0.0);
+        assert_eq!(
+            proj.square_distance(north, (70.1, 20.0)),
+            PlaneProjection::new(68.5).square_distance(north, (70.1, 20.0))
+        );
+        assert_eq!(
+            proj.square_distance(south, (50.1, 10.0)),
+            PlaneProjection::new(55.5).square_distance(south, (50.1, 10.0))
+        );
+
+        // Geodesic distance between Malmo and Stockholm is 513_861m,
+        // which is approximated much better than by a projection for any end of the line
+        let error = (proj.distance(MALMO_C, STOCKHOLM_C) - 513_861.0).abs();
+        assert!(error < 300.0);
+        assert!(
+            error * 20.0
+                < (PlaneProjection::new(MALMO_C.0).distance(MALMO_C, STOCKHOLM_C) - 513_861.0)
+                    .abs()
+        );
+        let length = proj.polyline_length(&[MALMO_C, LUND_C, STOCKHOLM_C]);
+        assert_eq!(
+            length,
+            proj.distance(MALMO_C, LUND_C) + proj.distance(LUND_C, STOCKHOLM_C)
+        );
+
+        // a single band
+        let proj = BandedProjection::new(55.65, 55.65, 1.0);
+        assert_eq!(proj.bands.len(), 1);
+    }
+}
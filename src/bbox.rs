@@ -0,0 +1,570 @@
+use crate::{LatLon, PlaneProjection, lon_diff, wrap_lon};
+use alloc::vec::Vec;
+
+/// A bounding box in (latitude, longitude) format.
+/// Boxes that span the antimeridian have `min.1` greater than `max.1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BBox {
+    /// South-West corner of the box.
+    pub min: LatLon,
+    /// North-East corner of the box.
+    pub max: LatLon,
+}
+
+impl BBox {
+    /// The smallest bounding box that contains all the points, which spans the antimeridian if that is smaller.
+    /// Returns `None` if there are no points.
+    ///
+    /// ```
+    /// use plane_projection::BBox;
+    ///
+    /// let bbox = BBox::from_points(&[(55.6, 13.0), (55.7, 13.2), (55.65, 13.1)]).unwrap();
+    /// assert_eq!(bbox, BBox { min: (55.6, 13.0), max: (55.7, 13.2) });
+    ///
+    /// let bbox = BBox::from_points(&[(0.0, 179.0), (1.0, -179.0)]).unwrap();
+    /// assert_eq!(bbox, BBox { min: (0.0, 179.0), max: (1.0, -179.0) });
+    /// ```
+    pub fn from_points(points: &[LatLon]) -> Option<Self> {
+        let &first = points.first()?;
+        let (min_lat, max_lat) = points.iter().fold((first.0, first.0), |(min, max), p| {
+            (min.min(p.0), max.max(p.0))
+        });
+        let mut lons = points.iter().map(|p| wrap_lon(p.1)).collect::<Vec<_>>();
+        lons.sort_by(f64::total_cmp);
+
+        // The box covers everything except the largest gap between longitudes, which may be over the antimeridian
+        let (mut min_lon, mut max_lon) = (lons[0], lons[lons.len() - 1]);
+        let mut largest_gap = min_lon + 360.0 - max_lon;
+        for pair in lons.windows(2) {
+            if pair[1] - pair[0] > largest_gap {
+                largest_gap = pair[1] - pair[0];
+                (min_lon, max_lon) = (pair[1], pair[0]);
+            }
+        }
+        Some(Self {
+            min: (min_lat, min_lon),
+            max: (max_lat, max_lon),
+        })
+    }
+
+    /// Center of the box, handling boxes that span the antimeridian.
+    pub fn center(&self) -> LatLon {
+        (
+            (self.min.0 + self.max.0) * 0.5,
+            wrap_lon(self.min.1 + self.lon_width() * 0.5),
+        )
+    }
+
+    /// Checks if the point is inside the box (including its boundary), handling boxes that span the antimeridian.
+    pub fn contains(&self, point: LatLon) -> bool {
+        let lon = wrap_lon(point.1);
+        let inside_lon = if self.min.1 <= self.max.1 {
+            lon >= self.min.1 && lon <= self.max.1
+        } else {
+            lon >= self.min.1 || lon <= self.max.1
+        };
+        inside_lon && point.0 >= self.min.0 && point.0 <= self.max.0
+    }
+
+    /// Intersection of two boxes, or `None` if they don't intersect. Touching boxes intersect by their boundary.
+    /// If boxes spanning the antimeridian overlap in two separate parts, the larger one is returned.
+    pub fn intersection(&self, other: &BBox) -> Option<BBox> {
+        let min_lat = self.min.0.max(other.min.0);
+        let max_lat = self.max.0.min(other.max.0);
+        if min_lat > max_lat {
+            return None;
+        }
+        // A box that covers all longitudes would otherwise intersect the other one in two parts at its boundary
+        if self.lon_width() >= 360.0 || other.lon_width() >= 360.0 {
+            let lon = if self.lon_width() >= 360.0 {
+                other
+            } else {
+                self
+            };
+            return Some(BBox {
+                min: (min_lat, lon.min.1),
+                max: (max_lat, lon.max.1),
+            });
+        }
+        let (start, end) = self.lon_range();
+        let (other_start, other_end) = other.lon_range();
+        let (start, end) = [-360.0, 0.0, 360.0]
+            .into_iter()
+            .map(|shift| (start.max(other_start + shift), end.min(other_end + shift)))
+            .filter(|(start, end)| start <= end)
+            .max_by(|a, b| (a.1 - a.0).total_cmp(&(b.1 - b.0)))?;
+        Some(BBox::from_lon_range((min_lat, max_lat), start, end))
+    }
+
+    /// Checks if two boxes intersect, including touching by their boundary.
+    pub fn intersects(&self, other: &BBox) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The smallest box that contains both boxes, which spans the antimeridian if that is smaller.
+    pub fn union(&self, other: &BBox) -> BBox {
+        let lat = (self.min.0.min(other.min.0), self.max.0.max(other.max.0));
+        let (start, end) = self.lon_range();
+        let (other_start, other_end) = other.lon_range();
+        let (start, end) = [0.0, -360.0, 360.0]
+            .into_iter()
+            .map(|shift| (start.min(other_start + shift), end.max(other_end + shift)))
+            .min_by(|a, b| (a.1 - a.0).total_cmp(&(b.1 - b.0)))
+            .unwrap();
+        BBox::from_lon_range(lat, start, end)
+    }
+
+    /// Longitude range of the box as `(start, end)` with `start <= end`, where `end` may exceed 180.0.
+    #[inline(always)]
+    pub(crate) fn lon_range(&self) -> (f64, f64) {
+        (self.min.1, self.min.1 + self.lon_width())
+    }
+
+    /// Inverse of [`BBox::lon_range()`], covering all longitudes if the range is 360.0 degrees or wider.
+    fn from_lon_range(lat: (f64, f64), start: f64, end: f64) -> Self {
+        let (min_lon, max_lon) = if end - start < 360.0 {
+            (wrap_lon(start), wrap_lon(end))
+        } else {
+            (-180.0, 180.0)
+        };
+        Self {
+            min: (lat.0, min_lon),
+            max: (lat.1, max_lon),
+        }
+    }
+
+    /// Width of the box in degrees of longitude.
+    #[inline(always)]
+    fn lon_width(&self) -> f64 {
+        if self.min.1 <= self.max.1 {
+            self.max.1 - self.min.1
+        } else {
+            self.max.1 - self.min.1 + 360.0
+        }
+    }
+}
+
+impl PlaneProjection {
+    /// Bounding box that contains the circle of `radius` meters around the `center`.
+    pub fn buffer_point(&self, center: LatLon, radius: f64) -> BBox {
+        self.buffer_bbox(
+            BBox {
+                min: center,
+                max: center,
+            },
+            radius,
+        )
+    }
+
+    /// Expands the bounding box by `margin` meters in every direction.
+    /// The result is clamped at the poles and covers all longitudes if it wraps around the Earth.
+    pub fn buffer_bbox(&self, bbox: BBox, margin: f64) -> BBox {
+        let lat_buffer = margin / self.lat_scale;
+        let lon_buffer = margin / self.lon_scale;
+        let (min_lon, max_lon) = if bbox.lon_width() + 2.0 * lon_buffer < 360.0 {
+            (
+                wrap_lon(bbox.min.1 - lon_buffer),
+                wrap_lon(bbox.max.1 + lon_buffer),
+            )
+        } else {
+            (-180.0, 180.0)
+        };
+        BBox {
+            min: ((bbox.min.0 - lat_buffer).max(-90.0), min_lon),
+            max: ((bbox.max.0 + lat_buffer).min(90.0), max_lon),
+        }
+    }
+
+    /// Width of the box in meters from West to East.
+    pub fn bbox_width(&self, bbox: BBox) -> f64 {
+        bbox.lon_width() * self.lon_scale
+    }
+
+    /// Height of the box in meters from South to North.
+    pub fn bbox_height(&self, bbox: BBox) -> f64 {
+        (bbox.max.0 - bbox.min.0) * self.lat_scale
+    }
+
+    /// Area in square meters of the intersection of two boxes, which is 0.0 if they don't intersect.
+    pub fn bbox_overlap_area(&self, a: BBox, b: BBox) -> f64 {
+        a.intersection(&b).map_or(0.0, |overlap| {
+            self.bbox_width(overlap) * self.bbox_height(overlap)
+        })
+    }
+
+    /// Square distance in meters from the point to the closest point of the box, which is 0.0 for points inside.
+    pub(crate) fn square_distance_to_bbox(&self, point: LatLon, bbox: BBox) -> f64 {
+        let lat = point.0.clamp(bbox.min.0, bbox.max.0);
+        let lon = if bbox.contains((bbox.min.0, point.1)) {
+            point.1
+        } else if lon_diff(point.1, bbox.min.1).abs() < lon_diff(point.1, bbox.max.1).abs() {
+            bbox.min.1
+        } else {
+            bbox.max.1
+        };
+        self.square_distance(point, (lat, lon))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn contains_test() {
+        let bbox = BBox {
+            min: (55.6, 13.0),
+            max: (55.8, 13.2),
+        };
+        assert!(bbox.contains(LUND_C));
+        assert!(bbox.contains((55.65, 13.1)));
+        assert!(bbox.contains(bbox.min) && bbox.contains(bbox.max));
+        assert!(!bbox.contains((55.5, 13.1)));
+        assert!(!bbox.contains((55.9, 13.1)));
+        assert!(!bbox.contains((55.65, 12.9)));
+        assert!(!bbox.contains((55.65, 13.3)));
+        assert!(bbox.contains((55.65, 13.1 + 360.0)));
+
+        // spans the antimeridian
+        let bbox = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -179.0),
+        };
+        assert!(bbox.contains((0.0, 179.5)));
+        assert!(bbox.contains((0.0, -179.5)));
+        assert!(bbox.contains((0.0, 180.0)));
+        assert!(bbox.contains((0.0, 180.5)));
+        assert!(!bbox.contains((0.0, 0.0)));
+        assert!(!bbox.contains((0.0, 178.0)));
+        assert!(!bbox.contains((0.0, -178.0)));
+        assert!(!bbox.contains((2.0, 179.5)));
+    }
+
+    #[test]
+    fn from_points_test() {
+        assert_eq!(BBox::from_points(&[]), None);
+        assert_eq!(
+            BBox::from_points(&[LUND_C]),
+            Some(BBox {
+                min: LUND_C,
+                max: LUND_C
+            })
+        );
+        assert_eq!(
+            BBox::from_points(&[(55.6, 13.2), (55.7, 13.0), (55.65, 13.1)]),
+            Some(BBox {
+                min: (55.6, 13.0),
+                max: (55.7, 13.2)
+            })
+        );
+        // spans the antimeridian
+        assert_eq!(
+            BBox::from_points(&[(0.0, -179.0), (1.0, 178.0), (-1.0, 179.5)]),
+            Some(BBox {
+                min: (-1.0, 178.0),
+                max: (1.0, -179.0)
+            })
+        );
+        assert_eq!(
+            BBox::from_points(&[(0.0, 181.0), (0.0, 179.0)]),
+            Some(BBox {
+                min: (0.0, 179.0),
+                max: (0.0, -179.0)
+            })
+        );
+        // equal gaps prefer the box that doesn't span the antimeridian
+        assert_eq!(
+            BBox::from_points(&[(0.0, -90.0), (0.0, 90.0)]),
+            Some(BBox {
+                min: (0.0, -90.0),
+                max: (0.0, 90.0)
+            })
+        );
+    }
+
+    #[test]
+    fn center_test() {
+        let bbox = BBox {
+            min: (55.6, 13.0),
+            max: (55.8, 13.2),
+        };
+        let center = bbox.center();
+        assert!((center.0 - 55.7).abs() < 1e-12 && (center.1 - 13.1).abs() < 1e-12);
+        let bbox = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -177.0),
+        };
+        assert_eq!(bbox.center(), (0.0, -179.0));
+    }
+
+    #[test]
+    fn bbox_size_test() {
+        let proj = PlaneProjection::new(55.65);
+        let bbox = BBox::from_points(&[MALMO_C, LUND_C]).unwrap();
+        let width = proj.distance(MALMO_C, (MALMO_C.0, LUND_C.1));
+        let height = proj.distance(MALMO_C, (LUND_C.0, MALMO_C.1));
+        assert!((proj.bbox_width(bbox) - width).abs() < 1e-6);
+        assert!((proj.bbox_height(bbox) - height).abs() < 1e-6);
+
+        // spans the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let bbox = BBox {
+            min: (0.0, 179.0),
+            max: (0.0, -179.0),
+        };
+        assert!((proj.bbox_width(bbox) - proj.distance((0.0, 179.0), (0.0, 181.0))).abs() < 1e-6);
+        assert_eq!(proj.bbox_height(bbox), 0.0);
+    }
+
+    #[test]
+    fn intersection_test() {
+        let a = BBox {
+            min: (55.6, 13.0),
+            max: (55.8, 13.2),
+        };
+        assert_eq!(a.intersection(&a), Some(a));
+        let b = BBox {
+            min: (55.7, 13.1),
+            max: (55.9, 13.3),
+        };
+        let expected = BBox {
+            min: (55.7, 13.1),
+            max: (55.8, 13.2),
+        };
+        assert_eq!(a.intersection(&b), Some(expected));
+        assert_eq!(b.intersection(&a), Some(expected));
+        assert!(a.intersects(&b));
+        // touching
+        let c = BBox {
+            min: (55.8, 13.2),
+            max: (55.9, 13.3),
+        };
+        assert_eq!(
+            a.intersection(&c),
+            Some(BBox {
+                min: (55.8, 13.2),
+                max: (55.8, 13.2)
+            })
+        );
+        // disjoint by latitude or longitude
+        assert!(!a.intersects(&BBox {
+            min: (55.9, 13.0),
+            max: (56.0, 13.2)
+        }));
+        assert!(!a.intersects(&BBox {
+            min: (55.6, 13.3),
+            max: (55.8, 13.4)
+        }));
+
+        // spans the antimeridian
+        let a = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -179.0),
+        };
+        let b = BBox {
+            min: (0.0, -179.5),
+            max: (2.0, -170.0),
+        };
+        assert_eq!(
+            a.intersection(&b),
+            Some(BBox {
+                min: (0.0, -179.5),
+                max: (1.0, -179.0)
+            })
+        );
+        assert_eq!(b.intersection(&a), a.intersection(&b));
+        assert!(!a.intersects(&BBox {
+            min: (0.0, 0.0),
+            max: (1.0, 1.0)
+        }));
+        // all longitudes
+        let world = BBox {
+            min: (-90.0, -180.0),
+            max: (90.0, 180.0),
+        };
+        assert_eq!(world.intersection(&a), Some(a));
+        assert_eq!(a.intersection(&world), Some(a));
+    }
+
+    #[test]
+    fn union_test() {
+        let a = BBox {
+            min: (55.6, 13.0),
+            max: (55.8, 13.2),
+        };
+        let b = BBox {
+            min: (55.7, 13.1),
+            max: (55.9, 13.3),
+        };
+        let expected = BBox {
+            min: (55.6, 13.0),
+            max: (55.9, 13.3),
+        };
+        assert_eq!(a.union(&b), expected);
+        assert_eq!(b.union(&a), expected);
+        assert_eq!(a.union(&a), a);
+
+        // across the antimeridian
+        let a = BBox {
+            min: (0.0, 178.0),
+            max: (1.0, 179.0),
+        };
+        let b = BBox {
+            min: (-1.0, -179.0),
+            max: (0.0, -178.0),
+        };
+        let expected = BBox {
+            min: (-1.0, 178.0),
+            max: (1.0, -178.0),
+        };
+        assert_eq!(a.union(&b), expected);
+        assert_eq!(b.union(&a), expected);
+        let c = BBox {
+            min: (0.0, -179.0),
+            max: (0.0, 178.5),
+        };
+        assert_eq!(
+            a.union(&c),
+            BBox {
+                min: (0.0, -179.0),
+                max: (1.0, 179.0)
+            }
+        );
+        // covers all longitudes
+        let c = BBox {
+            min: (0.0, 179.0),
+            max: (0.0, 178.0),
+        };
+        assert_eq!(
+            a.union(&c),
+            BBox {
+                min: (0.0, -180.0),
+                max: (1.0, 180.0)
+            }
+        );
+    }
+
+    #[test]
+    fn bbox_overlap_area_test() {
+        let proj = PlaneProjection::new(55.65);
+        let a = BBox {
+            min: (55.6, 13.0),
+            max: (55.8, 13.2),
+        };
+        let b = BBox {
+            min: (55.7, 13.1),
+            max: (55.9, 13.3),
+        };
+        let overlap = a.intersection(&b).unwrap();
+        let expected = proj.bbox_width(overlap) * proj.bbox_height(overlap);
+        assert_eq!(proj.bbox_overlap_area(a, b), expected);
+        assert!(
+            (proj.bbox_overlap_area(a, a)
+                - proj.area(&[a.min, (a.min.0, a.max.1), a.max, (a.max.0, a.min.1)]))
+            .abs()
+                < 1e-3
+        );
+        assert_eq!(
+            proj.bbox_overlap_area(
+                a,
+                BBox {
+                    min: (56.0, 13.0),
+                    max: (56.1, 13.2)
+                }
+            ),
+            0.0
+        );
+    }
+
+    #[test]
+    fn buffer_point_test() {
+        let proj = PlaneProjection::new(55.65);
+        let bbox = proj.buffer_point(LUND_C, 1000.0);
+        assert!(bbox.min.0 < LUND_C.0 && bbox.min.1 < LUND_C.1);
+        assert!(bbox.max.0 > LUND_C.0 && bbox.max.1 > LUND_C.1);
+        for lat in [bbox.min.0, bbox.max.0] {
+            let distance = proj.distance(LUND_C, (lat, LUND_C.1));
+            assert!((distance - 1000.0).abs() < 1e-6);
+        }
+        for lon in [bbox.min.1, bbox.max.1] {
+            let distance = proj.distance(LUND_C, (LUND_C.0, lon));
+            assert!((distance - 1000.0).abs() < 1e-6);
+        }
+        assert_eq!(
+            proj.buffer_point(LUND_C, 0.0),
+            BBox {
+                min: LUND_C,
+                max: LUND_C
+            }
+        );
+
+        // wraps over the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let bbox = proj.buffer_point((0.0, 179.999), 1000.0);
+        assert!(bbox.min.1 > bbox.max.1);
+        assert!(bbox.min.1 < 179.999 && bbox.max.1 < -179.99);
+
+        // clamped at the poles and covers all longitudes for huge radius
+        let proj = PlaneProjection::new(89.0);
+        let bbox = proj.buffer_point((89.0, 0.0), 400_000.0);
+        assert_eq!(
+            bbox,
+            BBox {
+                min: (bbox.min.0, -180.0),
+                max: (90.0, 180.0)
+            }
+        );
+        assert!(bbox.min.0 < 85.5);
+    }
+
+    #[test]
+    fn buffer_bbox_test() {
+        let proj = PlaneProjection::new(55.65);
+        let bbox = BBox {
+            min: (55.6, 13.0),
+            max: (55.7, 13.2),
+        };
+        assert_eq!(proj.buffer_bbox(bbox, 0.0), bbox);
+        let buffered = proj.buffer_bbox(bbox, 1000.0);
+        assert!((proj.distance(bbox.min, (buffered.min.0, bbox.min.1)) - 1000.0).abs() < 1e-6);
+        assert!((proj.distance(bbox.min, (bbox.min.0, buffered.min.1)) - 1000.0).abs() < 1e-6);
+        assert!((proj.distance(bbox.max, (buffered.max.0, bbox.max.1)) - 1000.0).abs() < 1e-6);
+        assert!((proj.distance(bbox.max, (bbox.max.0, buffered.max.1)) - 1000.0).abs() < 1e-6);
+
+        // box that already spans the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let bbox = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -179.0),
+        };
+        let buffered = proj.buffer_bbox(bbox, 10_000.0);
+        assert!(buffered.min.1 < 179.0 && buffered.max.1 > -179.0);
+        assert!(buffered.min.1 > buffered.max.1);
+
+        // box that starts to span the antimeridian
+        let bbox = BBox {
+            min: (-1.0, 170.0),
+            max: (1.0, 179.95),
+        };
+        let buffered = proj.buffer_bbox(bbox, 10_000.0);
+        assert!(buffered.max.1 < -179.9);
+
+        // wraps around the Earth
+        let bbox = BBox {
+            min: (-1.0, -179.0),
+            max: (1.0, 179.0),
+        };
+        let buffered = proj.buffer_bbox(bbox, 200_000.0);
+        assert_eq!((buffered.min.1, buffered.max.1), (-180.0, 180.0));
+
+        // clamped at the poles
+        let bbox = BBox {
+            min: (-89.99, 0.0),
+            max: (89.99, 1.0),
+        };
+        let buffered = proj.buffer_bbox(bbox, 10_000.0);
+        assert_eq!((buffered.min.0, buffered.max.0), (-90.0, 90.0));
+    }
+}
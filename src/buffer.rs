@@ -0,0 +1,171 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{LatLon, PlaneProjection};
+use alloc::vec::Vec;
+
+impl PlaneProjection {
+    /// Polygon ring approximating the area within `radius` meters from the point or the polyline,
+    /// with round caps and joins, for example a corridor around a route. Arcs are approximated
+    /// with `quadrant_segments` segments per quarter of a circle, so a point becomes a polygon with
+    /// `4 * quadrant_segments` vertices. The ring goes counterclockwise and is not closed, i.e. the first vertex
+    /// is not repeated at the end. Returns an empty ring for an empty polyline.
+    ///
+    /// Inner sides of the turns are joined at the intersection of the offset segments, so the ring
+    /// overlaps itself if a segment is shorter than the `radius` at a sharp turn.
+    /// Use [`PlaneProjection::within_corridor()`] to check if a point is within the buffer without building it.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let route = [(55.60, 13.00), (55.61, 13.00), (55.61, 13.02)];
+    /// let corridor = proj.buffer(&route, 50.0, 8);
+    /// assert!(proj.contains(&corridor, (55.61, 13.01)));
+    /// assert!(proj.contains(&corridor, proj.offset((55.6, 13.0), 45.0, 0.0)));
+    /// assert!(!proj.contains(&corridor, proj.offset((55.6, 13.0), 55.0, 0.0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is not positive or `quadrant_segments` is 0.
+    pub fn buffer(&self, line: &[LatLon], radius: f64, quadrant_segments: usize) -> Vec<LatLon> {
+        assert!(radius > 0.0, "`radius` must be positive");
+        assert!(
+            quadrant_segments > 0,
+            "`quadrant_segments` must be positive"
+        );
+        let Some(&origin) = line.first() else {
+            return Vec::new();
+        };
+        // Transform to local Cartesian coordinates with the first point as origin and skip repeated points
+        let mut points: Vec<(f64, f64)> = Vec::with_capacity(line.len());
+        for &point in line {
+            let point = self.project_relative(origin, point);
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+
+        let step = core::f64::consts::FRAC_PI_2 / quadrant_segments as f64;
+        let mut ring = Vec::new();
+        if points.len() == 1 {
+            arc(
+                &mut ring,
+                (0.0, 0.0),
+                radius,
+                0.0,
+                -core::f64::consts::TAU,
+                step,
+            );
+            ring.pop();
+        } else {
+            // The outline is the right-side offset of the path going forward and then back,
+            // so the caps are just the joins at the U-turns on both ends
+            let path = points.iter().chain(points.iter().rev().skip(1)).copied();
+            let path = path.collect::<Vec<_>>();
+            let heading = |a: (f64, f64), b: (f64, f64)| (b.1 - a.1).atan2(b.0 - a.0);
+            // Joins at every vertex of the loop, including the closing one at the start
+            for i in 0..path.len() - 1 {
+                let vertex = path[i];
+                let incoming = heading(path[(i + path.len() - 2) % (path.len() - 1)], vertex);
+                let outgoing = heading(vertex, path[i + 1]);
+                let turn = normalize_angle(outgoing - incoming);
+                if turn > 0.0 {
+                    // Right turn, so the right side is inner and the offset segments meet at the miter point
+                    let angle = incoming + core::f64::consts::FRAC_PI_2 + turn * 0.5;
+                    let distance = radius / (turn * 0.5).cos();
+                    ring.push((
+                        vertex.0 + distance * angle.cos(),
+                        vertex.1 + distance * angle.sin(),
+                    ));
+                } else {
+                    let start = incoming + core::f64::consts::FRAC_PI_2;
+                    arc(&mut ring, vertex, radius, start, turn, step);
+                }
+            }
+        }
+        ring.into_iter()
+            .map(|p| self.unproject_relative(origin, p))
+            .collect()
+    }
+}
+
+/// Normalizes the angle in radians to [-PI, PI), so U-turns are treated as left turns and get round caps.
+#[inline(always)]
+fn normalize_angle(angle: f64) -> f64 {
+    use core::f64::consts::{PI, TAU};
+    angle - TAU * ((angle + PI) / TAU).floor()
+}
+
+/// Appends the arc around `center` from the `start` angle (clockwise from North) by `sweep` radians,
+/// including both ends, with steps no longer than `step` radians.
+fn arc(
+    ring: &mut Vec<(f64, f64)>,
+    center: (f64, f64),
+    radius: f64,
+    start: f64,
+    sweep: f64,
+    step: f64,
+) {
+    let count = ((sweep.abs() / step).ceil() as usize).max(1);
+    for i in 0..=count {
+        let angle = start + sweep * i as f64 / count as f64;
+        ring.push((
+            center.0 + radius * angle.cos(),
+            center.1 + radius * angle.sin(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn buffer_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.buffer(&[], 10.0, 8).is_empty());
+
+        // a point becomes a regular polygon
+        let circle = proj.buffer(&[LUND_C, LUND_C], 100.0, 8);
+        assert_eq!(circle.len(), 32);
+        for point in &circle {
+            assert!((proj.distance(LUND_C, *point) - 100.0).abs() < 1e-6);
+        }
+        let expected = 0.5 * 32.0 * 100.0 * 100.0 * (core::f64::consts::TAU / 32.0).sin();
+        assert!((proj.area(&circle) - expected).abs() < 1e-3);
+
+        // a straight segment becomes a stadium
+        let line = [MALMO_C, LUND_C];
+        let length = proj.distance(MALMO_C, LUND_C);
+        let stadium = proj.buffer(&line, 100.0, 16);
+        assert_eq!(stadium.len(), 2 * (16 * 2 + 1));
+        for point in &stadium {
+            let distance = proj.distance_to_segment(*point, (MALMO_C, LUND_C));
+            assert!((distance - 100.0).abs() < 1e-6);
+        }
+        let expected = 2.0 * 100.0 * length + core::f64::consts::PI * 100.0 * 100.0;
+        assert!((proj.area(&stadium) - expected).abs() / expected < 1e-3);
+        assert!(proj.contains(&stadium, proj.midpoint(MALMO_C, LUND_C)));
+
+        // right turn from North to East has a miter on the inner side and a round join on the outer one
+        let proj = PlaneProjection::new(0.0);
+        let line = [(0.0, 0.0), (0.01, 0.0), (0.01, 0.01)];
+        let ring = proj.buffer(&line, 100.0, 4);
+        let corner = proj.offset(line[1], 100.0, -100.0);
+        assert!(
+            ring.iter()
+                .any(|point| proj.distance(*point, corner) < 1e-6)
+        );
+        for point in &ring {
+            let distance = proj
+                .distance_to_segment(*point, (line[0], line[1]))
+                .min(proj.distance_to_segment(*point, (line[1], line[2])));
+            assert!((distance - 100.0).abs() < 1e-6);
+        }
+        assert!(proj.contains(&ring, proj.offset(line[1], -70.0, 70.0)));
+        assert!(!proj.contains(&ring, proj.offset(line[1], -72.0, 72.0)));
+        assert!(proj.contains(&ring, proj.offset(line[1], 99.0, -99.0)));
+    }
+}
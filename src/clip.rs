@@ -0,0 +1,311 @@
+use crate::{BBox, LatLon, PlaneProjection};
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl PlaneProjection {
+    /// Part of the segment inside the `bbox` (including its boundary), or `None` if the segment is outside.
+    /// Clipping is done with the Liang-Barsky algorithm in the projection space around the box center,
+    /// so segments and boxes that span the antimeridian are handled. Boxes should be narrower than
+    /// half of the Earth unless they cover all longitudes.
+    ///
+    /// ```
+    /// use plane_projection::{BBox, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let bbox = BBox { min: (55.6, 13.0), max: (55.7, 13.2) };
+    /// let clipped = proj.clip_segment(((55.65, 12.9), (55.65, 13.1)), bbox);
+    /// assert_eq!(clipped, Some(((55.65, 13.0), (55.65, 13.1))));
+    /// ```
+    pub fn clip_segment(&self, segment: (LatLon, LatLon), bbox: BBox) -> Option<(LatLon, LatLon)> {
+        let (t0, t1) = self.clip_segment_range(segment, bbox)?;
+        Some((
+            self.segment_point(segment, t0),
+            self.segment_point(segment, t1),
+        ))
+    }
+
+    /// Parts of the polyline inside the `bbox`, see [`PlaneProjection::clip_segment()`]. A polyline that leaves
+    /// and re-enters the box is split into several parts. A polyline with a single point is kept if the point
+    /// is inside the box.
+    ///
+    /// ```
+    /// use plane_projection::{BBox, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let bbox = BBox { min: (55.6, 13.0), max: (55.7, 13.2) };
+    /// let line = [(55.65, 12.9), (55.65, 13.1), (55.75, 13.1), (55.65, 13.15)];
+    /// let parts = proj.clip_polyline(&line, bbox);
+    /// assert_eq!(parts.len(), 2);
+    /// assert_eq!(parts[0], [(55.65, 13.0), (55.65, 13.1), (55.7, 13.1)]);
+    /// ```
+    pub fn clip_polyline(&self, line: &[LatLon], bbox: BBox) -> Vec<Vec<LatLon>> {
+        if let [point] = line {
+            return if bbox.contains(*point) {
+                vec![vec![*point]]
+            } else {
+                Vec::new()
+            };
+        }
+
+        let mut parts: Vec<Vec<LatLon>> = Vec::new();
+        // Whether the previous segment ended inside the box, so the next one continues the same part
+        let mut connected = false;
+        for segment in line.windows(2) {
+            let segment = (segment[0], segment[1]);
+            let Some((t0, t1)) = self.clip_segment_range(segment, bbox) else {
+                connected = false;
+                continue;
+            };
+            match parts.last_mut() {
+                Some(part) if connected && t0 == 0.0 => part.push(self.segment_point(segment, t1)),
+                _ => parts.push(vec![
+                    self.segment_point(segment, t0),
+                    self.segment_point(segment, t1),
+                ]),
+            }
+            connected = t1 == 1.0;
+        }
+        parts
+    }
+
+    /// Part of the polygon ring inside the `bbox`, clipped with the Sutherland-Hodgman algorithm in the projection
+    /// space around the box center, see [`PlaneProjection::clip_segment()`]. The ring can be either closed or not
+    /// and the result is closed the same way. Returns an empty ring if the polygon is outside of the box.
+    /// Concave polygons that cross the box several times are kept as a single ring with degenerate edges
+    /// along the box boundary, which is fine for rendering and area calculations.
+    ///
+    /// ```
+    /// use plane_projection::{BBox, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let bbox = BBox { min: (55.6, 13.0), max: (55.7, 13.2) };
+    /// let ring = [(55.65, 12.9), (55.65, 13.1), (55.75, 13.1), (55.75, 12.9)];
+    /// let clipped = proj.clip_polygon(&ring, bbox);
+    /// assert_eq!(clipped.len(), 4);
+    /// assert!((proj.area(&clipped) - proj.area(&ring) / 4.0).abs() < 1.0);
+    /// ```
+    pub fn clip_polygon(&self, ring: &[LatLon], bbox: BBox) -> Vec<LatLon> {
+        let (ring, closed) = match ring {
+            [first, rest @ .., last] if first == last => (&ring[..rest.len() + 1], true),
+            _ => (ring, false),
+        };
+        // Transform to local Cartesian coordinates with the box center as origin,
+        // while keeping the original points to return the untouched vertices as is
+        let center = bbox.center();
+        let (half_height, half_width) = (self.bbox_height(bbox) * 0.5, self.bbox_width(bbox) * 0.5);
+        let mut vertices = ring
+            .iter()
+            .map(|&point| (self.project_relative(center, point), point))
+            .collect::<Vec<_>>();
+
+        // (North, East) component and the sign to clip `sign * component <= limit` half-planes
+        let edges = [
+            (0, 1.0, half_height),
+            (0, -1.0, half_height),
+            (1, 1.0, half_width),
+            (1, -1.0, half_width),
+        ];
+        for (axis, sign, limit) in edges {
+            let value = |vertex: &((f64, f64), LatLon)| {
+                sign * if axis == 0 { vertex.0.0 } else { vertex.0.1 }
+            };
+            let mut clipped = Vec::with_capacity(vertices.len() + 4);
+            for (i, vertex) in vertices.iter().enumerate() {
+                let prev = &vertices[(i + vertices.len() - 1) % vertices.len()];
+                let (inside, prev_inside) = (value(vertex) <= limit, value(prev) <= limit);
+                if inside != prev_inside {
+                    let t = (limit - value(prev)) / (value(vertex) - value(prev));
+                    let local = (
+                        prev.0.0 + (vertex.0.0 - prev.0.0) * t,
+                        prev.0.1 + (vertex.0.1 - prev.0.1) * t,
+                    );
+                    clipped.push((local, self.interpolate(prev.1, vertex.1, t)));
+                }
+                if inside {
+                    clipped.push(*vertex);
+                }
+            }
+            vertices = clipped;
+        }
+
+        let mut result = vertices
+            .into_iter()
+            .map(|(_, point)| point)
+            .collect::<Vec<_>>();
+        if closed && !result.is_empty() {
+            result.push(result[0]);
+        }
+        result
+    }
+
+    /// Liang-Barsky clipping, returning the range of the segment parameter inside the box.
+    fn clip_segment_range(&self, segment: (LatLon, LatLon), bbox: BBox) -> Option<(f64, f64)> {
+        // Transform to local Cartesian coordinates with the box center as origin
+        let center = bbox.center();
+        let (half_height, half_width) = (self.bbox_height(bbox) * 0.5, self.bbox_width(bbox) * 0.5);
+        let start = self.project_relative(center, segment.0);
+        let direction = self.project_relative(segment.0, segment.1);
+
+        let (mut t0, mut t1) = (0.0, 1.0);
+        for (p, q) in [
+            (-direction.0, start.0 + half_height),
+            (direction.0, half_height - start.0),
+            (-direction.1, start.1 + half_width),
+            (direction.1, half_width - start.1),
+        ] {
+            if p == 0.0 {
+                // parallel to the edge, so either fully inside or outside of it
+                if q < 0.0 {
+                    return None;
+                }
+            } else if p < 0.0 {
+                // entering the edge at `q / p`
+                t0 = f64::max(t0, q / p);
+            } else {
+                // leaving the edge at `q / p`
+                t1 = f64::min(t1, q / p);
+            }
+        }
+        (t0 <= t1).then_some((t0, t1))
+    }
+
+    /// Point on the segment at `t`, which is exactly the segment end for 0.0 and 1.0.
+    #[inline(always)]
+    fn segment_point(&self, segment: (LatLon, LatLon), t: f64) -> LatLon {
+        match t {
+            0.0 => segment.0,
+            1.0 => segment.1,
+            _ => self.interpolate(segment.0, segment.1, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BBOX: BBox = BBox {
+        min: (0.0, 0.0),
+        max: (1.0, 1.0),
+    };
+
+    #[test]
+    fn clip_segment_test() {
+        let proj = PlaneProjection::new(0.5);
+        let inside = ((0.2, 0.2), (0.8, 0.7));
+        assert_eq!(proj.clip_segment(inside, BBOX), Some(inside));
+        assert_eq!(proj.clip_segment(((2.0, 0.0), (2.0, 1.0)), BBOX), None);
+        assert_eq!(proj.clip_segment(((-1.0, 2.0), (2.0, 2.0)), BBOX), None);
+        // diagonal passing by the corner
+        assert!(proj.clip_segment(((0.0, 1.5), (1.5, 0.0)), BBOX).is_some());
+        assert_eq!(proj.clip_segment(((1.1, 2.0), (2.0, 1.1)), BBOX), None);
+
+        // crossing the box, touching its boundary and a point
+        let (start, end) = proj.clip_segment(((0.5, -1.0), (0.5, 2.0)), BBOX).unwrap();
+        assert!(proj.distance(start, (0.5, 0.0)) < 1e-6 && proj.distance(end, (0.5, 1.0)) < 1e-6);
+        assert_eq!(
+            proj.clip_segment(((1.0, -1.0), (1.0, 2.0)), BBOX),
+            Some(((1.0, 0.0), (1.0, 1.0)))
+        );
+        assert_eq!(
+            proj.clip_segment(((0.5, 0.5), (0.5, 0.5)), BBOX),
+            Some(((0.5, 0.5), (0.5, 0.5)))
+        );
+        assert_eq!(proj.clip_segment(((1.5, 0.5), (1.5, 0.5)), BBOX), None);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let bbox = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -179.0),
+        };
+        let (start, end) = proj
+            .clip_segment(((0.0, 178.0), (0.0, -178.0)), bbox)
+            .unwrap();
+        assert!(proj.distance(start, (0.0, 179.0)) < 1e-6);
+        assert!(proj.distance(end, (0.0, -179.0)) < 1e-6);
+        assert_eq!(proj.clip_segment(((0.0, 178.0), (0.0, 178.5)), bbox), None);
+    }
+
+    #[test]
+    fn clip_polygon_test() {
+        let proj = PlaneProjection::new(0.5);
+        assert!(proj.clip_polygon(&[], BBOX).is_empty());
+        let inside = [(0.2, 0.2), (0.8, 0.2), (0.5, 0.7)];
+        assert_eq!(proj.clip_polygon(&inside, BBOX), inside);
+        let closed = [(0.2, 0.2), (0.8, 0.2), (0.5, 0.7), (0.2, 0.2)];
+        assert_eq!(proj.clip_polygon(&closed, BBOX), closed);
+        assert!(
+            proj.clip_polygon(&[(2.0, 2.0), (3.0, 2.0), (2.0, 3.0)], BBOX)
+                .is_empty()
+        );
+
+        // polygon covering the whole box is clipped to the box itself
+        let around = [
+            (-1.0, -1.0),
+            (2.0, -1.0),
+            (2.0, 2.0),
+            (-1.0, 2.0),
+            (-1.0, -1.0),
+        ];
+        let clipped = proj.clip_polygon(&around, BBOX);
+        assert_eq!(clipped.len(), 5);
+        assert_eq!(clipped[0], clipped[4]);
+        let box_ring = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        assert!((proj.area(&clipped) - proj.area(&box_ring)).abs() < 1e-3);
+        for point in &clipped {
+            assert!(
+                box_ring
+                    .iter()
+                    .any(|corner| proj.distance(*point, *corner) < 1e-6)
+            );
+        }
+
+        // triangle sticking out of the box to the North
+        let triangle = [(0.5, 0.2), (1.5, 0.5), (0.5, 0.8)];
+        let clipped = proj.clip_polygon(&triangle, BBOX);
+        assert_eq!(clipped.len(), 4);
+        let expected = proj.area(&triangle) * (1.0 - 0.25);
+        assert!((proj.area(&clipped) - expected).abs() < 1e-3);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let bbox = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -179.0),
+        };
+        let ring = [(0.0, 178.0), (0.5, -178.0), (-0.5, -178.0)];
+        let clipped = proj.clip_polygon(&ring, bbox);
+        assert!(clipped.iter().all(|&point| bbox.contains(point)));
+        assert!(proj.area(&clipped) > 0.0 && proj.area(&clipped) < proj.area(&ring));
+    }
+
+    #[test]
+    fn clip_polyline_test() {
+        let proj = PlaneProjection::new(0.5);
+        assert!(proj.clip_polyline(&[], BBOX).is_empty());
+        assert_eq!(proj.clip_polyline(&[(0.5, 0.5)], BBOX), [[(0.5, 0.5)]]);
+        assert!(proj.clip_polyline(&[(1.5, 0.5)], BBOX).is_empty());
+
+        let inside = [(0.2, 0.2), (0.8, 0.7), (0.5, 0.5)];
+        assert_eq!(proj.clip_polyline(&inside, BBOX), [inside]);
+        assert!(
+            proj.clip_polyline(&[(2.0, 0.0), (2.0, 1.0)], BBOX)
+                .is_empty()
+        );
+
+        // leaves the box and re-enters it
+        let line = [(0.5, 0.5), (0.5, 1.5), (0.7, 1.5), (0.7, 0.5)];
+        let parts = proj.clip_polyline(&line, BBOX);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 2);
+        assert_eq!(parts[0][0], (0.5, 0.5));
+        assert!(proj.distance(parts[0][1], (0.5, 1.0)) < 1e-6);
+        assert!(proj.distance(parts[1][0], (0.7, 1.0)) < 1e-6);
+        assert_eq!(parts[1][1], (0.7, 0.5));
+
+        // touches the boundary from the inside and stays in one part
+        let line = [(0.5, 0.5), (1.0, 0.5), (0.5, 0.6)];
+        assert_eq!(proj.clip_polyline(&line, BBOX), [line]);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{Float, GeoPoint};
+
+/// A point with latitude and longitude in degrees, accepted by the point methods of
+/// [`PlaneProjection`](crate::PlaneProjection) like [`distance()`](crate::PlaneProjection::distance()) and
+/// [`heading()`](crate::PlaneProjection::heading()), so domain types can be passed without converting them
+/// to (lat, lon) tuples at every call site.
+///
+/// Implemented for (lat, lon) tuples, `[lat, lon]` arrays, [`GeoPoint`], [`LonLat`], references to coordinates and,
+/// behind the `geo` and `glam` features, for `geo_types` and `glam` points in (x, y) = (lon, lat) order.
+///
+/// ```
+/// use plane_projection::{Coordinate, PlaneProjection};
+///
+/// struct Position {
+///     latitude: f64,
+///     longitude: f64,
+/// }
+///
+/// impl Coordinate for Position {
+///     fn lat(&self) -> f64 {
+///         self.latitude
+///     }
+///     fn lon(&self) -> f64 {
+///         self.longitude
+///     }
+/// }
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let lund = Position { latitude: 55.7041417, longitude: 13.1913041 };
+/// assert_eq!(proj.distance(&lund, [55.6033090, 13.0019737]) as u32, 16373);
+/// ```
+pub trait Coordinate<T = f64> {
+    /// Latitude in degrees.
+    fn lat(&self) -> T;
+
+    /// Longitude in degrees.
+    fn lon(&self) -> T;
+
+    /// The point in (lat, lon) format.
+    #[inline(always)]
+    fn lat_lon(&self) -> (T, T) {
+        (self.lat(), self.lon())
+    }
+}
+
+/// A point in GeoJSON-style (longitude, latitude) order, which can be passed to any method accepting
+/// [`Coordinate`] without swapping the values manually.
+///
+/// ```
+/// use plane_projection::{LonLat, PlaneProjection};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let lund = LonLat(13.1913041, 55.7041417);
+/// assert_eq!(proj.distance(lund, LonLat(13.0019737, 55.6033090)) as u32, 16373);
+/// assert_eq!(proj.distance(lund, (55.6033090, 13.0019737)) as u32, 16373);
+///
+/// let LonLat(lon, lat) = LonLat::from(proj.destination(lund, 90.0, 1000.0));
+/// assert!(lon > lund.0 && lat == lund.1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LonLat<T = f64>(pub T, pub T);
+
+impl<T: Float> Coordinate<T> for LonLat<T> {
+    #[inline(always)]
+    fn lat(&self) -> T {
+        self.1
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<(T, T)> for LonLat<T> {
+    /// Converts from (lat, lon) format.
+    #[inline(always)]
+    fn from((lat, lon): (T, T)) -> Self {
+        Self(lon, lat)
+    }
+}
+
+impl<T> From<LonLat<T>> for (T, T) {
+    /// Converts to (lat, lon) format.
+    #[inline(always)]
+    fn from(LonLat(lon, lat): LonLat<T>) -> Self {
+        (lat, lon)
+    }
+}
+
+impl<T: Float> Coordinate<T> for (T, T) {
+    #[inline(always)]
+    fn lat(&self) -> T {
+        self.0
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> T {
+        self.1
+    }
+}
+
+impl<T: Float> Coordinate<T> for [T; 2] {
+    #[inline(always)]
+    fn lat(&self) -> T {
+        self[0]
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> T {
+        self[1]
+    }
+}
+
+impl Coordinate for GeoPoint {
+    #[inline(always)]
+    fn lat(&self) -> f64 {
+        GeoPoint::lat(self)
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> f64 {
+        GeoPoint::lon(self)
+    }
+}
+
+impl<T, C: Coordinate<T> + ?Sized> Coordinate<T> for &C {
+    #[inline(always)]
+    fn lat(&self) -> T {
+        (**self).lat()
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> T {
+        (**self).lon()
+    }
+}
+
+#[cfg(feature = "geo")]
+impl<T: Float + geo_types::CoordNum> Coordinate<T> for geo_types::Coord<T> {
+    #[inline(always)]
+    fn lat(&self) -> T {
+        self.y
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> T {
+        self.x
+    }
+}
+
+#[cfg(feature = "geo")]
+impl<T: Float + geo_types::CoordNum> Coordinate<T> for geo_types::Point<T> {
+    #[inline(always)]
+    fn lat(&self) -> T {
+        self.y()
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> T {
+        self.x()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl Coordinate for glam::DVec2 {
+    #[inline(always)]
+    fn lat(&self) -> f64 {
+        self.y
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> f64 {
+        self.x
+    }
+}
+
+#[cfg(feature = "glam")]
+impl Coordinate<f32> for glam::Vec2 {
+    #[inline(always)]
+    fn lat(&self) -> f32 {
+        self.y
+    }
+
+    #[inline(always)]
+    fn lon(&self) -> f32 {
+        self.x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlaneProjection;
+    use crate::tests::{LUND_C, MALMO_C};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn coordinate_test() {
+        let proj = PlaneProjection::new(55.65);
+        let expected = proj.distance(MALMO_C, LUND_C);
+        let points = [MALMO_C, LUND_C];
+        let distances: Vec<f64> = points
+            .iter()
+            .map(|point| proj.distance(point, [LUND_C.0, LUND_C.1]))
+            .collect();
+        assert_eq!(distances, [expected, 0.0]);
+        let lund = GeoPoint::try_from(LUND_C).unwrap();
+        assert_eq!(proj.distance(MALMO_C, lund), expected);
+        assert_eq!(proj.heading(MALMO_C, lund), proj.heading(MALMO_C, LUND_C));
+        assert_eq!(
+            proj.distance_to_segment([LUND_C.0, LUND_C.1], (MALMO_C, lund)),
+            0.0
+        );
+
+        let lund = LonLat(LUND_C.1, LUND_C.0);
+        assert_eq!(proj.distance(MALMO_C, lund), expected);
+        assert_eq!(LonLat::from(LUND_C), lund);
+        assert_eq!(<(f64, f64)>::from(lund), LUND_C);
+
+        #[cfg(feature = "geo")]
+        {
+            let lund = geo_types::Point::new(LUND_C.1, LUND_C.0);
+            assert_eq!(proj.distance(MALMO_C, lund), expected);
+            assert_eq!(proj.distance(MALMO_C, lund.0), expected);
+        }
+        #[cfg(feature = "glam")]
+        {
+            let lund = glam::DVec2::new(LUND_C.1, LUND_C.0);
+            assert_eq!(proj.distance(MALMO_C, lund), expected);
+            let proj = proj.cast::<f32>();
+            let (malmo, lund) = (
+                (MALMO_C.0 as f32, MALMO_C.1 as f32),
+                (LUND_C.0 as f32, LUND_C.1 as f32),
+            );
+            assert_eq!(
+                proj.distance(malmo, glam::Vec2::new(lund.1, lund.0)),
+                proj.distance(malmo, lund)
+            );
+        }
+    }
+}
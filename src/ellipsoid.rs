@@ -0,0 +1,113 @@
+/// An ellipsoid model of the Earth or another planetary body the projection is built for.
+///
+/// ```
+/// use plane_projection::{Ellipsoid, PlaneProjection};
+///
+/// let proj = PlaneProjection::with_ellipsoid(55.65, Ellipsoid::SPHERE);
+/// let distance = proj.distance((55.704141722528554, 13.191304107330561), (55.60330902847681, 13.001973666557435));
+/// assert_eq!(distance as u32, 16334);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipsoid {
+    /// Equatorial (semi-major axis) radius in meters.
+    pub equatorial_radius: f64,
+    /// Flattening `(a - b) / a`, which is 0.0 for a sphere.
+    pub flattening: f64,
+}
+
+impl Ellipsoid {
+    /// World Geodetic System 1984 ellipsoid, used by GPS. The default one.
+    pub const WGS84: Self = Self::new(6378137.0, 1.0 / 298.257223563);
+
+    /// Geodetic Reference System 1980 ellipsoid, used by ITRS, ETRS89 and NAD83.
+    pub const GRS80: Self = Self::new(6378137.0, 1.0 / 298.257222101);
+
+    /// Sphere with the IUGG mean radius of the Earth, used by haversine formulas.
+    pub const SPHERE: Self = Self::new(6371008.8, 0.0);
+
+    /// The Moon as a sphere with the IAU mean radius.
+    pub const MOON: Self = Self::new(1737400.0, 0.0);
+
+    /// Mars ellipsoid with the IAU equatorial and polar radii of 3396.19 km and 3376.20 km.
+    pub const MARS: Self = Self::new(3396190.0, (3396190.0 - 3376200.0) / 3396190.0);
+
+    /// Creates an ellipsoid with the equatorial radius in meters and flattening.
+    pub const fn new(equatorial_radius: f64, flattening: f64) -> Self {
+        Self {
+            equatorial_radius,
+            flattening,
+        }
+    }
+
+    /// Squared first eccentricity.
+    #[inline(always)]
+    const fn squared_eccentricity(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
+    /// Squared `w` factor of the radii of curvature at the latitude with `cos_lat` cosine.
+    /// Based on https://en.wikipedia.org/wiki/Earth_radius#Meridional
+    #[inline(always)]
+    pub(crate) const fn curvature_w2(&self, cos_lat: f64) -> f64 {
+        1.0 / (1.0 - self.squared_eccentricity() * (1.0 - cos_lat * cos_lat))
+    }
+
+    /// Meters per degree of longitude and latitude from the curvature factor `w` and its square `w2`.
+    #[inline(always)]
+    pub(crate) const fn scales(&self, cos_lat: f64, w2: f64, w: f64) -> (f64, f64) {
+        let radius = self.equatorial_radius;
+        let lon_scale = (radius * w * cos_lat).to_radians(); // based on normal radius of curvature
+        let lat_scale = (radius * w * w2 * (1.0 - self.squared_eccentricity())).to_radians(); // based on meridonal radius of curvature
+        (lon_scale, lat_scale)
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Self::WGS84
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlaneProjection;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn ellipsoid_test() {
+        let wgs84 = PlaneProjection::new(55.65);
+        let proj = PlaneProjection::with_ellipsoid(55.65, Ellipsoid::WGS84);
+        assert_eq!(
+            proj.distance(MALMO_C, LUND_C),
+            wgs84.distance(MALMO_C, LUND_C)
+        );
+
+        // GRS80 differs from WGS84 only by 0.1 mm in the polar radius
+        let proj = PlaneProjection::with_ellipsoid(55.65, Ellipsoid::GRS80);
+        assert!((proj.distance(MALMO_C, LUND_C) - wgs84.distance(MALMO_C, LUND_C)).abs() < 1e-6);
+
+        // a degree of latitude on a sphere is the same everywhere
+        let proj = PlaneProjection::with_ellipsoid(55.65, Ellipsoid::SPHERE);
+        let equator = PlaneProjection::with_ellipsoid(0.0, Ellipsoid::SPHERE);
+        let degree = 6371008.8f64.to_radians();
+        assert!((proj.distance((55.0, 13.0), (56.0, 13.0)) - degree).abs() < 1e-6);
+        assert!((equator.distance((0.0, 13.0), (1.0, 13.0)) - degree).abs() < 1e-6);
+        assert!((equator.distance((0.0, 13.0), (0.0, 14.0)) - degree).abs() < 1e-3);
+
+        // 0.035 degrees of longitude at the Jezero crater latitude
+        let proj: PlaneProjection = PlaneProjection::with_ellipsoid(18.44, Ellipsoid::MARS);
+        let distance = proj.distance((18.4447, 77.4508), (18.4447, 77.4508 + 0.035));
+        assert_eq!(distance.round() as u32, 1969);
+        let degree =
+            PlaneProjection::with_ellipsoid(0.0, Ellipsoid::MOON).distance((0.0, 0.0), (1.0, 0.0));
+        assert!((degree - 1737400f64.to_radians()).abs() < 1e-6);
+
+        let custom = Ellipsoid::new(1000.0, 0.0);
+        let proj = PlaneProjection::with_ellipsoid(0.0, custom);
+        assert!(
+            (proj.distance((0.0, 0.0), (90.0, 0.0)) - 500.0 * core::f64::consts::PI).abs() < 1e-9
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Google [Encoded Polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+//! decoding with precision 5 (Google Maps) or 6 (OSRM, Valhalla), feeding decoded points directly
+//! into the projection without collecting them into a `Vec<LatLon>` first.
+//!
+//! ```
+//! use plane_projection::PlaneProjection;
+//!
+//! let proj = PlaneProjection::new(40.0);
+//! let length = proj.encoded_polyline_length("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5).unwrap();
+//! assert_eq!(length as u32, 801276);
+//! ```
+
+use crate::{BBox, LatLon, PlaneProjection, ProjectedPolyline};
+use alloc::vec::Vec;
+
+/// The largest supported precision. Encoded values are limited to 32 bits as in the reference encoder,
+/// so with 9 decimal digits only coordinates within ±2.1 degrees can be represented.
+pub const MAX_PRECISION: u32 = 9;
+
+/// Error returned when the encoded polyline is malformed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidPolyline {
+    /// Byte offset in the encoded string where decoding failed.
+    pub position: usize,
+}
+
+impl core::fmt::Display for InvalidPolyline {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid encoded polyline at byte {}", self.position)
+    }
+}
+
+impl core::error::Error for InvalidPolyline {}
+
+/// Iterator over the (latitude, longitude) points of an encoded polyline.
+/// Yields an error and stops if the input is malformed.
+///
+/// ```
+/// use plane_projection::encoded_polyline::EncodedPolyline;
+///
+/// let points = EncodedPolyline::new("_p~iF~ps|U_ulLnnqC", 5).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(points, vec![(38.5, -120.2), (40.7, -120.95)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EncodedPolyline<'a> {
+    encoded: &'a [u8],
+    position: usize,
+    factor: f64,
+    lat: i32,
+    lon: i32,
+}
+
+impl<'a> EncodedPolyline<'a> {
+    /// Creates a decoder for the polyline encoded with `precision` decimal digits, usually 5 or 6.
+    /// Precision above [`MAX_PRECISION`] is clamped to it.
+    pub fn new(encoded: &'a str, precision: u32) -> Self {
+        Self {
+            encoded: encoded.as_bytes(),
+            position: 0,
+            factor: 10u32.pow(precision.min(MAX_PRECISION)) as f64,
+            lat: 0,
+            lon: 0,
+        }
+    }
+
+    /// Decodes a single zigzag encoded value, which must fit into 32 bits.
+    fn next_value(&mut self) -> Result<i32, InvalidPolyline> {
+        let start = self.position;
+        let mut value = 0i64;
+        let mut shift = 0;
+        loop {
+            let error = InvalidPolyline {
+                position: self.position,
+            };
+            let byte = *self.encoded.get(self.position).ok_or(error)?;
+            // 5-bit chunks are offset by 63 and the 6th bit is set on all but the last chunk
+            // 7 chunks are enough for any 32-bit value
+            if !(63..=126).contains(&byte) || shift > 30 {
+                return Err(error);
+            }
+            self.position += 1;
+            let chunk = (byte - 63) as i64;
+            value |= (chunk & 0x1f) << shift;
+            shift += 5;
+            if chunk & 0x20 == 0 {
+                break;
+            }
+        }
+        let value = if value & 1 != 0 {
+            !(value >> 1)
+        } else {
+            value >> 1
+        };
+        i32::try_from(value).map_err(|_| InvalidPolyline { position: start })
+    }
+}
+
+impl Iterator for EncodedPolyline<'_> {
+    type Item = Result<LatLon, InvalidPolyline>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.encoded.len() {
+            return None;
+        }
+        let start = self.position;
+        let point = self.next_value().and_then(|lat| {
+            let lon = self.next_value()?;
+            let overflow = InvalidPolyline { position: start };
+            Ok((
+                self.lat.checked_add(lat).ok_or(overflow)?,
+                self.lon.checked_add(lon).ok_or(overflow)?,
+            ))
+        });
+        match point {
+            Ok((lat, lon)) => {
+                self.lat = lat;
+                self.lon = lon;
+                Some(Ok((
+                    self.lat as f64 / self.factor,
+                    self.lon as f64 / self.factor,
+                )))
+            }
+            Err(error) => {
+                // stop on the first error
+                self.position = self.encoded.len();
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl PlaneProjection {
+    /// Length in meters of the encoded polyline, see [`PlaneProjection::polyline_length()`].
+    pub fn encoded_polyline_length(
+        &self,
+        encoded: &str,
+        precision: u32,
+    ) -> Result<f64, InvalidPolyline> {
+        let mut points = EncodedPolyline::new(encoded, precision);
+        let Some(mut prev) = points.next().transpose()? else {
+            return Ok(0.0);
+        };
+        let mut length = 0.0;
+        for point in points {
+            let point = point?;
+            length += self.distance(prev, point);
+            prev = point;
+        }
+        Ok(length)
+    }
+
+    /// Bounding box of the encoded polyline points. Returns `None` if the polyline is empty.
+    pub fn encoded_polyline_bbox(
+        &self,
+        encoded: &str,
+        precision: u32,
+    ) -> Result<Option<BBox>, InvalidPolyline> {
+        let mut bbox: Option<BBox> = None;
+        for point in EncodedPolyline::new(encoded, precision) {
+            let point = point?;
+            bbox = Some(match bbox {
+                Some(BBox { min, max }) => BBox {
+                    min: (min.0.min(point.0), min.1.min(point.1)),
+                    max: (max.0.max(point.0), max.1.max(point.1)),
+                },
+                None => BBox {
+                    min: point,
+                    max: point,
+                },
+            });
+        }
+        Ok(bbox)
+    }
+}
+
+impl ProjectedPolyline {
+    /// Decodes and projects the encoded polyline in one pass, see [`ProjectedPolyline::new()`].
+    /// Empty polylines are rejected with the error at position 0.
+    pub fn from_encoded(
+        projection: &PlaneProjection,
+        encoded: &str,
+        precision: u32,
+    ) -> Result<Self, InvalidPolyline> {
+        let mut points = EncodedPolyline::new(encoded, precision);
+        let origin = points
+            .next()
+            .unwrap_or(Err(InvalidPolyline { position: 0 }))?;
+        let projected = core::iter::once(Ok((0.0, 0.0)))
+            .chain(points.map(|point| Ok(projection.project_relative(origin, point?))))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_projected(projection, origin, projected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENCODED: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+    const DECODED: [LatLon; 3] = [(38.5, -120.2), (40.7, -120.95), (43.252, -126.453)];
+
+    #[test]
+    fn encoded_polyline_test() {
+        let points = EncodedPolyline::new(ENCODED, 5)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(points, DECODED);
+        // the same values with precision 6 are 10 times smaller
+        let points = EncodedPolyline::new(ENCODED, 6)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        for (point, expected) in points.iter().zip(DECODED) {
+            assert!((point.0 * 10.0 - expected.0).abs() < 1e-9);
+            assert!((point.1 * 10.0 - expected.1).abs() < 1e-9);
+        }
+        assert_eq!(EncodedPolyline::new("", 5).next(), None);
+
+        // truncated value, missing longitude and invalid character
+        let mut points = EncodedPolyline::new("_p~iF~ps|", 5);
+        assert_eq!(points.next(), Some(Err(InvalidPolyline { position: 9 })));
+        assert_eq!(points.next(), None);
+        let mut points = EncodedPolyline::new("_p~iF~ps|U_ulL", 5);
+        assert_eq!(points.next(), Some(Ok(DECODED[0])));
+        assert_eq!(points.next(), Some(Err(InvalidPolyline { position: 14 })));
+        assert_eq!(
+            EncodedPolyline::new("_p~iF ps|U", 5).next(),
+            Some(Err(InvalidPolyline { position: 5 }))
+        );
+
+        // values beyond 32 bits and overflowing coordinates
+        let encoded = ["}", &"~".repeat(11), "C"].concat().repeat(8);
+        let mut points = EncodedPolyline::new(&encoded, 5);
+        assert_eq!(points.next(), Some(Err(InvalidPolyline { position: 7 })));
+        assert_eq!(points.next(), None);
+        let mut points = EncodedPolyline::new("______A?______A?", 5);
+        assert!(matches!(points.next(), Some(Ok(_))));
+        assert_eq!(points.next(), Some(Err(InvalidPolyline { position: 8 })));
+
+        // too high precision is clamped
+        for precision in [20, u32::MAX] {
+            assert!(
+                EncodedPolyline::new(ENCODED, precision)
+                    .zip(EncodedPolyline::new(ENCODED, MAX_PRECISION))
+                    .all(|(a, b)| a == b)
+            );
+        }
+
+        let proj = PlaneProjection::new(40.0);
+        let length = proj.encoded_polyline_length(ENCODED, 5).unwrap();
+        assert!((length - proj.polyline_length(&DECODED)).abs() < 1e-6);
+        assert_eq!(proj.encoded_polyline_length("", 5), Ok(0.0));
+        assert_eq!(
+            proj.encoded_polyline_length("_p~iF~ps|", 5),
+            Err(InvalidPolyline { position: 9 })
+        );
+
+        assert_eq!(
+            proj.encoded_polyline_bbox(ENCODED, 5),
+            Ok(Some(BBox {
+                min: (38.5, -126.453),
+                max: (43.252, -120.2)
+            }))
+        );
+        assert_eq!(proj.encoded_polyline_bbox("", 5), Ok(None));
+
+        let line = ProjectedPolyline::from_encoded(&proj, ENCODED, 5).unwrap();
+        let expected = ProjectedPolyline::new(&proj, &DECODED);
+        for point in [(39.0, -121.0), (42.0, -124.0), (45.0, -130.0)] {
+            assert_eq!(line.nearest_segment(point), expected.nearest_segment(point));
+        }
+        assert!(ProjectedPolyline::from_encoded(&proj, "_p~iF~ps|U_ulL", 5).is_err());
+        assert!(matches!(
+            ProjectedPolyline::from_encoded(&proj, "", 5),
+            Err(InvalidPolyline { position: 0 })
+        ));
+    }
+}
@@ -0,0 +1,148 @@
+//! Stable C ABI for calling the projection from C and C++. The projection is an opaque heap-allocated
+//! handle, created with [`plane_projection_new()`] and released with [`plane_projection_free()`].
+//! Build a shared library with `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! ```c
+//! typedef struct PlaneProjection PlaneProjection;
+//!
+//! PlaneProjection *plane_projection_new(double latitude);
+//! void plane_projection_free(PlaneProjection *proj);
+//! double plane_projection_distance(const PlaneProjection *proj,
+//!                                  double lat1, double lon1, double lat2, double lon2);
+//! float plane_projection_heading(const PlaneProjection *proj,
+//!                                double lat1, double lon1, double lat2, double lon2);
+//! void plane_projection_distances(const PlaneProjection *proj, double lat, double lon,
+//!                                 const double *lats, const double *lons, double *out, size_t len);
+//! ```
+
+use crate::PlaneProjection;
+use alloc::boxed::Box;
+
+/// Creates a plane projection to the Earth at provided latitude, see [`PlaneProjection::new()`].
+/// The returned handle must be released with [`plane_projection_free()`].
+#[unsafe(no_mangle)]
+pub extern "C" fn plane_projection_new(latitude: f64) -> *mut PlaneProjection {
+    Box::into_raw(Box::new(PlaneProjection::new(latitude)))
+}
+
+/// Releases the projection created with [`plane_projection_new()`]. Null is ignored.
+///
+/// # Safety
+///
+/// `proj` must be null or a handle returned by [`plane_projection_new()`] that was not released yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn plane_projection_free(proj: *mut PlaneProjection) {
+    if !proj.is_null() {
+        drop(unsafe { Box::from_raw(proj) });
+    }
+}
+
+/// Distance in meters between two points, see [`PlaneProjection::distance()`].
+///
+/// # Safety
+///
+/// `proj` must be a valid handle returned by [`plane_projection_new()`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn plane_projection_distance(
+    proj: *const PlaneProjection,
+    lat1: f64,
+    lon1: f64,
+    lat2: f64,
+    lon2: f64,
+) -> f64 {
+    unsafe { &*proj }.distance((lat1, lon1), (lat2, lon2))
+}
+
+/// Heading in degrees from the first point to the second, see [`PlaneProjection::heading()`].
+///
+/// # Safety
+///
+/// `proj` must be a valid handle returned by [`plane_projection_new()`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn plane_projection_heading(
+    proj: *const PlaneProjection,
+    lat1: f64,
+    lon1: f64,
+    lat2: f64,
+    lon2: f64,
+) -> f32 {
+    unsafe { &*proj }.heading((lat1, lon1), (lat2, lon2))
+}
+
+/// Distances in meters from the point at `lat` and `lon` to `len` points given in separate `lats` and `lons`
+/// arrays, written to `out`, see [`PlaneProjection::distances_columnar()`].
+///
+/// # Safety
+///
+/// `proj` must be a valid handle returned by [`plane_projection_new()`], `lats` and `lons` must point to
+/// `len` readable values and `out` to `len` writable values that don't overlap with the inputs.
+/// Pointers may be null only if `len` is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn plane_projection_distances(
+    proj: *const PlaneProjection,
+    lat: f64,
+    lon: f64,
+    lats: *const f64,
+    lons: *const f64,
+    out: *mut f64,
+    len: usize,
+) {
+    if len == 0 {
+        return;
+    }
+    let (lats, lons, out) = unsafe {
+        (
+            core::slice::from_raw_parts(lats, len),
+            core::slice::from_raw_parts(lons, len),
+            core::slice::from_raw_parts_mut(out, len),
+        )
+    };
+    unsafe { &*proj }.distances_columnar((lat, lon), lats, lons, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    #[test]
+    fn ffi_test() {
+        let expected = PlaneProjection::new(55.65);
+        let proj = plane_projection_new(55.65);
+        unsafe {
+            assert_eq!(
+                plane_projection_distance(proj, MALMO_C.0, MALMO_C.1, LUND_C.0, LUND_C.1),
+                expected.distance(MALMO_C, LUND_C)
+            );
+            assert_eq!(
+                plane_projection_heading(proj, MALMO_C.0, MALMO_C.1, LUND_C.0, LUND_C.1),
+                expected.heading(MALMO_C, LUND_C)
+            );
+
+            let lats = [LUND_C.0, STOCKHOLM_C.0];
+            let lons = [LUND_C.1, STOCKHOLM_C.1];
+            let mut out = [0.0; 2];
+            plane_projection_distances(
+                proj,
+                MALMO_C.0,
+                MALMO_C.1,
+                lats.as_ptr(),
+                lons.as_ptr(),
+                out.as_mut_ptr(),
+                out.len(),
+            );
+            assert_eq!(
+                out,
+                [
+                    expected.distance(MALMO_C, LUND_C),
+                    expected.distance(MALMO_C, STOCKHOLM_C)
+                ]
+            );
+            let null = core::ptr::null_mut();
+            plane_projection_distances(proj, MALMO_C.0, MALMO_C.1, null, null, null, 0);
+
+            plane_projection_free(proj);
+            plane_projection_free(core::ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Scalar type used by [`PlaneProjection`](crate::PlaneProjection) for coordinates and distances.
+///
+/// Implemented for `f64`, the default, and `f32`. Other types, like fixed-point numbers, can implement it
+/// as well: the projection scales are calculated in `f64` and converted once with [`Float::from_f64()`].
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Send
+    + Sync
+    + Sum
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Converts from `f64`, rounding if needed.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts to `f64`, rounding if needed.
+    fn to_f64(self) -> f64;
+
+    /// Square root of the value.
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($float:ty, $libm_sqrt:ident) => {
+        impl Float for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            #[inline(always)]
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                #[cfg(feature = "std")]
+                return <$float>::sqrt(self);
+                #[cfg(not(feature = "std"))]
+                return libm::$libm_sqrt(self);
+            }
+        }
+    };
+}
+
+impl_float!(f64, sqrt);
+impl_float!(f32, sqrtf);
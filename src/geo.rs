@@ -0,0 +1,110 @@
+//! [`geo_types`] interop, so geometries can be passed without converting them to (lat, lon) tuples.
+//! Note that `geo_types` uses (x, y) = (longitude, latitude) order.
+//!
+//! ```
+//! use geo_types::{LineString, Point};
+//! use plane_projection::PlaneProjection;
+//!
+//! let proj = PlaneProjection::new(55.65);
+//! let distance = proj.geo_distance(Point::new(13.1913041, 55.7041417), Point::new(13.0019737, 55.6033090));
+//! assert_eq!(distance as u32, 16373);
+//!
+//! let line = LineString::from(vec![(13.1913041, 55.7041417), (13.0019737, 55.6033090)]);
+//! assert_eq!(proj.geo_length(&line) as u32, 16373);
+//! ```
+
+use crate::{LatLon, PlaneProjection, PointOnLine};
+use alloc::vec::Vec;
+use geo_types::{Coord, LineString, Point, Polygon};
+
+/// Converts `geo_types` coordinate to (latitude, longitude).
+#[inline(always)]
+fn lat_lon(coord: Coord<f64>) -> LatLon {
+    (coord.y, coord.x)
+}
+
+/// Converts all the line coordinates to (latitude, longitude).
+fn lat_lons(line: &LineString<f64>) -> Vec<LatLon> {
+    line.coords().map(|&coord| lat_lon(coord)).collect()
+}
+
+impl PlaneProjection {
+    /// Distance in meters between two points, see [`PlaneProjection::distance()`].
+    #[inline(always)]
+    pub fn geo_distance(&self, a: Point<f64>, b: Point<f64>) -> f64 {
+        self.distance(lat_lon(a.0), lat_lon(b.0))
+    }
+
+    /// Length in meters of the line, see [`PlaneProjection::polyline_length()`].
+    pub fn geo_length(&self, line: &LineString<f64>) -> f64 {
+        line.lines()
+            .map(|segment| self.distance(lat_lon(segment.start), lat_lon(segment.end)))
+            .sum()
+    }
+
+    /// Area in square meters of the polygon excluding its holes, see [`PlaneProjection::polygon_area()`].
+    pub fn geo_area(&self, polygon: &Polygon<f64>) -> f64 {
+        let holes = polygon.interiors().iter().map(lat_lons).collect::<Vec<_>>();
+        self.polygon_area(&lat_lons(polygon.exterior()), &holes)
+    }
+
+    /// Finds the closest to `point` location on the line, see [`PlaneProjection::point_on_line()`].
+    /// Returns `None` if the line is empty.
+    pub fn geo_point_on_line(
+        &self,
+        point: Point<f64>,
+        line: &LineString<f64>,
+    ) -> Option<PointOnLine> {
+        if line.0.is_empty() {
+            return None;
+        }
+        Some(self.point_on_line(lat_lon(point.0), &lat_lons(line)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+    use geo_types::polygon;
+
+    fn point(point: LatLon) -> Point<f64> {
+        Point::new(point.1, point.0)
+    }
+
+    #[test]
+    fn geo_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.geo_distance(point(MALMO_C), point(LUND_C)),
+            proj.distance(MALMO_C, LUND_C)
+        );
+
+        let line = LineString::from(vec![
+            (MALMO_C.1, MALMO_C.0),
+            (LUND_C.1, LUND_C.0),
+            (13.3, 55.8),
+        ]);
+        let points = [MALMO_C, LUND_C, (55.8, 13.3)];
+        assert_eq!(proj.geo_length(&line), proj.polyline_length(&points));
+        assert_eq!(proj.geo_length(&LineString::new(vec![])), 0.0);
+
+        let query = (55.67817981392954, 13.058789566271836);
+        assert_eq!(
+            proj.geo_point_on_line(point(query), &line),
+            Some(proj.point_on_line(query, &points))
+        );
+        assert_eq!(
+            proj.geo_point_on_line(point(query), &LineString::new(vec![])),
+            None
+        );
+
+        let polygon = polygon!(
+            exterior: [(x: 13.0, y: 55.6), (x: 13.2, y: 55.6), (x: 13.2, y: 55.7), (x: 13.0, y: 55.7)],
+            interiors: [[(x: 13.05, y: 55.62), (x: 13.1, y: 55.62), (x: 13.1, y: 55.65)]],
+        );
+        let expected = proj.area(&[(55.6, 13.0), (55.6, 13.2), (55.7, 13.2), (55.7, 13.0)])
+            - proj.area(&[(55.62, 13.05), (55.62, 13.1), (55.65, 13.1)]);
+        assert!((proj.geo_area(&polygon) - expected).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::LatLon;
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+
+/// A validated coordinate with finite latitude in range [-90.0, 90.0] and longitude normalized to
+/// [-180.0, 180.0] degrees. Named accessors make it hard to swap latitude and longitude, while
+/// conversions to and from [`LatLon`] keep it usable with all the projection methods.
+/// Point methods like [`PlaneProjection::distance()`](crate::PlaneProjection::distance()) accept it directly,
+/// see [`Coordinate`](crate::Coordinate).
+///
+/// ```
+/// use plane_projection::{GeoPoint, LatLon, PlaneProjection};
+///
+/// let lund = GeoPoint::try_new(55.7041417, 13.1913041).unwrap();
+/// let malmo = GeoPoint::try_from((55.6033090, 13.0019737)).unwrap();
+/// assert!((GeoPoint::try_new(55.7, 373.19).unwrap().lon() - 13.19).abs() < 1e-9);
+/// assert!(GeoPoint::try_new(555.7041417, 13.1913041).is_err());
+///
+/// let proj = PlaneProjection::new(55.65);
+/// assert_eq!(proj.distance(lund, malmo) as u32, 16373);
+///
+/// let (lat, lon): LatLon = lund.into();
+/// assert_eq!((lat, lon), (lund.lat(), lund.lon()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "LatLon", into = "LatLon")
+)]
+pub struct GeoPoint {
+    lat: f64,
+    lon: f64,
+}
+
+impl GeoPoint {
+    /// Creates a point from latitude and longitude in degrees, wrapping the longitude around the Earth.
+    /// Fails if any of the values is not finite or the latitude is outside of range [-90.0, 90.0].
+    pub fn try_new(lat: f64, lon: f64) -> Result<Self, InvalidCoordinate> {
+        if !lat.is_finite() || !lon.is_finite() || !(-90.0..=90.0).contains(&lat) {
+            return Err(InvalidCoordinate { lat, lon });
+        }
+        let lon = if (-180.0..=180.0).contains(&lon) {
+            lon
+        } else {
+            lon - 360.0 * ((lon + 180.0) / 360.0).floor()
+        };
+        Ok(Self { lat, lon })
+    }
+
+    /// Latitude in degrees.
+    #[inline(always)]
+    pub fn lat(&self) -> f64 {
+        self.lat
+    }
+
+    /// Longitude in degrees.
+    #[inline(always)]
+    pub fn lon(&self) -> f64 {
+        self.lon
+    }
+}
+
+impl TryFrom<LatLon> for GeoPoint {
+    type Error = InvalidCoordinate;
+
+    fn try_from((lat, lon): LatLon) -> Result<Self, Self::Error> {
+        Self::try_new(lat, lon)
+    }
+}
+
+impl From<GeoPoint> for LatLon {
+    fn from(point: GeoPoint) -> Self {
+        (point.lat, point.lon)
+    }
+}
+
+/// Error returned by [`GeoPoint::try_new()`] for non-finite values or a latitude outside of [-90.0, 90.0].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvalidCoordinate {
+    /// The rejected latitude in degrees.
+    pub lat: f64,
+    /// The rejected longitude in degrees.
+    pub lon: f64,
+}
+
+impl core::fmt::Display for InvalidCoordinate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid coordinate ({}, {})", self.lat, self.lon)
+    }
+}
+
+impl core::error::Error for InvalidCoordinate {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::MALMO_C;
+
+    #[test]
+    fn geo_point_test() {
+        let point = GeoPoint::try_from(MALMO_C).unwrap();
+        assert_eq!((point.lat(), point.lon()), MALMO_C);
+        assert_eq!(LatLon::from(point), MALMO_C);
+
+        // longitude is wrapped around the Earth, while the antimeridian itself is kept as is
+        for (lon, expected) in [
+            (180.0, 180.0),
+            (-180.0, -180.0),
+            (190.0, -170.0),
+            (-190.0, 170.0),
+            (540.0, -180.0),
+            (725.0, 5.0),
+        ] {
+            assert_eq!(GeoPoint::try_new(0.0, lon).unwrap().lon(), expected);
+        }
+        assert!(GeoPoint::try_new(90.0, 0.0).is_ok());
+        assert!(GeoPoint::try_new(-90.0, 0.0).is_ok());
+
+        for (lat, lon) in [
+            (90.1, 0.0),
+            (-90.1, 0.0),
+            (f64::NAN, 0.0),
+            (0.0, f64::NAN),
+            (0.0, f64::INFINITY),
+            (f64::NEG_INFINITY, 0.0),
+        ] {
+            let error = GeoPoint::try_new(lat, lon).unwrap_err();
+            assert!(error.lat.total_cmp(&lat).is_eq() && error.lon.total_cmp(&lon).is_eq());
+        }
+    }
+}
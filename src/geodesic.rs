@@ -0,0 +1,86 @@
+//! Exact geodesic reference methods based on [`geographiclib_rs`], useful to validate the plane projection
+//! results and to handle the rare long-distance pairs.
+
+use crate::{Ellipsoid, LatLon, normalize_heading};
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+use std::sync::OnceLock;
+
+/// WGS84 geodesic, cached since its construction precomputes the series coefficients.
+fn wgs84() -> &'static Geodesic {
+    static WGS84: OnceLock<Geodesic> = OnceLock::new();
+    WGS84.get_or_init(Geodesic::wgs84)
+}
+
+/// Geodesic (shortest path) distance in meters between two points on the WGS84 ellipsoid,
+/// accurate to a few nanometers at any distance.
+pub fn geodesic_distance(a: LatLon, b: LatLon) -> f64 {
+    wgs84().inverse(a.0, a.1, b.0, b.1)
+}
+
+/// Initial heading (azimuth) in degrees of the geodesic from point `a` to point `b` on the WGS84 ellipsoid,
+/// using the same convention as [`PlaneProjection::heading()`](crate::PlaneProjection::heading).
+pub fn geodesic_heading(a: LatLon, b: LatLon) -> f64 {
+    let (azimuth, _, _) = wgs84().inverse(a.0, a.1, b.0, b.1);
+    normalize_heading(azimuth)
+}
+
+impl Ellipsoid {
+    /// Geodesic distance in meters between two points on the ellipsoid, see [`geodesic_distance()`].
+    /// Slower than [`geodesic_distance()`] as the geodesic coefficients are calculated on every call.
+    pub fn geodesic_distance(&self, a: LatLon, b: LatLon) -> f64 {
+        Geodesic::new(self.equatorial_radius, self.flattening).inverse(a.0, a.1, b.0, b.1)
+    }
+
+    /// Initial heading in degrees of the geodesic between two points on the ellipsoid, see [`geodesic_heading()`].
+    pub fn geodesic_heading(&self, a: LatLon, b: LatLon) -> f64 {
+        let (azimuth, _, _) =
+            Geodesic::new(self.equatorial_radius, self.flattening).inverse(a.0, a.1, b.0, b.1);
+        normalize_heading(azimuth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+    use crate::{PlaneProjection, haversine_distance};
+
+    #[test]
+    fn geodesic_test() {
+        assert_eq!(geodesic_distance(MALMO_C, MALMO_C), 0.0);
+        assert_eq!(geodesic_distance(MALMO_C, LUND_C).round() as u32, 16_373);
+        assert_eq!(
+            geodesic_distance(MALMO_C, STOCKHOLM_C).round() as u32,
+            513_861
+        );
+        assert_eq!(
+            Ellipsoid::WGS84.geodesic_distance(MALMO_C, STOCKHOLM_C),
+            geodesic_distance(MALMO_C, STOCKHOLM_C)
+        );
+        let sphere = Ellipsoid::SPHERE.geodesic_distance(MALMO_C, STOCKHOLM_C);
+        assert!((sphere - haversine_distance(MALMO_C, STOCKHOLM_C)).abs() < 1e-6);
+
+        // the plane projection agrees with the geodesic for local pairs
+        let proj = PlaneProjection::new((MALMO_C.0 + LUND_C.0) * 0.5);
+        let error = proj.distance(MALMO_C, LUND_C) - geodesic_distance(MALMO_C, LUND_C);
+        assert!(error.abs() < 0.1);
+        let heading = geodesic_heading(MALMO_C, LUND_C);
+        assert!((heading - proj.heading(MALMO_C, LUND_C) as f64).abs() < 0.1);
+        assert!((geodesic_heading(LUND_C, MALMO_C) - (heading + 180.0)).abs() < 0.2);
+
+        assert_eq!(geodesic_heading((0.0, 0.0), (1.0, 0.0)), 0.0);
+        assert_eq!(geodesic_heading((0.0, 0.0), (0.0, 1.0)), 90.0);
+        assert_eq!(geodesic_heading((0.0, 0.0), (-1.0, 0.0)), 180.0);
+        assert_eq!(geodesic_heading((0.0, 0.0), (0.0, -1.0)), 270.0);
+        assert_eq!(
+            Ellipsoid::GRS80.geodesic_heading((0.0, 0.0), (0.0, -1.0)),
+            270.0
+        );
+
+        // tiny negative azimuths don't round up to 360.0
+        let heading = geodesic_heading((0.0, 0.0), (10.0, -1e-16));
+        assert!((0.0..360.0).contains(&heading), "{heading}");
+        let heading = Ellipsoid::GRS80.geodesic_heading((0.0, 0.0), (10.0, -1e-16));
+        assert!((0.0..360.0).contains(&heading), "{heading}");
+    }
+}
@@ -0,0 +1,319 @@
+#[cfg(not(feature = "std"))]
+use crate::Float as _;
+use crate::{LatLon, PlaneProjection, project_to_origin_segment};
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    Polygon {
+        /// Ring vertices in the local Cartesian coordinates, without the closing point.
+        points: Vec<(f64, f64)>,
+        /// Bounding box of the ring in the local Cartesian coordinates.
+        min: (f64, f64),
+        max: (f64, f64),
+    },
+}
+
+/// A circular or polygonal fence with the geometry projected once, so checks against it are allocation-free.
+///
+/// ```
+/// use plane_projection::{Geofence, PlaneProjection};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let fence = Geofence::circle(&proj, (55.7041417, 13.1913041), 1000.0);
+/// assert!(fence.contains((55.705, 13.19)));
+/// assert_eq!(fence.distance_to_boundary((55.6033090, 13.0019737)) as u32, 15373);
+/// ```
+#[derive(Clone)]
+pub struct Geofence {
+    projection: PlaneProjection,
+    /// Origin of the local Cartesian coordinates, which is the circle center or the first ring vertex.
+    origin: LatLon,
+    shape: Shape,
+}
+
+impl Geofence {
+    /// Creates a fence of all points within `radius` meters from the `center`.
+    pub fn circle(projection: &PlaneProjection, center: LatLon, radius: f64) -> Self {
+        Self {
+            projection: projection.clone(),
+            origin: center,
+            shape: Shape::Circle { radius },
+        }
+    }
+
+    /// Creates a fence of all points inside the polygon ring, which can be either closed or not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring is empty.
+    pub fn polygon(projection: &PlaneProjection, ring: &[LatLon]) -> Self {
+        let origin = ring[0];
+        let ring = match ring {
+            [first, .., last] if first == last => &ring[..ring.len() - 1],
+            _ => ring,
+        };
+        let points = ring
+            .iter()
+            .map(|&point| projection.project_relative(origin, point))
+            .collect::<Vec<_>>();
+        let (min, max) = points.iter().fold(
+            (points[0], points[0]),
+            |(min, max): ((f64, f64), (f64, f64)), p| {
+                (
+                    (min.0.min(p.0), min.1.min(p.1)),
+                    (max.0.max(p.0), max.1.max(p.1)),
+                )
+            },
+        );
+        Self {
+            projection: projection.clone(),
+            origin,
+            shape: Shape::Polygon { points, min, max },
+        }
+    }
+
+    /// The projection the fence geometry is projected with.
+    pub fn projection(&self) -> &PlaneProjection {
+        &self.projection
+    }
+
+    /// Checks if the point is inside the fence.
+    /// Points exactly on the polygon boundary may be treated either as inside or outside.
+    pub fn contains(&self, point: LatLon) -> bool {
+        let p = self.projection.project_relative(self.origin, point);
+        match &self.shape {
+            Shape::Circle { radius } => p.0 * p.0 + p.1 * p.1 <= radius * radius,
+            Shape::Polygon { points, min, max } => {
+                if p.0 < min.0 || p.0 > max.0 || p.1 < min.1 || p.1 > max.1 {
+                    return false;
+                }
+                // Ray casting to the East, see [`PlaneProjection::contains()`]
+                let mut prev = points[points.len() - 1];
+                let mut inside = false;
+                for &vertex in points {
+                    if (vertex.0 > p.0) != (prev.0 > p.0) {
+                        let east =
+                            vertex.1 + (prev.1 - vertex.1) * (p.0 - vertex.0) / (prev.0 - vertex.0);
+                        if east > p.1 {
+                            inside = !inside;
+                        }
+                    }
+                    prev = vertex;
+                }
+                inside
+            }
+        }
+    }
+
+    /// Signed distance in meters from the point to the fence boundary,
+    /// which is positive outside the fence and negative inside.
+    pub fn distance_to_boundary(&self, point: LatLon) -> f64 {
+        let p = self.projection.project_relative(self.origin, point);
+        match &self.shape {
+            Shape::Circle { radius } => (p.0 * p.0 + p.1 * p.1).sqrt() - radius,
+            Shape::Polygon { points, .. } => {
+                let mut prev = points[points.len() - 1];
+                let mut square_distance = f64::INFINITY;
+                for &vertex in points {
+                    let edge = (vertex.0 - prev.0, vertex.1 - prev.1);
+                    let (_, d) = project_to_origin_segment((p.0 - prev.0, p.1 - prev.1), edge);
+                    square_distance = square_distance.min(d);
+                    prev = vertex;
+                }
+                let distance = square_distance.sqrt();
+                if self.contains(point) {
+                    -distance
+                } else {
+                    distance
+                }
+            }
+        }
+    }
+}
+
+/// An event emitted by [`GeofenceMonitor::update()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeofenceEvent {
+    /// The position entered the fence.
+    Enter,
+    /// The position left the fence.
+    Exit,
+    /// The position stayed inside the fence for the dwell time, emitted once per visit.
+    Dwell,
+}
+
+/// Detects enter, exit and dwell events of a position stream relative to a [`Geofence`].
+///
+/// Hysteresis suppresses GPS jitter near the boundary: a position enters the fence only when it is at least
+/// `enter_margin` meters inside and exits only when it is at least `exit_margin` meters outside.
+/// For a circular fence of radius `r` this is the enter radius `r - enter_margin` and the exit radius `r + exit_margin`.
+///
+/// ```
+/// use plane_projection::{Geofence, GeofenceEvent, GeofenceMonitor, PlaneProjection};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let fence = Geofence::circle(&proj, (55.7041417, 13.1913041), 100.0);
+/// let mut monitor = GeofenceMonitor::new(fence, 10.0, 10.0, 60.0);
+/// assert_eq!(monitor.update((55.7041, 13.1913), 0.0), Some(GeofenceEvent::Enter));
+/// assert_eq!(monitor.update((55.7042, 13.1913), 30.0), None);
+/// assert_eq!(monitor.update((55.7041, 13.1914), 60.0), Some(GeofenceEvent::Dwell));
+/// assert_eq!(monitor.update((55.7141, 13.1913), 90.0), Some(GeofenceEvent::Exit));
+/// ```
+#[derive(Clone)]
+pub struct GeofenceMonitor {
+    fence: Geofence,
+    enter_margin: f64,
+    exit_margin: f64,
+    dwell_time: f64,
+    /// Timestamp of the last [`GeofenceEvent::Enter`] while inside the fence.
+    entered_at: Option<f64>,
+    dwell_reported: bool,
+}
+
+impl GeofenceMonitor {
+    /// Creates a monitor that starts outside of the `fence`. Margins are in meters and `dwell_time` is in the same
+    /// units as timestamps passed to [`GeofenceMonitor::update()`], for example seconds.
+    pub fn new(fence: Geofence, enter_margin: f64, exit_margin: f64, dwell_time: f64) -> Self {
+        Self {
+            fence,
+            enter_margin,
+            exit_margin,
+            dwell_time,
+            entered_at: None,
+            dwell_reported: false,
+        }
+    }
+
+    /// The monitored fence.
+    pub fn fence(&self) -> &Geofence {
+        &self.fence
+    }
+
+    /// Checks if the position is currently considered inside the fence.
+    pub fn is_inside(&self) -> bool {
+        self.entered_at.is_some()
+    }
+
+    /// Consumes the next position fix at `timestamp` and returns the event it triggers, if any.
+    /// Timestamps are expected to be non-decreasing.
+    pub fn update(&mut self, point: LatLon, timestamp: f64) -> Option<GeofenceEvent> {
+        let distance = self.fence.distance_to_boundary(point);
+        match self.entered_at {
+            None if distance <= -self.enter_margin => {
+                self.entered_at = Some(timestamp);
+                self.dwell_reported = false;
+                Some(GeofenceEvent::Enter)
+            }
+            Some(_) if distance >= self.exit_margin => {
+                self.entered_at = None;
+                Some(GeofenceEvent::Exit)
+            }
+            Some(entered_at)
+                if !self.dwell_reported && timestamp - entered_at >= self.dwell_time =>
+            {
+                self.dwell_reported = true;
+                Some(GeofenceEvent::Dwell)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn circle_test() {
+        let proj = PlaneProjection::new(55.65);
+        let fence = Geofence::circle(&proj, LUND_C, 1000.0);
+        assert!(fence.contains(LUND_C));
+        assert!(!fence.contains(MALMO_C));
+        assert!(fence.contains(proj.offset(LUND_C, 999.0, 0.0)));
+        assert!(!fence.contains(proj.offset(LUND_C, 0.0, -1001.0)));
+
+        assert_eq!(fence.distance_to_boundary(LUND_C), -1000.0);
+        let distance = fence.distance_to_boundary(MALMO_C);
+        assert!((distance - (proj.distance(LUND_C, MALMO_C) - 1000.0)).abs() < 1e-6);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let fence = Geofence::circle(&proj, (0.0, 180.0), 1000.0);
+        assert!(fence.contains((0.0, -179.995)));
+        assert!(fence.contains((0.0, 179.995)));
+    }
+
+    #[test]
+    fn polygon_test() {
+        let proj = PlaneProjection::new(0.0);
+        let ring = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)];
+        let fence = Geofence::polygon(&proj, &ring);
+        assert!(fence.contains((0.5, 0.5)));
+        assert!(!fence.contains((1.5, 0.5)));
+        assert!(!fence.contains((0.5, -0.5)));
+        // same result for not closed rings
+        let open = Geofence::polygon(&proj, &ring[..4]);
+        assert!(open.contains((0.5, 0.5)));
+        assert!(!open.contains((1.5, 0.5)));
+
+        let edge = proj.distance((0.5, 0.0), (0.5, 0.1));
+        assert!((fence.distance_to_boundary((0.5, 0.1)) + edge).abs() < 1e-6);
+        assert!((fence.distance_to_boundary((0.5, -0.1)) - edge).abs() < 1e-6);
+        assert_eq!(
+            fence.distance_to_boundary((2.0, 2.0)),
+            proj.distance((1.0, 1.0), (2.0, 2.0))
+        );
+
+        // concave ring
+        let ring = [
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 2.0),
+            (1.5, 2.0),
+            (1.5, 0.5),
+            (0.0, 0.5),
+        ];
+        let fence = Geofence::polygon(&proj, &ring);
+        assert!(fence.contains((1.7, 1.0)));
+        assert!(!fence.contains((1.0, 1.0)));
+        assert_eq!(
+            fence.contains((0.25, 0.25)),
+            proj.contains(&ring, (0.25, 0.25))
+        );
+    }
+
+    #[test]
+    fn monitor_test() {
+        let proj = PlaneProjection::new(55.65);
+        let fence = Geofence::circle(&proj, LUND_C, 100.0);
+        let mut monitor = GeofenceMonitor::new(fence, 10.0, 20.0, 60.0);
+        let at = |distance: f64| proj.offset(LUND_C, distance, 0.0);
+        assert!(!monitor.is_inside());
+
+        // jitter around the boundary doesn't trigger events
+        assert_eq!(monitor.update(at(95.0), 0.0), None);
+        assert_eq!(monitor.update(at(105.0), 1.0), None);
+        assert_eq!(monitor.update(at(89.0), 2.0), Some(GeofenceEvent::Enter));
+        assert!(monitor.is_inside());
+        assert_eq!(monitor.update(at(110.0), 3.0), None);
+        assert_eq!(monitor.update(at(50.0), 4.0), None);
+        assert_eq!(monitor.update(at(50.0), 61.0), None);
+        assert_eq!(monitor.update(at(115.0), 62.0), Some(GeofenceEvent::Dwell));
+        assert_eq!(monitor.update(at(50.0), 100.0), None);
+        assert_eq!(monitor.update(at(121.0), 101.0), Some(GeofenceEvent::Exit));
+        assert!(!monitor.is_inside());
+        assert_eq!(monitor.update(at(95.0), 102.0), None);
+
+        // the next visit reports dwell again
+        assert_eq!(monitor.update(MALMO_C, 103.0), None);
+        assert_eq!(monitor.update(LUND_C, 200.0), Some(GeofenceEvent::Enter));
+        assert_eq!(monitor.update(LUND_C, 259.0), None);
+        assert_eq!(monitor.update(LUND_C, 260.0), Some(GeofenceEvent::Dwell));
+        assert_eq!(monitor.update(MALMO_C, 261.0), Some(GeofenceEvent::Exit));
+    }
+}
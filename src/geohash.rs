@@ -0,0 +1,265 @@
+//! [Geohash](https://en.wikipedia.org/wiki/Geohash) encoding and decoding, where every extra character
+//! makes the cell 32 times smaller, from ~5000km at precision 1 down to a few centimeters at precision 12.
+//!
+//! ```
+//! use plane_projection::geohash;
+//!
+//! let hash = geohash::encode((55.7041417, 13.1913041), 7);
+//! assert_eq!(hash, "u3ckuht");
+//! assert!(geohash::decode(&hash).unwrap().contains((55.7041417, 13.1913041)));
+//! ```
+
+use crate::{BBox, LatLon, PlaneProjection, wrap_lon};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Maximum supported geohash length.
+pub const MAX_PRECISION: usize = 12;
+
+/// Geohash base32 alphabet.
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Error returned when the geohash is malformed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidGeohash {
+    /// Byte offset in the geohash where decoding failed.
+    pub position: usize,
+}
+
+impl core::fmt::Display for InvalidGeohash {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid geohash at byte {}", self.position)
+    }
+}
+
+impl core::error::Error for InvalidGeohash {}
+
+/// Encodes the point into a geohash with `precision` characters.
+///
+/// # Panics
+///
+/// Panics if `precision` is 0 or exceeds [`MAX_PRECISION`].
+pub fn encode(point: LatLon, precision: usize) -> String {
+    assert!(
+        (1..=MAX_PRECISION).contains(&precision),
+        "`precision` must be in range [1, {MAX_PRECISION}]"
+    );
+    let (lat_bits, lon_bits) = cell_bits(precision);
+    let lat = bucket(point.0.clamp(-90.0, 90.0) + 90.0, 180.0, lat_bits);
+    let lon = bucket(wrap_lon(point.1) + 180.0, 360.0, lon_bits);
+    encode_cell(lat, lon, precision)
+}
+
+/// Decodes the geohash into its cell bounding box. Decoding is case-insensitive.
+pub fn decode(hash: &str) -> Result<BBox, InvalidGeohash> {
+    if hash.is_empty() || hash.len() > MAX_PRECISION {
+        return Err(InvalidGeohash {
+            position: hash.len().min(MAX_PRECISION),
+        });
+    }
+    let (mut lat, mut lon, mut lat_bits, mut lon_bits) = (0u64, 0u64, 0, 0);
+    for (position, byte) in hash.bytes().enumerate() {
+        let value = ALPHABET
+            .iter()
+            .position(|&c| c == byte.to_ascii_lowercase())
+            .ok_or(InvalidGeohash { position })?;
+        // Bits alternate starting from longitude, so odd characters start from latitude
+        for bit in (0..5).rev() {
+            let bit = (value >> bit) as u64 & 1;
+            if (lat_bits + lon_bits) % 2 == 0 {
+                lon = lon << 1 | bit;
+                lon_bits += 1;
+            } else {
+                lat = lat << 1 | bit;
+                lat_bits += 1;
+            }
+        }
+    }
+    Ok(cell_bbox(lat, lon, (lat_bits, lon_bits)))
+}
+
+impl PlaneProjection {
+    /// Geohashes with `precision` characters of the cells that intersect the circle of `radius` meters around
+    /// the `center`, useful to query databases sharded by geohash prefixes.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let hashes = proj.geohashes_within_radius((55.7041417, 13.1913041), 100.0, 7);
+    /// assert!(hashes.contains(&"u3ckuht".to_string()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is 0 or exceeds [`MAX_PRECISION`].
+    pub fn geohashes_within_radius(
+        &self,
+        center: LatLon,
+        radius: f64,
+        precision: usize,
+    ) -> Vec<String> {
+        assert!(
+            (1..=MAX_PRECISION).contains(&precision),
+            "`precision` must be in range [1, {MAX_PRECISION}]"
+        );
+        let bits = cell_bits(precision);
+        let square_radius = radius * radius;
+        let bbox = self.buffer_point(center, radius);
+        let (start, end) = bbox.lon_range();
+        let (lon_start, lon_end) = (
+            bucket_unclamped(start + 180.0, 360.0, bits.1),
+            bucket_unclamped(end + 180.0, 360.0, bits.1),
+        );
+        // All longitudes are covered once, even if the range wraps around the Earth
+        let lon_end = lon_end.min(lon_start + (1 << bits.1) - 1);
+        let lat_start = bucket(bbox.min.0 + 90.0, 180.0, bits.0);
+        let lat_end = bucket(bbox.max.0 + 90.0, 180.0, bits.0);
+
+        let mut hashes = Vec::new();
+        for lat in lat_start..=lat_end {
+            for lon in lon_start..=lon_end {
+                let lon = lon % (1 << bits.1);
+                if self.square_distance_to_bbox(center, cell_bbox(lat, lon, bits)) <= square_radius
+                {
+                    hashes.push(encode_cell(lat, lon, precision));
+                }
+            }
+        }
+        hashes
+    }
+}
+
+/// Number of latitude and longitude bits in a geohash with `precision` characters.
+#[inline(always)]
+fn cell_bits(precision: usize) -> (u32, u32) {
+    let bits = 5 * precision as u32;
+    (bits / 2, bits.div_ceil(2))
+}
+
+/// Index of the cell that contains `value` in range [0.0, `range`] split into `2^bits` cells,
+/// where `range` itself belongs to the last cell.
+#[inline(always)]
+fn bucket(value: f64, range: f64, bits: u32) -> u64 {
+    bucket_unclamped(value, range, bits).min((1 << bits) - 1)
+}
+
+/// Same as [`bucket()`], but allows values beyond `range` for longitudes over the antimeridian.
+#[inline(always)]
+fn bucket_unclamped(value: f64, range: f64, bits: u32) -> u64 {
+    (value / range * (1u64 << bits) as f64).max(0.0) as u64
+}
+
+/// Bounding box of the cell with `lat` and `lon` indices.
+fn cell_bbox(lat: u64, lon: u64, bits: (u32, u32)) -> BBox {
+    let height = 180.0 / (1u64 << bits.0) as f64;
+    let width = 360.0 / (1u64 << bits.1) as f64;
+    BBox {
+        min: (lat as f64 * height - 90.0, lon as f64 * width - 180.0),
+        max: (
+            (lat + 1) as f64 * height - 90.0,
+            (lon + 1) as f64 * width - 180.0,
+        ),
+    }
+}
+
+/// Geohash of the cell with `lat` and `lon` indices by interleaving their bits, starting from longitude.
+fn encode_cell(lat: u64, lon: u64, precision: usize) -> String {
+    let (lat_bits, lon_bits) = cell_bits(precision);
+    let mut interleaved = 0u64;
+    for i in 0..lon_bits {
+        interleaved = interleaved << 1 | (lon >> (lon_bits - 1 - i)) & 1;
+        if i < lat_bits {
+            interleaved = interleaved << 1 | (lat >> (lat_bits - 1 - i)) & 1;
+        }
+    }
+    (0..precision)
+        .rev()
+        .map(|i| ALPHABET[(interleaved >> (5 * i)) as usize & 31] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn encode_test() {
+        // reference values from https://en.wikipedia.org/wiki/Geohash
+        assert_eq!(encode((42.605, -5.603), 5), "ezs42");
+        assert_eq!(encode((57.64911, 10.40744), 11), "u4pruydqqvj");
+        assert_eq!(encode((0.0, 0.0), 1), "s");
+        assert_eq!(encode((-90.0, -180.0), 12), "000000000000");
+        assert_eq!(encode((90.0, 180.0), 12), "zzzzzzzzzzzz");
+        assert_eq!(encode((0.0, 360.0), 6), encode((0.0, 0.0), 6));
+        assert_eq!(&encode(MALMO_C, 12)[..5], &encode(MALMO_C, 5));
+    }
+
+    #[test]
+    fn decode_test() {
+        let bbox = decode("ezs42").unwrap();
+        assert!(bbox.contains((42.605, -5.603)));
+        assert!((bbox.center().0 - 42.605).abs() < 0.03 && (bbox.center().1 + 5.603).abs() < 0.03);
+        assert_eq!(decode("EZS42"), decode("ezs42"));
+        assert_eq!(
+            decode("s"),
+            Ok(BBox {
+                min: (0.0, 0.0),
+                max: (45.0, 45.0)
+            })
+        );
+
+        for precision in 1..=MAX_PRECISION {
+            for point in [LUND_C, MALMO_C, (-33.86, 151.21), (40.71, -74.01)] {
+                let hash = encode(point, precision);
+                let bbox = decode(&hash).unwrap();
+                assert!(bbox.contains(point));
+                assert_eq!(encode(bbox.center(), precision), hash);
+            }
+        }
+
+        assert_eq!(decode(""), Err(InvalidGeohash { position: 0 }));
+        assert_eq!(decode("ezsa2"), Err(InvalidGeohash { position: 3 }));
+        assert_eq!(
+            decode("0123456789bcd"),
+            Err(InvalidGeohash { position: 12 })
+        );
+    }
+
+    #[test]
+    fn geohashes_within_radius_test() {
+        let proj = PlaneProjection::new(55.65);
+        let hash = encode(LUND_C, 6);
+        let center = decode(&hash).unwrap().center();
+        assert_eq!(
+            proj.geohashes_within_radius(center, 10.0, 6),
+            [hash.as_str()]
+        );
+
+        let hashes = proj.geohashes_within_radius(LUND_C, 1000.0, 6);
+        assert!(hashes.contains(&hash));
+        for hash in &hashes {
+            let bbox = decode(hash).unwrap();
+            assert!(proj.square_distance_to_bbox(LUND_C, bbox) <= 1000.0 * 1000.0);
+        }
+        // every point within the radius is covered
+        for i in 0..100 {
+            let angle = (i as f64 * 3.6).to_radians();
+            let point = proj.offset(LUND_C, angle.sin() * 999.0, angle.cos() * 999.0);
+            assert!(hashes.contains(&encode(point, 6)));
+        }
+
+        // spans the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let hashes = proj.geohashes_within_radius((0.0, 180.0), 1000.0, 5);
+        assert!(hashes.contains(&encode((0.0, 179.999), 5)));
+        assert!(hashes.contains(&encode((0.0, -179.999), 5)));
+        assert!(hashes.contains(&encode((-0.001, -179.999), 5)));
+        assert_eq!(hashes.len(), 4);
+
+        // covers all longitudes without duplicates
+        let hashes = proj.geohashes_within_radius((0.0, 0.0), 30_000_000.0, 1);
+        assert_eq!(hashes.len(), 32);
+    }
+}
@@ -0,0 +1,255 @@
+//! [GeoJSON](https://docs.rs/geojson) geometries support, computing metrics over all parts of
+//! the geometry, including `Multi*` geometries and geometry collections.
+//! Note that GeoJSON uses (longitude, latitude) order in positions.
+//!
+//! ```
+//! use geojson::Geometry;
+//! use plane_projection::PlaneProjection;
+//!
+//! let geometry: Geometry = r#"{
+//!     "type": "LineString",
+//!     "coordinates": [[13.1913041, 55.7041417], [13.0019737, 55.6033090]]
+//! }"#.parse().unwrap();
+//!
+//! let proj = PlaneProjection::new(55.65);
+//! assert_eq!(proj.geojson_length(&geometry) as u32, 16373);
+//! ```
+
+use crate::{BBox, LatLon, PlaneProjection};
+use ::geojson::{Geometry, GeometryValue, Position};
+
+/// Converts GeoJSON position to (latitude, longitude).
+#[inline(always)]
+fn lat_lon(position: &Position) -> LatLon {
+    (position[1], position[0])
+}
+
+/// Converts all the line positions to (latitude, longitude).
+fn lat_lons(line: &[Position]) -> Vec<LatLon> {
+    line.iter().map(lat_lon).collect()
+}
+
+/// Calls `f` for every position in the geometry.
+fn for_each_position(geometry: &Geometry, f: &mut impl FnMut(&Position)) {
+    match &geometry.value {
+        GeometryValue::Point { coordinates } => f(coordinates),
+        GeometryValue::MultiPoint { coordinates } | GeometryValue::LineString { coordinates } => {
+            coordinates.iter().for_each(f)
+        }
+        GeometryValue::MultiLineString { coordinates } | GeometryValue::Polygon { coordinates } => {
+            coordinates.iter().flatten().for_each(f)
+        }
+        GeometryValue::MultiPolygon { coordinates } => {
+            coordinates.iter().flatten().flatten().for_each(f)
+        }
+        GeometryValue::GeometryCollection { geometries } => geometries
+            .iter()
+            .for_each(|geometry| for_each_position(geometry, f)),
+    }
+}
+
+impl PlaneProjection {
+    /// Total length in meters of all the (multi) line strings in the geometry.
+    /// Points and polygons have zero length.
+    pub fn geojson_length(&self, geometry: &Geometry) -> f64 {
+        let line_length = |line: &[Position]| {
+            line.windows(2)
+                .map(|segment| self.distance(lat_lon(&segment[0]), lat_lon(&segment[1])))
+                .sum::<f64>()
+        };
+        match &geometry.value {
+            GeometryValue::LineString { coordinates } => line_length(coordinates),
+            GeometryValue::MultiLineString { coordinates } => {
+                coordinates.iter().map(|line| line_length(line)).sum()
+            }
+            GeometryValue::GeometryCollection { geometries } => geometries
+                .iter()
+                .map(|geometry| self.geojson_length(geometry))
+                .sum(),
+            _ => 0.0,
+        }
+    }
+
+    /// Total area in square meters of all the (multi) polygons in the geometry, excluding their holes.
+    /// Points and line strings have zero area.
+    pub fn geojson_area(&self, geometry: &Geometry) -> f64 {
+        let polygon_area = |rings: &[Vec<Position>]| match rings {
+            [] => 0.0,
+            [exterior, holes @ ..] => {
+                let holes = holes.iter().map(|hole| lat_lons(hole)).collect::<Vec<_>>();
+                self.polygon_area(&lat_lons(exterior), &holes)
+            }
+        };
+        match &geometry.value {
+            GeometryValue::Polygon { coordinates } => polygon_area(coordinates),
+            GeometryValue::MultiPolygon { coordinates } => coordinates
+                .iter()
+                .map(|polygon| polygon_area(polygon))
+                .sum(),
+            GeometryValue::GeometryCollection { geometries } => geometries
+                .iter()
+                .map(|geometry| self.geojson_area(geometry))
+                .sum(),
+            _ => 0.0,
+        }
+    }
+
+    /// Bounding box of all the positions in the geometry. Returns `None` if the geometry is empty.
+    pub fn geojson_bbox(&self, geometry: &Geometry) -> Option<BBox> {
+        let mut bbox: Option<BBox> = None;
+        for_each_position(geometry, &mut |position| {
+            let point = lat_lon(position);
+            bbox = Some(match bbox {
+                Some(BBox { min, max }) => BBox {
+                    min: (min.0.min(point.0), min.1.min(point.1)),
+                    max: (max.0.max(point.0), max.1.max(point.1)),
+                },
+                None => BBox {
+                    min: point,
+                    max: point,
+                },
+            });
+        });
+        bbox
+    }
+
+    /// The closest to `point` location on the geometry and the distance to it in meters,
+    /// considering points, line strings and polygon rings. Returns `None` if the geometry is empty.
+    pub fn geojson_nearest_point(
+        &self,
+        point: LatLon,
+        geometry: &Geometry,
+    ) -> Option<(LatLon, f64)> {
+        let nearest_on_line = |line: &[Position]| {
+            (!line.is_empty()).then(|| {
+                let nearest = self.point_on_line(point, &lat_lons(line));
+                (nearest.point, nearest.distance)
+            })
+        };
+        let nearest_of = |nearest: Option<(LatLon, f64)>, candidate: Option<(LatLon, f64)>| match (
+            nearest, candidate,
+        ) {
+            (Some(a), Some(b)) => Some(if b.1 < a.1 { b } else { a }),
+            (a, b) => a.or(b),
+        };
+        match &geometry.value {
+            GeometryValue::Point { coordinates } => {
+                nearest_on_line(core::slice::from_ref(coordinates))
+            }
+            GeometryValue::MultiPoint { coordinates } => coordinates
+                .iter()
+                .map(|position| nearest_on_line(core::slice::from_ref(position)))
+                .fold(None, nearest_of),
+            GeometryValue::LineString { coordinates } => nearest_on_line(coordinates),
+            GeometryValue::MultiLineString { coordinates }
+            | GeometryValue::Polygon { coordinates } => coordinates
+                .iter()
+                .map(|line| nearest_on_line(line))
+                .fold(None, nearest_of),
+            GeometryValue::MultiPolygon { coordinates } => coordinates
+                .iter()
+                .flatten()
+                .map(|line| nearest_on_line(line))
+                .fold(None, nearest_of),
+            GeometryValue::GeometryCollection { geometries } => geometries
+                .iter()
+                .map(|geometry| self.geojson_nearest_point(point, geometry))
+                .fold(None, nearest_of),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    fn geometry(json: &str) -> Geometry {
+        json.parse().unwrap()
+    }
+
+    #[test]
+    fn geojson_test() {
+        let proj = PlaneProjection::new(55.65);
+        let point = geometry(r#"{"type": "Point", "coordinates": [13.0, 55.6]}"#);
+        assert_eq!(proj.geojson_length(&point), 0.0);
+        assert_eq!(proj.geojson_area(&point), 0.0);
+        assert_eq!(
+            proj.geojson_bbox(&point),
+            Some(BBox {
+                min: (55.6, 13.0),
+                max: (55.6, 13.0)
+            })
+        );
+        assert_eq!(
+            proj.geojson_nearest_point(MALMO_C, &point),
+            Some(((55.6, 13.0), proj.distance(MALMO_C, (55.6, 13.0))))
+        );
+
+        let lines = geometry(
+            r#"{"type": "MultiLineString", "coordinates": [
+                [[13.001973666557435, 55.60330902847681], [13.191304107330561, 55.704141722528554]],
+                [[13.0, 55.8], [13.3, 55.8], [13.3, 55.9]]
+            ]}"#,
+        );
+        let expected = proj.distance(MALMO_C, LUND_C)
+            + proj.polyline_length(&[(55.8, 13.0), (55.8, 13.3), (55.9, 13.3)]);
+        assert!((proj.geojson_length(&lines) - expected).abs() < 1e-6);
+        assert_eq!(proj.geojson_area(&lines), 0.0);
+        assert_eq!(
+            proj.geojson_bbox(&lines),
+            Some(BBox {
+                min: (MALMO_C.0, 13.0),
+                max: (55.9, 13.3)
+            })
+        );
+        let (nearest, distance) = proj.geojson_nearest_point((55.85, 13.31), &lines).unwrap();
+        assert!(proj.distance(nearest, (55.85, 13.3)) < 1e-6);
+        assert!((distance - proj.distance((55.85, 13.31), (55.85, 13.3))).abs() < 1e-6);
+
+        let polygons = geometry(
+            r#"{"type": "MultiPolygon", "coordinates": [
+                [
+                    [[13.0, 55.6], [13.2, 55.6], [13.2, 55.7], [13.0, 55.7], [13.0, 55.6]],
+                    [[13.05, 55.62], [13.1, 55.62], [13.1, 55.65], [13.05, 55.62]]
+                ],
+                [[[14.0, 56.0], [14.1, 56.0], [14.1, 56.1], [14.0, 56.0]]]
+            ]}"#,
+        );
+        let expected = proj.area(&[(55.6, 13.0), (55.6, 13.2), (55.7, 13.2), (55.7, 13.0)])
+            - proj.area(&[(55.62, 13.05), (55.62, 13.1), (55.65, 13.1)])
+            + proj.area(&[(56.0, 14.0), (56.0, 14.1), (56.1, 14.1)]);
+        assert!((proj.geojson_area(&polygons) - expected).abs() < 1e-6);
+        assert_eq!(proj.geojson_length(&polygons), 0.0);
+        assert_eq!(
+            proj.geojson_bbox(&polygons),
+            Some(BBox {
+                min: (55.6, 13.0),
+                max: (56.1, 14.1)
+            })
+        );
+        // the hole boundary is the closest one
+        let (nearest, _) = proj
+            .geojson_nearest_point((55.63, 13.11), &polygons)
+            .unwrap();
+        assert!((nearest.1 - 13.1).abs() < 1e-9);
+
+        let collection = geometry(&format!(
+            r#"{{"type": "GeometryCollection", "geometries": [{}, {}]}}"#,
+            r#"{"type": "Point", "coordinates": [13.0, 55.6]}"#,
+            r#"{"type": "LineString", "coordinates": [[13.0, 55.8], [13.3, 55.8]]}"#,
+        ));
+        assert!(
+            (proj.geojson_length(&collection) - proj.distance((55.8, 13.0), (55.8, 13.3))).abs()
+                < 1e-6
+        );
+        let (nearest, _) = proj
+            .geojson_nearest_point((55.61, 13.0), &collection)
+            .unwrap();
+        assert_eq!(nearest, (55.6, 13.0));
+
+        let empty = geometry(r#"{"type": "GeometryCollection", "geometries": []}"#);
+        assert_eq!(proj.geojson_bbox(&empty), None);
+        assert_eq!(proj.geojson_nearest_point(MALMO_C, &empty), None);
+    }
+}
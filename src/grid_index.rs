@@ -0,0 +1,245 @@
+#[cfg(not(feature = "std"))]
+use crate::{Float as _, math::Float as _};
+use crate::{LatLon, PlaneProjection};
+use alloc::vec::Vec;
+
+/// Cell coordinates in the grid, (north, east) like in the projection space.
+/// Values are limited to the `i32` range, so the arithmetic over them never overflows.
+type Cell = (i64, i64);
+
+/// A uniform grid spatial index that buckets points into square cells of a fixed size in meters.
+/// Works best when the query radius is comparable with the cell size.
+///
+/// ```
+/// use plane_projection::{GridIndex, PlaneProjection};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let points = [(55.6033090, 13.0019737), (55.7041417, 13.1913041), (55.6050, 13.0020)];
+/// let index = GridIndex::new(&proj, &points, 500.0);
+///
+/// let mut nearby = index.within_radius((55.6040, 13.0019), 500.0).collect::<Vec<_>>();
+/// nearby.sort_by_key(|&(i, _)| i);
+/// assert_eq!(nearby.iter().map(|&(i, _)| i).collect::<Vec<_>>(), [0, 2]);
+///
+/// assert_eq!(index.nearest((55.70, 13.19)).map(|(i, _)| i), Some(1));
+/// ```
+#[derive(Clone)]
+pub struct GridIndex {
+    projection: PlaneProjection,
+    /// Origin of the local Cartesian coordinates, which is the first indexed point.
+    origin: LatLon,
+    cell_size: f64,
+    /// Indexed points with their cells and original indices, sorted by cell.
+    entries: Vec<(Cell, usize, (f64, f64))>,
+    /// Range of occupied cells.
+    min_cell: Cell,
+    max_cell: Cell,
+}
+
+impl GridIndex {
+    /// Builds the index over the points with `cell_size` meters cells.
+    pub fn new(projection: &PlaneProjection, points: &[LatLon], cell_size: f64) -> Self {
+        let origin = points.first().copied().unwrap_or_default();
+        let mut index = Self {
+            projection: projection.clone(),
+            origin,
+            cell_size,
+            entries: Vec::with_capacity(points.len()),
+            min_cell: (i64::MAX, i64::MAX),
+            max_cell: (i64::MIN, i64::MIN),
+        };
+        for (i, &point) in points.iter().enumerate() {
+            let p = projection.project_relative(origin, point);
+            let cell = index.cell(p);
+            index.min_cell = (index.min_cell.0.min(cell.0), index.min_cell.1.min(cell.1));
+            index.max_cell = (index.max_cell.0.max(cell.0), index.max_cell.1.max(cell.1));
+            index.entries.push((cell, i, p));
+        }
+        index
+            .entries
+            .sort_unstable_by_key(|&(cell, i, _)| (cell, i));
+        index
+    }
+
+    /// Number of indexed points.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if there are no indexed points.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Indices of points within `radius` meters from the `center` together with their distances,
+    /// in no particular order.
+    pub fn within_radius(
+        &self,
+        center: LatLon,
+        radius: f64,
+    ) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let p = self.projection.project_relative(self.origin, center);
+        let min = self.cell((p.0 - radius, p.1 - radius));
+        let max = self.cell((p.0 + radius, p.1 + radius));
+        let square_radius = radius * radius;
+        (min.0..=max.0)
+            .flat_map(move |row| self.row_entries(row, min.1, max.1))
+            .filter_map(move |&(_, i, q)| {
+                let square_distance = square_distance(p, q);
+                (square_distance <= square_radius).then(|| (i, square_distance.sqrt()))
+            })
+    }
+
+    /// Index of the nearest to `point` indexed point and the distance to it in meters.
+    /// If there are several equally close points, the one with the smallest index is returned.
+    /// Returns `None` if the index is empty.
+    pub fn nearest(&self, point: LatLon) -> Option<(usize, f64)> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let p = self.projection.project_relative(self.origin, point);
+        let center = self.cell(p);
+        // Chebyshev distances in cells to the nearest and the farthest occupied cells
+        let (min, max) = (self.min_cell, self.max_cell);
+        let first_ring = (min.0 - center.0)
+            .max(center.0 - max.0)
+            .max(min.1 - center.1)
+            .max(center.1 - max.1)
+            .max(0);
+        let last_ring = (center.0 - min.0)
+            .max(max.0 - center.0)
+            .max(center.1 - min.1)
+            .max(max.1 - center.1);
+
+        let mut best: Option<(usize, f64)> = None;
+        for ring in first_ring..=last_ring {
+            // Every point in this ring is at least `(ring - 1) * cell_size` away
+            let ring_min_distance = (ring - 1).max(0) as f64 * self.cell_size;
+            if best.is_some_and(|(_, d)| d < ring_min_distance * ring_min_distance) {
+                break;
+            }
+            let (top, bottom) = (center.0 - ring, center.0 + ring);
+            let (left, right) = (center.1 - ring, center.1 + ring);
+            for row in top.max(self.min_cell.0)..=bottom.min(self.max_cell.0) {
+                // Inner rows of the ring have only the leftmost and the rightmost cells
+                let entries = if row == top || row == bottom {
+                    [self.row_entries(row, left, right), &[]]
+                } else {
+                    [
+                        self.row_entries(row, left, left),
+                        self.row_entries(row, right, right),
+                    ]
+                };
+                for &(_, i, q) in entries.into_iter().flatten() {
+                    best = nearest_of(best, i, square_distance(p, q));
+                }
+            }
+        }
+        best.map(|(i, square_distance)| (i, square_distance.sqrt()))
+    }
+
+    /// Cell of the point in the local Cartesian coordinates.
+    #[inline(always)]
+    fn cell(&self, p: (f64, f64)) -> Cell {
+        let cell = |x: f64| (x / self.cell_size).floor() as i32 as i64;
+        (cell(p.0), cell(p.1))
+    }
+
+    /// Entries in the `row` of cells from `first` to `last` columns inclusive.
+    fn row_entries(&self, row: i64, first: i64, last: i64) -> &[(Cell, usize, (f64, f64))] {
+        let start = self
+            .entries
+            .partition_point(|&(cell, _, _)| cell < (row, first));
+        let end = self
+            .entries
+            .partition_point(|&(cell, _, _)| cell <= (row, last));
+        &self.entries[start..end.max(start)]
+    }
+}
+
+#[inline(always)]
+fn square_distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let d0 = a.0 - b.0;
+    let d1 = a.1 - b.1;
+    d0 * d0 + d1 * d1
+}
+
+/// Picks the nearest of the current `best` and the candidate, tie-breaking by index.
+#[inline(always)]
+fn nearest_of(best: Option<(usize, f64)>, i: usize, square_distance: f64) -> Option<(usize, f64)> {
+    match best {
+        Some((best_i, best_d)) if (best_d, best_i) <= (square_distance, i) => best,
+        _ => Some((i, square_distance)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BBox;
+    use crate::tests::{LUND_C, MALMO_C, pseudo_random_points};
+
+    const AREA: BBox = BBox {
+        min: (55.6, 13.0),
+        max: (55.7, 13.2),
+    };
+
+    #[test]
+    fn within_radius_test() {
+        let proj = PlaneProjection::new(55.65);
+        let index = GridIndex::new(&proj, &[], 100.0);
+        assert!(index.is_empty());
+        assert_eq!(index.within_radius(MALMO_C, 1000.0).count(), 0);
+
+        let points = pseudo_random_points(2000, AREA);
+        let index = GridIndex::new(&proj, &points, 250.0);
+        assert_eq!(index.len(), 2000);
+        for (query, radius) in [
+            (MALMO_C, 500.0),
+            (LUND_C, 100.0),
+            ((55.65, 13.1), 1000.0),
+            ((55.65, 13.1), 0.0),
+        ] {
+            let mut result = index.within_radius(query, radius).collect::<Vec<_>>();
+            result.sort_by_key(|&(i, _)| i);
+            let expected = points
+                .iter()
+                .enumerate()
+                .filter(|&(_, &point)| proj.distance(query, point) <= radius)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            assert_eq!(result.iter().map(|&(i, _)| i).collect::<Vec<_>>(), expected);
+            for (i, distance) in result {
+                assert!((distance - proj.distance(query, points[i])).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(GridIndex::new(&proj, &[], 100.0).nearest(MALMO_C), None);
+
+        let index = GridIndex::new(&proj, &[LUND_C, MALMO_C, LUND_C], 100.0);
+        assert_eq!(index.nearest(LUND_C), Some((0, 0.0)));
+        let (i, distance) = index.nearest((55.6, 13.0)).unwrap();
+        assert_eq!(i, 1);
+        assert!((distance - proj.distance((55.6, 13.0), MALMO_C)).abs() < 1e-6);
+        // far away from all points
+        assert_eq!(index.nearest((50.0, 10.0)).map(|(i, _)| i), Some(1));
+
+        let points = pseudo_random_points(2000, AREA);
+        let candidates = GridIndex::new(&proj, &points, 250.0);
+        for j in 0..100 {
+            let j = j as f64;
+            let query = (
+                55.55 + (j * 0.3).fract() * 0.2,
+                12.9 + (j * 0.7).fract() * 0.4,
+            );
+            let expected = proj.k_nearest(&points, query, 1)[0];
+            let (i, distance) = candidates.nearest(query).unwrap();
+            assert!((distance - expected.1).abs() < 1e-6);
+            assert!((proj.distance(query, points[i]) - expected.1).abs() < 1e-6);
+        }
+    }
+}
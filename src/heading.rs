@@ -0,0 +1,188 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{Coordinate, Float, PlaneProjection};
+
+/// Units, origin and direction of the headings, for integration with systems that don't use compass headings,
+/// see [`PlaneProjection::heading_as()`].
+///
+/// ```
+/// use plane_projection::{HeadingConvention, PlaneProjection};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// // ROS yaw in the ENU frame, so moving North is PI / 2
+/// let yaw = proj.heading_as((55.60, 13.0), (55.61, 13.0), HeadingConvention::EnuRadians);
+/// assert!((yaw - core::f64::consts::FRAC_PI_2).abs() < 1e-9);
+/// let destination = proj.destination((55.60, 13.0), HeadingConvention::EnuRadians.to_compass(yaw), 1000.0);
+/// assert!(destination.0 > 55.60);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HeadingConvention {
+    /// Degrees in range [0.0, 360.0) measured clockwise from North, returned by [`PlaneProjection::heading()`].
+    Compass,
+    /// Degrees in range (-180.0, 180.0] measured clockwise from North, so West is -90.0.
+    SignedCompass,
+    /// Degrees in range (-180.0, 180.0] measured counterclockwise from East, the math convention.
+    Enu,
+    /// Radians in range (-PI, PI] measured counterclockwise from East, like the yaw in ROS ENU frames.
+    EnuRadians,
+}
+
+impl HeadingConvention {
+    /// Converts the compass `heading` in degrees, as [`PlaneProjection::heading()`] returns, to this convention.
+    #[inline(always)]
+    pub fn from_compass(self, heading: f64) -> f64 {
+        match self {
+            Self::Compass => normalize_heading(heading),
+            Self::SignedCompass => heading_difference(0.0, heading),
+            Self::Enu => heading_difference(0.0, 90.0 - heading),
+            Self::EnuRadians => heading_difference(0.0, 90.0 - heading).to_radians(),
+        }
+    }
+
+    /// Converts the `heading` in this convention to the compass heading in degrees in range [0.0, 360.0),
+    /// for example to pass it to [`PlaneProjection::destination()`].
+    #[inline(always)]
+    pub fn to_compass(self, heading: f64) -> f64 {
+        match self {
+            Self::Compass | Self::SignedCompass => normalize_heading(heading),
+            Self::Enu => normalize_heading(90.0 - heading),
+            Self::EnuRadians => normalize_heading(90.0 - heading.to_degrees()),
+        }
+    }
+}
+
+impl<T: Float> PlaneProjection<T> {
+    /// Heading from point `a` to point `b` in the given `convention`, computed with `f64` precision
+    /// like [`PlaneProjection::heading_f64()`].
+    #[inline(always)]
+    pub fn heading_as(
+        &self,
+        a: impl Coordinate<T>,
+        b: impl Coordinate<T>,
+        convention: HeadingConvention,
+    ) -> f64 {
+        convention.from_compass(self.heading_f64(a, b))
+    }
+}
+
+/// Normalizes heading in degrees into range [0.0, 360.0), so -90.0 becomes 270.0 and 450.0 becomes 90.0.
+#[inline(always)]
+pub fn normalize_heading(heading: f64) -> f64 {
+    let heading = heading % 360.0;
+    if heading < 0.0 {
+        // tiny negative values round up to 360.0
+        let heading = heading + 360.0;
+        if heading < 360.0 { heading } else { 0.0 }
+    } else {
+        heading
+    }
+}
+
+/// Signed difference in degrees to turn from heading `from` to heading `to` in range (-180.0, 180.0],
+/// where positive values are clockwise turns, so the difference from 350.0 to 10.0 is 20.0.
+#[inline(always)]
+pub fn heading_difference(from: f64, to: f64) -> f64 {
+    let diff = normalize_heading(to - from);
+    if diff > 180.0 { diff - 360.0 } else { diff }
+}
+
+/// Circular mean of the headings in degrees in range [0.0, 360.0), so the mean of 350.0 and 10.0 is 0.0.
+/// Returns `None` if `headings` is empty or they cancel each other out, like 0.0 and 180.0.
+///
+/// ```
+/// use plane_projection::mean_heading;
+///
+/// assert_eq!(mean_heading(&[350.0, 10.0]).unwrap().round(), 0.0);
+/// assert_eq!(mean_heading(&[80.0, 90.0, 100.0]).unwrap().round(), 90.0);
+/// assert_eq!(mean_heading(&[0.0, 180.0]), None);
+/// ```
+pub fn mean_heading(headings: &[f64]) -> Option<f64> {
+    let (sin, cos) = headings.iter().fold((0.0, 0.0), |(sin, cos), heading| {
+        let (s, c) = heading.to_radians().sin_cos();
+        (sin + s, cos + c)
+    });
+    // Resultant vector shorter than rounding errors means there is no dominant direction
+    let epsilon = 1e-9 * headings.len() as f64;
+    if sin * sin + cos * cos <= epsilon * epsilon {
+        return None;
+    }
+    Some(normalize_heading(sin.atan2(cos).to_degrees()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn normalize_heading_test() {
+        assert_eq!(normalize_heading(0.0), 0.0);
+        assert_eq!(normalize_heading(359.5), 359.5);
+        assert_eq!(normalize_heading(360.0), 0.0);
+        assert_eq!(normalize_heading(450.0), 90.0);
+        assert_eq!(normalize_heading(-90.0), 270.0);
+        assert_eq!(normalize_heading(-720.0), 0.0);
+        assert_eq!(normalize_heading(-1e-15), 0.0);
+    }
+
+    #[test]
+    fn heading_difference_test() {
+        assert_eq!(heading_difference(10.0, 20.0), 10.0);
+        assert_eq!(heading_difference(20.0, 10.0), -10.0);
+        assert_eq!(heading_difference(350.0, 10.0), 20.0);
+        assert_eq!(heading_difference(10.0, 350.0), -20.0);
+        assert_eq!(heading_difference(0.0, 180.0), 180.0);
+        assert_eq!(heading_difference(180.0, 0.0), 180.0);
+        assert_eq!(heading_difference(-90.0, 270.0), 0.0);
+    }
+
+    #[test]
+    fn mean_heading_test() {
+        assert_eq!(mean_heading(&[]), None);
+        assert_eq!(mean_heading(&[123.0]).unwrap().round(), 123.0);
+        assert_eq!(mean_heading(&[350.0, 10.0]).unwrap().round(), 0.0);
+        assert_eq!(mean_heading(&[340.0, 350.0, 360.0]).unwrap().round(), 350.0);
+        assert_eq!(mean_heading(&[170.0, 190.0]).unwrap().round(), 180.0);
+        assert_eq!(mean_heading(&[0.0, 180.0]), None);
+        assert_eq!(mean_heading(&[0.0, 120.0, 240.0]), None);
+    }
+
+    #[test]
+    fn heading_convention_test() {
+        use HeadingConvention::*;
+        let cases = [
+            // compass, signed compass, ENU, ENU radians
+            (0.0, 0.0, 90.0, FRAC_PI_2),
+            (90.0, 90.0, 0.0, 0.0),
+            (180.0, 180.0, -90.0, -FRAC_PI_2),
+            (270.0, -90.0, 180.0, PI),
+            (315.0, -45.0, 135.0, 3.0 * PI / 4.0),
+        ];
+        for (compass, signed, enu, radians) in cases {
+            assert_eq!(Compass.from_compass(compass), compass);
+            assert_eq!(SignedCompass.from_compass(compass), signed);
+            assert_eq!(Enu.from_compass(compass), enu);
+            assert!((EnuRadians.from_compass(compass) - radians).abs() < 1e-12);
+
+            assert_eq!(Compass.to_compass(compass), compass);
+            assert_eq!(SignedCompass.to_compass(signed), compass);
+            assert_eq!(Enu.to_compass(enu), compass);
+            assert!((EnuRadians.to_compass(radians) - compass).abs() < 1e-12);
+        }
+        // out of range values are normalized
+        assert_eq!(Compass.from_compass(-90.0), 270.0);
+        assert_eq!(SignedCompass.from_compass(540.0), 180.0);
+        assert_eq!(Enu.to_compass(-270.0), 0.0);
+
+        let proj = PlaneProjection::new(55.65);
+        let heading = proj.heading_f64(MALMO_C, LUND_C);
+        assert_eq!(proj.heading_as(MALMO_C, LUND_C, Compass), heading);
+        let yaw = proj.heading_as(MALMO_C, LUND_C, EnuRadians);
+        // atan2 in the ENU frame directly
+        let (north, east) = proj.project(LUND_C).into();
+        let (origin_north, origin_east) = proj.project(MALMO_C).into();
+        let expected: f64 = (north - origin_north).atan2(east - origin_east);
+        assert!((yaw - expected).abs() < 1e-12);
+    }
+}
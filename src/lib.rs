@@ -1,13 +1,99 @@
-// Values that define WGS84 ellipsoid model of the Earth in meters.
-const EQUATORIAL_RADIUS: f64 = 6378137.0;
-const FLATTENING: f64 = 1.0 / 298.257223563;
-const SQUARED_ECCENTRICITY: f64 = FLATTENING * (2.0 - FLATTENING);
+#![cfg_attr(not(feature = "std"), no_std)]
+// The test harness links `std`, whose inherent float methods take precedence over the `libm` backed traits
+#![cfg_attr(all(test, not(feature = "std")), allow(unused_imports))]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either `std` or `libm` feature must be enabled");
+
+extern crate alloc;
+
+mod accuracy;
+mod banded_projection;
+mod bbox;
+mod buffer;
+mod clip;
+mod coordinate;
+mod ellipsoid;
+#[cfg(feature = "encoded-polyline")]
+pub mod encoded_polyline;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod float;
+#[cfg(feature = "geo")]
+pub mod geo;
+mod geo_point;
+#[cfg(feature = "geodesic")]
+mod geodesic;
+mod geofence;
+#[cfg(feature = "geohash")]
+pub mod geohash;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+mod grid_index;
+mod heading;
+#[cfg(not(feature = "std"))]
+mod math;
+mod morton;
+mod parallel_offset;
+mod points;
+mod polygon;
+mod polyline;
+mod polyline_snapper;
+mod predicates;
+mod projected_point;
+mod projected_polyline;
+mod projection_f32;
+#[cfg(feature = "python")]
+pub mod python;
+mod radians;
+#[cfg(feature = "rstar")]
+pub mod rtree;
+mod segment;
+mod shapes;
+mod similarity;
+mod smart_distance;
+pub mod tiles;
+mod track;
+mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+
+pub use accuracy::DistanceComparison;
+pub use banded_projection::BandedProjection;
+pub use bbox::BBox;
+pub use coordinate::{Coordinate, LonLat};
+pub use ellipsoid::Ellipsoid;
+pub use float::Float;
+pub use geo_point::{GeoPoint, InvalidCoordinate};
+#[cfg(feature = "geodesic")]
+pub use geodesic::{geodesic_distance, geodesic_heading};
+pub use geofence::{Geofence, GeofenceEvent, GeofenceMonitor};
+pub use grid_index::GridIndex;
+pub use heading::{HeadingConvention, heading_difference, mean_heading, normalize_heading};
+pub use parallel_offset::LineJoin;
+pub use polygon::Orientation;
+pub use polyline::PointOnLine;
+pub use polyline_snapper::{PolylineSnapper, SnapCandidate};
+pub use predicates::{DegenerateGeometry, DegeneratePolicy};
+pub use projected_point::ProjectedPoint;
+pub use projected_polyline::ProjectedPolyline;
+pub use projection_f32::{LatLonF32, PlaneProjectionF32};
+pub use smart_distance::{SmartDistance, haversine_distance};
+pub use track::{Fix, SmoothingWindow, Stop, Track};
+pub use units::LengthUnit;
+
+/// Minimum number of items processed by a single thread in parallel batch operations.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_LEN: usize = 1024;
 
 /// A coordinate in (latitude, longitude) format.
 pub type LatLon = (f64, f64);
 
 /// A plane projection, useful for blazingly fast approximate distance calculations.
-/// Based on WGS84 ellipsoid model of the Earth, plane projection provides 0.1% precision
+/// Based on WGS84 ellipsoid model of the Earth by default (see [`Ellipsoid`]), plane projection provides 0.1% precision
 /// on distances under 500km at latitudes up to the 65°.
 /// See https://blog.mapbox.com/fast-geodesic-approximations-with-cheap-ruler-106f229ad016
 /// for more details about the principle and formulas behind.
@@ -23,44 +109,121 @@ pub type LatLon = (f64, f64);
 /// assert_eq!(heading as u32, 226);
 /// ```
 #[derive(Clone)]
-pub struct PlaneProjection {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaneProjection<T = f64> {
     /// Meters per degree of longitude.
-    lon_scale: f64,
+    lon_scale: T,
     /// Meters per degree of latitude.
-    lat_scale: f64,
+    lat_scale: T,
+    /// Latitude in degrees the projection was created for.
+    latitude: T,
+    /// Ellipsoid the projection was created for, so it is kept when the projection is recentered.
+    ellipsoid: Ellipsoid,
 }
 
-impl PlaneProjection {
+impl<T: Float> PlaneProjection<T> {
     /// Creates a plane projection to the Earth at provided latitude.
-    pub fn new(latitude: f64) -> Self {
-        // `cosf32` gives sufficient precision (adds approx. 0.0001 meter error) with much better performance
-        let cos_lat = (latitude as f32).to_radians().cos() as f64;
+    pub fn new(latitude: T) -> Self {
+        Self::with_ellipsoid(latitude, Ellipsoid::WGS84)
+    }
 
-        // Based on https://en.wikipedia.org/wiki/Earth_radius#Meridional
-        let w2 = 1.0 / (1.0 - SQUARED_ECCENTRICITY * (1.0 - cos_lat * cos_lat));
-        let w = w2.sqrt();
-        let lon_scale = (EQUATORIAL_RADIUS * w * cos_lat).to_radians(); // based on normal radius of curvature
-        let lat_scale = (EQUATORIAL_RADIUS * w * w2 * (1.0 - SQUARED_ECCENTRICITY)).to_radians(); // based on meridonal radius of curvature
+    /// Creates a plane projection to the `ellipsoid` at provided latitude,
+    /// for example a spherical Earth to match the legacy systems.
+    /// The scales are computed in `f64` and then converted to `T`.
+    pub fn with_ellipsoid(latitude: T, ellipsoid: Ellipsoid) -> Self {
+        let f64_latitude = latitude.to_f64();
+        // `cosf32` gives sufficient precision (adds approx. 0.0001 meter error) with much better performance
+        let cos_lat = (f64_latitude as f32).to_radians().cos() as f64;
+        PlaneProjection {
+            latitude,
+            ..PlaneProjection::from_cos_lat(f64_latitude, cos_lat, ellipsoid).cast()
+        }
+    }
 
-        Self {
-            lon_scale,
-            lat_scale,
+    /// Converts the projection to another scalar type, for example to [`PlaneProjectionF32`].
+    /// Other constructors than [`PlaneProjection::new()`] and [`PlaneProjection::with_ellipsoid()`]
+    /// are defined for the default `f64` projection only.
+    pub fn cast<U: Float>(&self) -> PlaneProjection<U> {
+        PlaneProjection {
+            lon_scale: U::from_f64(self.lon_scale.to_f64()),
+            lat_scale: U::from_f64(self.lat_scale.to_f64()),
+            latitude: U::from_f64(self.latitude.to_f64()),
+            ellipsoid: self.ellipsoid,
         }
     }
 
+    /// Latitude in degrees the projection was created for.
+    #[inline(always)]
+    pub fn latitude(&self) -> T {
+        self.latitude
+    }
+
+    /// Ellipsoid the projection was created for, [`Ellipsoid::WGS84`] unless
+    /// [`PlaneProjection::with_ellipsoid()`] was used.
+    #[inline(always)]
+    pub fn ellipsoid(&self) -> Ellipsoid {
+        self.ellipsoid
+    }
+
+    /// Meters per degree of latitude at the projection latitude.
+    #[inline(always)]
+    pub fn lat_scale(&self) -> T {
+        self.lat_scale
+    }
+
+    /// Meters per degree of longitude at the projection latitude.
+    #[inline(always)]
+    pub fn lon_scale(&self) -> T {
+        self.lon_scale
+    }
+
+    /// Ratio of [`PlaneProjection::lon_scale()`] to [`PlaneProjection::lat_scale()`], which is close to
+    /// the cosine of the latitude, going down from approximately 1.0 at the equator to 0.0 at the poles.
+    #[inline(always)]
+    pub fn anisotropy(&self) -> T {
+        self.lon_scale / self.lat_scale
+    }
+
     /// Projects a coordinate from (latitude, longitude) to the plane projection space.
     ///
     /// This function is intended for low-level coordinate manipulation (like vector math) in the projection space
     /// and should not be used unless the built-in methods like [`PlaneProjection::distance()`] and
     /// [`PlaneProjection::distance_to_segment()`] are insufficient for your use case.
+    /// See [`ProjectedPoint`] for the supported vector operations.
     #[inline(always)]
-    pub fn project(&self, ll: LatLon) -> (f64, f64) {
-        (ll.0 * self.lat_scale, ll.1 * self.lon_scale)
+    pub fn project(&self, ll: impl Coordinate<T>) -> ProjectedPoint<T> {
+        let ll = ll.lat_lon();
+        ProjectedPoint(ll.0 * self.lat_scale, ll.1 * self.lon_scale)
+    }
+
+    /// Converts a point from the plane projection space back to (latitude, longitude),
+    /// the inverse of [`PlaneProjection::project()`].
+    #[inline(always)]
+    pub fn unproject(&self, p: impl Into<ProjectedPoint<T>>) -> (T, T) {
+        let p = p.into();
+        (p.0 / self.lat_scale, wrap_lon(p.1 / self.lon_scale))
+    }
+
+    /// Projects a point to the local Cartesian coordinates with `origin` at (0.0, 0.0).
+    /// Unlike [`PlaneProjection::project()`] this handles the longitude wrap over the antimeridian.
+    #[inline(always)]
+    fn project_relative(&self, origin: (T, T), point: (T, T)) -> (T, T) {
+        (
+            (point.0 - origin.0) * self.lat_scale,
+            lon_diff(point.1, origin.1) * self.lon_scale,
+        )
+    }
+
+    /// Inverse of [`PlaneProjection::project_relative()`].
+    #[inline(always)]
+    fn unproject_relative(&self, origin: (T, T), p: (T, T)) -> (T, T) {
+        self.offset(origin, p.1, p.0)
     }
 
     /// Square distance in meters between two points in (lat, lon) format.
     #[inline(always)]
-    pub fn square_distance(&self, a: LatLon, b: LatLon) -> f64 {
+    pub fn square_distance(&self, a: impl Coordinate<T>, b: impl Coordinate<T>) -> T {
+        let (a, b) = (a.lat_lon(), b.lat_lon());
         let lat_dist = (a.0 - b.0) * self.lat_scale;
         let lon_dist = lon_diff(a.1, b.1) * self.lon_scale;
         lat_dist * lat_dist + lon_dist * lon_dist
@@ -68,78 +231,576 @@ impl PlaneProjection {
 
     /// Distance in meters between two points in (lat, lon) format.
     #[inline(always)]
-    pub fn distance(&self, a: LatLon, b: LatLon) -> f64 {
+    pub fn distance(&self, a: impl Coordinate<T>, b: impl Coordinate<T>) -> T {
         self.square_distance(a, b).sqrt()
     }
 
+    /// Checks if two points are within `max_distance` meters from each other, which is cheaper
+    /// than calculating the actual distance.
+    #[inline(always)]
+    pub fn is_within(&self, a: impl Coordinate<T>, b: impl Coordinate<T>, max_distance: T) -> bool {
+        self.square_distance(a, b) <= max_distance * max_distance
+    }
+
     /// Square distance in meters from point to the segment.
-    pub fn square_distance_to_segment(&self, point: LatLon, segment: (LatLon, LatLon)) -> f64 {
+    #[inline(always)]
+    pub fn square_distance_to_segment(
+        &self,
+        point: impl Coordinate<T>,
+        segment: (impl Coordinate<T>, impl Coordinate<T>),
+    ) -> T {
+        self.project_to_segment(point, segment).1
+    }
+
+    /// Projects the point onto the segment, returning the projection parameter `t` clamped to
+    /// the range where 0.0=start, 1.0=end of segment and square distance in meters to the projected point.
+    /// `t` is 0.0 for zero-length segments. Useful to compute the distance along a route after snapping.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj: PlaneProjection = PlaneProjection::new(55.65);
+    /// let segment = ((55.60, 13.00), (55.70, 13.00));
+    /// let (t, square_distance) = proj.project_to_segment((55.625, 13.01), segment);
+    /// assert_eq!((t * 100.0).round(), 25.0);
+    /// assert_eq!(square_distance.sqrt().round(), 630.0);
+    /// ```
+    #[inline(always)]
+    pub fn project_to_segment(
+        &self,
+        point: impl Coordinate<T>,
+        segment: (impl Coordinate<T>, impl Coordinate<T>),
+    ) -> (T, T) {
+        let (start, end) = (segment.0.lat_lon(), segment.1.lat_lon());
         // Transform to local Cartesian coordinates with segment start as origin
-        let mut point = (
-            (point.0 - segment.0.0) * self.lat_scale,
-            lon_diff(point.1, segment.0.1) * self.lon_scale,
-        );
-        let segment = (
-            (segment.1.0 - segment.0.0) * self.lat_scale,
-            lon_diff(segment.1.1, segment.0.1) * self.lon_scale,
-        );
-        if segment.0 != 0.0 || segment.1 != 0.0 {
-            // dot(point, segment) = |point| * |segment| * cos(alpha)
-            // dividing by |segment|^2 normalizes to range where 0.0=start, 1.0=end of segment
-            let projection = (point.0 * segment.0 + point.1 * segment.1)
-                / (segment.0 * segment.0 + segment.1 * segment.1);
-            if projection > 1.0 {
-                // adjust `point` vector to the end of segment
-                point.0 -= segment.0;
-                point.1 -= segment.1;
-            } else if projection > 0.0 {
-                // adjust `point` vector so it starts at projected point
-                point.0 -= segment.0 * projection;
-                point.1 -= segment.1 * projection;
-            }
-        }
-        // Square length of vector from closest point on segment to original point
-        point.0 * point.0 + point.1 * point.1
+        project_to_origin_segment(
+            self.project_relative(start, point.lat_lon()),
+            self.project_relative(start, end),
+        )
+    }
+
+    /// The closest to `point` location on the segment, for example to draw the snapped position.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let segment = ((55.60, 13.00), (55.70, 13.00));
+    /// let snapped = proj.closest_point_on_segment((55.625, 13.01), segment);
+    /// assert!(proj.distance(snapped, (55.625, 13.00)) < 1e-6);
+    /// ```
+    #[inline(always)]
+    pub fn closest_point_on_segment(
+        &self,
+        point: impl Coordinate<T>,
+        segment: (impl Coordinate<T>, impl Coordinate<T>),
+    ) -> (T, T) {
+        let segment = (segment.0.lat_lon(), segment.1.lat_lon());
+        let (t, _) = self.project_to_segment(point, segment);
+        self.interpolate(segment.0, segment.1, t)
     }
 
     /// Distance in meters from point to the segment.
     #[inline(always)]
-    pub fn distance_to_segment(&self, point: LatLon, segment: (LatLon, LatLon)) -> f64 {
+    pub fn distance_to_segment(
+        &self,
+        point: impl Coordinate<T>,
+        segment: (impl Coordinate<T>, impl Coordinate<T>),
+    ) -> T {
         self.square_distance_to_segment(point, segment).sqrt()
     }
 
     /// Heading (azimuth) in degrees from point `a` to point `b` in the range [0.0, 360.0) degrees,
     /// measured clockwise from North: 0.0 is North, 90.0 is East, 180.0 is South and 270.0 is West.
     #[inline(always)]
-    pub fn heading(&self, a: LatLon, b: LatLon) -> f32 {
-        // Convert to f32 for better `atan2` performance while maintaining sufficient precision
-        let dx = ((a.0 - b.0) * self.lat_scale) as f32;
-        let dy = (lon_diff(b.1, a.1) * self.lon_scale) as f32;
-
+    pub fn heading(&self, a: impl Coordinate<T>, b: impl Coordinate<T>) -> f32 {
+        let (dx, dy) = self.heading_deltas(a.lat_lon(), b.lat_lon());
         // Together with inverted `dx` this converts (-180, 180] `atan2` range into [0, 360) without branching
         180.0 - dy.atan2(dx).to_degrees()
     }
+
+    /// Inverted North and East differences in meters from `a` to `b`, which [`PlaneProjection::heading()`]
+    /// passes to `atan2`. Converted to f32 for better `atan2` performance while maintaining sufficient precision.
+    #[inline(always)]
+    fn heading_deltas(&self, a: (T, T), b: (T, T)) -> (f32, f32) {
+        (
+            ((a.0 - b.0) * self.lat_scale).to_f64() as f32,
+            (lon_diff(b.1, a.1) * self.lon_scale).to_f64() as f32,
+        )
+    }
+
+    /// Same as [`PlaneProjection::heading()`], but computed with `f64` precision, which is useful when
+    /// the heading is fed into further math like trajectory prediction and the `f32` error accumulates.
+    #[inline(always)]
+    pub fn heading_f64(&self, a: impl Coordinate<T>, b: impl Coordinate<T>) -> f64 {
+        let (a, b) = (a.lat_lon(), b.lat_lon());
+        let dx = ((a.0 - b.0) * self.lat_scale).to_f64();
+        let dy = (lon_diff(b.1, a.1) * self.lon_scale).to_f64();
+        180.0 - dy.atan2(dx).to_degrees()
+    }
+
+    /// Same as [`PlaneProjection::heading()`], but uses a polynomial `atan2` approximation with the maximum error
+    /// of 0.04 degrees instead of the `atan2` call. Useful when the heading only picks one of a few
+    /// icon rotations for millions of markers.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let heading = proj.heading_fast((55.7041417, 13.1913041), (55.6033090, 13.0019737));
+    /// assert!((heading - proj.heading((55.7041417, 13.1913041), (55.6033090, 13.0019737))).abs() < 0.04);
+    /// ```
+    #[inline(always)]
+    pub fn heading_fast(&self, a: impl Coordinate<T>, b: impl Coordinate<T>) -> f32 {
+        use core::f32::consts::{FRAC_PI_2, PI};
+
+        let (dx, dy) = self.heading_deltas(a.lat_lon(), b.lat_lon());
+        let (abs_dx, abs_dy) = (dx.abs(), dy.abs());
+        if abs_dx == 0.0 && abs_dy == 0.0 {
+            // The same as `atan2(0.0, 0.0)` gives in `heading()`
+            return 180.0;
+        }
+
+        // atan(z) for z in [0, 1], then `atan2(dy, dx)` is restored from the octant
+        let z = abs_dx.min(abs_dy) / abs_dx.max(abs_dy);
+        let z2 = z * z;
+        let mut angle = z * (0.995354 + z2 * (-0.288679 + z2 * 0.079331));
+        if abs_dy > abs_dx {
+            angle = FRAC_PI_2 - angle;
+        }
+        if dx < 0.0 {
+            angle = PI - angle;
+        }
+        if dy < 0.0 {
+            angle = -angle;
+        }
+        180.0 - angle.to_degrees()
+    }
+
+    /// Linear interpolation between two points in the projection space,
+    /// where `t` = 0.0 corresponds to `a` and 1.0 to `b`.
+    #[inline(always)]
+    pub fn interpolate(&self, a: impl Coordinate<T>, b: impl Coordinate<T>, t: T) -> (T, T) {
+        let (a, b) = (a.lat_lon(), b.lat_lon());
+        // Plane projection is linear, so interpolation in (lat, lon) gives the same result
+        (
+            a.0 + (b.0 - a.0) * t,
+            wrap_lon(a.1 + lon_diff(b.1, a.1) * t),
+        )
+    }
+
+    /// Midpoint between two points in the projection space.
+    #[inline(always)]
+    pub fn midpoint(&self, a: impl Coordinate<T>, b: impl Coordinate<T>) -> (T, T) {
+        self.interpolate(a, b, T::from_f64(0.5))
+    }
+
+    /// Translates a point by `dx` meters to the East and `dy` meters to the North.
+    #[inline(always)]
+    pub fn offset(&self, point: impl Coordinate<T>, dx: T, dy: T) -> (T, T) {
+        let point = point.lat_lon();
+        (
+            point.0 + dy / self.lat_scale,
+            wrap_lon(point.1 + dx / self.lon_scale),
+        )
+    }
 }
 
+impl PlaneProjection {
+    /// Maximum absolute latitude in degrees accepted by [`PlaneProjection::try_new()`]. Closer to the poles
+    /// meridians converge too fast and the longitude scale changes significantly within a few kilometers.
+    pub const MAX_LATITUDE: f64 = 80.0;
+
+    /// Creates a plane projection at the middle of the latitude extent of the `points`, which minimizes
+    /// the worst-case error of distances between them. Returns `None` if there are no points.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::for_points([(55.60, 13.00), (59.33, 18.06), (57.70, 11.97)]).unwrap();
+    /// assert_eq!(proj.latitude(), 57.465);
+    /// ```
+    pub fn for_points(points: impl IntoIterator<Item = impl Coordinate>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?.lat();
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (min.min(point.lat()), max.max(point.lat()))
+        });
+        Some(Self::new((min + max) * 0.5))
+    }
+
+    /// Same as [`PlaneProjection::for_points()`], but also returns the estimated worst-case relative error
+    /// of distances between the points, see [`PlaneProjection::bbox_error_estimate()`].
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// // Distances along the parallels at the box edges are up to 5% off
+    /// let (proj, error) = PlaneProjection::for_points_with_error(&[(55.60, 13.00), (59.33, 18.06)]).unwrap();
+    /// assert!(error > 0.05 && error < 0.06);
+    /// ```
+    pub fn for_points_with_error(
+        points: impl IntoIterator<Item = impl Coordinate>,
+    ) -> Option<(Self, f64)> {
+        let points = points
+            .into_iter()
+            .map(|point| point.lat_lon())
+            .collect::<alloc::vec::Vec<_>>();
+        let bbox = BBox::from_points(&points)?;
+        let proj = Self::new(bbox.center().0);
+        let error = proj.bbox_error_estimate(bbox);
+        Some((proj, error))
+    }
+
+    /// Creates a plane projection at the middle latitude of the `bbox`, see [`PlaneProjection::for_points()`].
+    pub fn for_bbox(bbox: BBox) -> Self {
+        Self::new((bbox.min.0 + bbox.max.0) * 0.5)
+    }
+
+    /// Same as [`PlaneProjection::for_bbox()`], but fails if the estimated worst-case relative error of distances
+    /// within the `bbox` exceeds `max_error`, so `0.001` means 0.1%, see [`PlaneProjection::bbox_error_estimate()`].
+    ///
+    /// ```
+    /// use plane_projection::{BBox, PlaneProjection};
+    ///
+    /// let city = BBox { min: (55.55, 12.90), max: (55.75, 13.25) };
+    /// assert!(PlaneProjection::try_for_bbox(city, 0.005).is_ok());
+    ///
+    /// let country = BBox { min: (55.3, 11.0), max: (69.1, 24.2) };
+    /// assert!(PlaneProjection::try_for_bbox(country, 0.005).is_err());
+    /// ```
+    pub fn try_for_bbox(bbox: BBox, max_error: f64) -> Result<Self, ExtentTooLarge> {
+        let proj = Self::for_bbox(bbox);
+        let error = proj.bbox_error_estimate(bbox);
+        if error <= max_error {
+            Ok(proj)
+        } else {
+            Err(ExtentTooLarge { error, max_error })
+        }
+    }
+
+    /// Creates a plane projection at the middle latitude between `a` and `b`, which is the most precise one
+    /// to measure the distance between them.
+    pub fn for_pair(a: impl Coordinate, b: impl Coordinate) -> Self {
+        Self::new((a.lat() + b.lat()) * 0.5)
+    }
+
+    /// Creates a plane projection to the Earth at provided latitude, if it is within the usable range of
+    /// [-[`PlaneProjection::MAX_LATITUDE`], [`PlaneProjection::MAX_LATITUDE`]] degrees.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// assert!(PlaneProjection::try_new(55.65).is_ok());
+    /// let error = PlaneProjection::try_new(85.0).err().unwrap();
+    /// assert_eq!(error.to_string(), "latitude 85 is outside of the usable range [-80, 80]");
+    /// ```
+    pub fn try_new(latitude: f64) -> Result<Self, UnsupportedLatitude> {
+        if latitude.abs() <= Self::MAX_LATITUDE {
+            Ok(Self::new(latitude))
+        } else {
+            Err(UnsupportedLatitude { latitude })
+        }
+    }
+
+    /// Creates a plane projection to the Earth at provided latitude using full `f64` trigonometry,
+    /// for survey-grade use cases where the last decimeter matters more than a few nanoseconds.
+    pub fn new_precise(latitude: f64) -> Self {
+        Self::from_cos_lat(latitude, latitude.to_radians().cos(), Ellipsoid::WGS84)
+    }
+
+    /// Creates a plane projection to the `ellipsoid` at the `latitude` with `cos_lat` cosine.
+    #[inline(always)]
+    fn from_cos_lat(latitude: f64, cos_lat: f64, ellipsoid: Ellipsoid) -> Self {
+        let w2 = ellipsoid.curvature_w2(cos_lat);
+        let (lon_scale, lat_scale) = ellipsoid.scales(cos_lat, w2, w2.sqrt());
+        Self {
+            lon_scale,
+            lat_scale,
+            latitude,
+            ellipsoid,
+        }
+    }
+
+    /// Creates a plane projection to the Earth at provided latitude at compile time, so it can be stored
+    /// in a `const` or `static`. Uses series expansions instead of hardware trigonometry, so it is slower
+    /// than [`PlaneProjection::new()`] at runtime, but slightly more precise.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// static PROJ: PlaneProjection = PlaneProjection::new_const(55.65);
+    /// let distance = PROJ.distance((55.704141722528554, 13.191304107330561), (55.60330902847681, 13.001973666557435));
+    /// assert_eq!(distance as u32, 16373);
+    /// ```
+    pub const fn new_const(latitude: f64) -> Self {
+        let cos_lat = const_cos(latitude.to_radians());
+        let ellipsoid = Ellipsoid::WGS84;
+        let w2 = ellipsoid.curvature_w2(cos_lat);
+        let (lon_scale, lat_scale) = ellipsoid.scales(cos_lat, w2, const_sqrt(w2));
+        Self {
+            lon_scale,
+            lat_scale,
+            latitude,
+            ellipsoid,
+        }
+    }
+
+    /// Moves the projection to the new `latitude` in place, which costs the same as [`PlaneProjection::new()`]:
+    /// a single `f32` cosine and a square root, so trackers following a moving vehicle can call it on every fix.
+    /// The projection keeps its [`PlaneProjection::ellipsoid()`].
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let mut proj = PlaneProjection::new(55.65);
+    /// let position = (57.70, 11.97);
+    /// if proj.drift_exceeded(position.0, 0.001) {
+    ///     proj.recenter(position.0);
+    /// }
+    /// assert_eq!(proj.latitude(), 57.70);
+    /// ```
+    #[inline(always)]
+    pub fn recenter(&mut self, latitude: f64) {
+        *self = Self::with_ellipsoid(latitude, self.ellipsoid);
+    }
+
+    /// Checks if distances around the `latitude` have the estimated relative error over `max_error`,
+    /// so `0.001` means 0.1%, which means it is time to [`PlaneProjection::recenter()`] the projection.
+    /// See [`PlaneProjection::error_estimate()`] for details.
+    #[inline(always)]
+    pub fn drift_exceeded(&self, latitude: f64, max_error: f64) -> bool {
+        Self::error_estimate(self.latitude, latitude - self.latitude, 0.0) > max_error
+    }
+
+    /// Destination point reached by moving `distance` meters from `origin` along the `heading` in degrees,
+    /// using the same convention as [`PlaneProjection::heading()`]: 0.0 is North, 90.0 is East and so on.
+    #[inline(always)]
+    pub fn destination(&self, origin: impl Coordinate, heading: f64, distance: f64) -> LatLon {
+        let (sin, cos) = heading.to_radians().sin_cos();
+        self.offset(origin, distance * sin, distance * cos)
+    }
+}
+
+/// Cosine for const contexts, using the Taylor series after reducing the angle to [0, PI/2].
+const fn const_cos(x: f64) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    // `round()` is not const, so reduce with a truncating cast followed by the correction
+    let mut x = x - (x / TAU) as i64 as f64 * TAU;
+    if x > PI {
+        x -= TAU;
+    } else if x < -PI {
+        x += TAU;
+    }
+    // cos(x) = cos(-x) = -cos(PI - x)
+    let (x, sign) = match x.abs() {
+        x if x > FRAC_PI_2 => (PI - x, -1.0),
+        x => (x, 1.0),
+    };
+
+    // Series terms on [0, PI/2] fall below f64 precision after x^26/26!
+    let x2 = x * x;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut n = 1;
+    while n <= 13 {
+        term *= -x2 / ((2 * n - 1) * (2 * n)) as f64;
+        sum += term;
+        n += 1;
+    }
+    sign * sum
+}
+
+/// Square root for const contexts, using the Newton's method.
+const fn const_sqrt(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let mut root = if x > 1.0 { x } else { 1.0 };
+    loop {
+        let next = 0.5 * (root + x / root);
+        // Starting above the root, the iterations decrease monotonically until converged
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+impl<T: Float> core::fmt::Debug for PlaneProjection<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PlaneProjection")
+            .field("latitude", &self.latitude.to_f64())
+            .field("lat_scale", &self.lat_scale.to_f64())
+            .field("lon_scale", &self.lon_scale.to_f64())
+            .field("anisotropy", &self.anisotropy().to_f64())
+            .field(
+                "recommended_max_distance",
+                &self.recommended_max_distance(0.001),
+            )
+            .finish()
+    }
+}
+
+impl<T: Float> core::fmt::Display for PlaneProjection<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "plane projection at {}° ({:.3} m/° lat, {:.3} m/° lon)",
+            self.latitude.to_f64(),
+            self.lat_scale.to_f64(),
+            self.lon_scale.to_f64()
+        )
+    }
+}
+
+/// Error returned by [`PlaneProjection::try_new()`] for latitudes where the plane projection is not usable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnsupportedLatitude {
+    /// The requested latitude in degrees.
+    pub latitude: f64,
+}
+
+impl core::fmt::Display for UnsupportedLatitude {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "latitude {} is outside of the usable range [-{max}, {max}]",
+            self.latitude,
+            max = PlaneProjection::MAX_LATITUDE
+        )
+    }
+}
+
+impl core::error::Error for UnsupportedLatitude {}
+
+/// Error returned by [`PlaneProjection::try_for_bbox()`] when the box is too large for the requested precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtentTooLarge {
+    /// Estimated worst-case relative error of distances within the box.
+    pub error: f64,
+    /// The requested maximum relative error.
+    pub max_error: f64,
+}
+
+impl core::fmt::Display for ExtentTooLarge {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "estimated relative error {} exceeds the maximum {}",
+            self.error, self.max_error
+        )
+    }
+}
+
+impl core::error::Error for ExtentTooLarge {}
+
 /// Returns the difference between two longitudes in range [-180.0, 180.0] degrees.
 #[inline(always)]
-fn lon_diff(a: f64, b: f64) -> f64 {
+fn lon_diff<T: Float>(a: T, b: T) -> T {
     let mut lon_diff = a - b;
-    if lon_diff > 180.0 {
-        lon_diff -= 360.0;
-    } else if lon_diff < -180.0 {
-        lon_diff += 360.0;
+    if lon_diff > T::from_f64(180.0) {
+        lon_diff -= T::from_f64(360.0);
+    } else if lon_diff < T::from_f64(-180.0) {
+        lon_diff += T::from_f64(360.0);
     }
     lon_diff
 }
 
+/// Projects the `point` onto the segment from (0.0, 0.0) to `segment` in the projection space,
+/// see [`PlaneProjection::project_to_segment()`].
+#[inline(always)]
+fn project_to_origin_segment<T: Float>(mut point: (T, T), segment: (T, T)) -> (T, T) {
+    let mut t = T::ZERO;
+    if segment.0 != T::ZERO || segment.1 != T::ZERO {
+        // dot(point, segment) = |point| * |segment| * cos(alpha)
+        // dividing by |segment|^2 normalizes to range where 0.0=start, 1.0=end of segment
+        let projection = (point.0 * segment.0 + point.1 * segment.1)
+            / (segment.0 * segment.0 + segment.1 * segment.1);
+        if projection > T::ONE {
+            // adjust `point` vector to the end of segment
+            point.0 -= segment.0;
+            point.1 -= segment.1;
+            t = T::ONE;
+        } else if projection > T::ZERO {
+            // adjust `point` vector so it starts at projected point
+            point.0 -= segment.0 * projection;
+            point.1 -= segment.1 * projection;
+            t = projection;
+        }
+    }
+    // Square length of vector from closest point on segment to original point
+    (t, point.0 * point.0 + point.1 * point.1)
+}
+
+/// Cross product of two vectors in the projection space, which is twice the signed area
+/// of the triangle they form. Positive values mean that `b` is clockwise from `a`.
+#[inline(always)]
+fn cross(a: (f64, f64), b: (f64, f64)) -> f64 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+/// Dot product of two vectors in the projection space.
+#[inline(always)]
+fn dot(a: (f64, f64), b: (f64, f64)) -> f64 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+/// Wraps longitude into range [-180.0, 180.0] degrees.
+#[inline(always)]
+fn wrap_lon<T: Float>(lon: T) -> T {
+    if lon > T::from_f64(180.0) {
+        lon - T::from_f64(360.0)
+    } else if lon < T::from_f64(-180.0) {
+        lon + T::from_f64(360.0)
+    } else {
+        lon
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const MALMO_C: LatLon = (55.60330902847681, 13.001973666557435);
-    const LUND_C: LatLon = (55.704141722528554, 13.191304107330561);
-    const STOCKHOLM_C: LatLon = (59.33036105663399, 18.058682977850953);
+    pub(crate) const MALMO_C: LatLon = (55.60330902847681, 13.001973666557435);
+    pub(crate) const LUND_C: LatLon = (55.704141722528554, 13.191304107330561);
+    pub(crate) const STOCKHOLM_C: LatLon = (59.33036105663399, 18.058682977850953);
+
+    /// Deterministic points spread over the `bbox`, good enough to compare against brute force.
+    pub(crate) fn pseudo_random_points(n: usize, bbox: BBox) -> alloc::vec::Vec<LatLon> {
+        let (lat_range, lon_range) = (bbox.max.0 - bbox.min.0, bbox.max.1 - bbox.min.1);
+        (0..n)
+            .map(|i| {
+                let i = i as f64;
+                (
+                    bbox.min.0 + (i * 0.618).fract() * lat_range,
+                    bbox.min.1 + (i * 0.414).fract() * lon_range,
+                )
+            })
+            .collect()
+    }
+
+    /// Checks that `batch` matches `single` within `tolerance` for every prefix of `items`,
+    /// so every batch length from empty to the full slice is covered.
+    pub(crate) fn assert_batch<I: Copy, O: Copy + From<f32> + Into<f64>>(
+        items: &[I],
+        batch: impl Fn(&[I], &mut [O]),
+        single: impl Fn(I) -> O,
+        tolerance: f64,
+    ) {
+        for len in 0..=items.len() {
+            let mut out = alloc::vec![O::from(f32::NAN); len];
+            batch(&items[..len], &mut out);
+            for (i, (&item, &actual)) in items.iter().zip(&out).enumerate() {
+                let (actual, expected) = (actual.into(), single(item).into());
+                assert!(
+                    (actual - expected).abs() <= tolerance,
+                    "item {i} of {len}: {actual} != {expected}"
+                );
+            }
+        }
+    }
 
     #[test]
     fn lon_diff_test() {
@@ -160,6 +821,11 @@ mod tests {
     fn distance_test() {
         let proj = PlaneProjection::new(55.65);
         assert_eq!(proj.distance(MALMO_C, LUND_C).round() as u32, 16374);
+        // float literals infer to the default `f64` projection
+        assert_eq!(
+            proj.distance((55.6, 13.0), (55.7, 13.0)).round() as u32,
+            11134
+        );
 
         // Geodesic distance is between Malmo and Stockholm is 513_861m and the best precision from
         // the plane projection is when halfway latitude is used.
@@ -171,6 +837,243 @@ mod tests {
         assert_eq!(proj.distance(MALMO_C, STOCKHOLM_C).round() as u32, 505_217); // 1.7% error
     }
 
+    #[test]
+    fn for_points_test() {
+        assert!(PlaneProjection::for_points(core::iter::empty::<LatLon>()).is_none());
+        assert!(PlaneProjection::for_points_with_error(core::iter::empty::<LatLon>()).is_none());
+        let proj = PlaneProjection::for_points([LUND_C]).unwrap();
+        assert_eq!(proj.latitude(), LUND_C.0);
+
+        // the middle of the extent, not the average latitude
+        let points = [MALMO_C, LUND_C, STOCKHOLM_C];
+        let middle = (MALMO_C.0 + STOCKHOLM_C.0) * 0.5;
+        let proj = PlaneProjection::for_points(points.iter()).unwrap();
+        assert_eq!(proj.latitude(), middle);
+        assert_eq!(proj.distance(MALMO_C, STOCKHOLM_C).round() as u32, 514_168);
+
+        let (proj, error) = PlaneProjection::for_points_with_error(points).unwrap();
+        assert_eq!(proj.latitude(), middle);
+        // Geodesic distance is between Malmo and Stockholm is 513_861m
+        assert!((proj.distance(MALMO_C, STOCKHOLM_C) - 513_861.0).abs() / 513_861.0 < error);
+        let (_, worse) = PlaneProjection::for_points_with_error([MALMO_C, (70.0, 13.0)]).unwrap();
+        assert!(worse > error);
+    }
+
+    #[test]
+    fn for_bbox_test() {
+        let bbox = BBox::from_points(&[MALMO_C, LUND_C, STOCKHOLM_C]).unwrap();
+        let middle = (MALMO_C.0 + STOCKHOLM_C.0) * 0.5;
+        assert_eq!(PlaneProjection::for_bbox(bbox).latitude(), middle);
+        assert_eq!(
+            PlaneProjection::for_pair(STOCKHOLM_C, MALMO_C).latitude(),
+            middle
+        );
+
+        let error = PlaneProjection::for_bbox(bbox).bbox_error_estimate(bbox);
+        assert_eq!(
+            PlaneProjection::try_for_bbox(bbox, error)
+                .unwrap()
+                .latitude(),
+            middle
+        );
+        assert_eq!(
+            PlaneProjection::try_for_bbox(bbox, 0.01).unwrap_err(),
+            ExtentTooLarge {
+                error,
+                max_error: 0.01
+            }
+        );
+    }
+
+    #[test]
+    fn recenter_test() {
+        let mut proj = PlaneProjection::new(55.65);
+        assert!(!proj.drift_exceeded(55.65, 0.0));
+        assert!(!proj.drift_exceeded(55.66, 0.001));
+        assert!(proj.drift_exceeded(55.75, 0.001));
+        assert!(proj.drift_exceeded(55.55, 0.001));
+
+        proj.recenter(STOCKHOLM_C.0);
+        let expected = PlaneProjection::new(STOCKHOLM_C.0);
+        assert_eq!(proj.latitude(), STOCKHOLM_C.0);
+        assert_eq!(
+            proj.distance(STOCKHOLM_C, MALMO_C),
+            expected.distance(STOCKHOLM_C, MALMO_C)
+        );
+        assert!(!proj.drift_exceeded(STOCKHOLM_C.0, 0.001));
+
+        // keeps the ellipsoid
+        let mut proj = PlaneProjection::with_ellipsoid(55.65, Ellipsoid::SPHERE);
+        proj.recenter(STOCKHOLM_C.0);
+        let expected = PlaneProjection::with_ellipsoid(STOCKHOLM_C.0, Ellipsoid::SPHERE);
+        assert_eq!(proj.ellipsoid(), Ellipsoid::SPHERE);
+        assert_eq!(proj.lat_scale(), expected.lat_scale());
+        assert_eq!(proj.lon_scale(), expected.lon_scale());
+        assert_ne!(
+            proj.distance(STOCKHOLM_C, MALMO_C),
+            PlaneProjection::new(STOCKHOLM_C.0).distance(STOCKHOLM_C, MALMO_C)
+        );
+        assert_eq!(PlaneProjection::new(0.0).ellipsoid(), Ellipsoid::WGS84);
+    }
+
+    #[test]
+    fn new_const_test() {
+        const PROJ: PlaneProjection = PlaneProjection::new_const(55.65);
+        let proj = PlaneProjection::new(55.65);
+        assert!((PROJ.lon_scale - proj.lon_scale).abs() / proj.lon_scale < 1e-6);
+        assert!((PROJ.lat_scale - proj.lat_scale).abs() / proj.lat_scale < 1e-6);
+
+        for latitude in [
+            -200.0, -90.0, -65.0, -1.0, 0.0, 12.3, 45.0, 89.0, 90.0, 400.0,
+        ] {
+            let cos = const_cos(f64::to_radians(latitude));
+            assert!((cos - f64::to_radians(latitude).cos()).abs() < 1e-14);
+        }
+        for x in [0.0, 1e-6, 0.5, 1.0, 1.0067, 2.0, 1e10] {
+            assert!((const_sqrt(x) - x.sqrt()).abs() <= x.sqrt() * f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn new_precise_test() {
+        let precise = PlaneProjection::new_precise(55.65);
+        let proj = PlaneProjection::new(55.65);
+        assert!((precise.lon_scale - proj.lon_scale).abs() / proj.lon_scale < 1e-6);
+        assert!((precise.lat_scale - proj.lat_scale).abs() / proj.lat_scale < 1e-6);
+        for latitude in [-89.0, -55.65, 0.0, 12.3, 45.0, 78.9] {
+            let precise = PlaneProjection::new_precise(latitude);
+            let expected = PlaneProjection::new_const(latitude);
+            assert!((precise.lon_scale - expected.lon_scale).abs() / expected.lon_scale < 1e-12);
+            assert!((precise.lat_scale - expected.lat_scale).abs() / expected.lat_scale < 1e-15);
+        }
+    }
+
+    #[test]
+    fn accessors_test() {
+        let proj: PlaneProjection = PlaneProjection::new(55.65);
+        assert_eq!(proj.latitude(), 55.65);
+        assert!((proj.lat_scale() - 111_335.0).abs() < 1.0);
+        assert!((proj.lon_scale() - 62_955.0).abs() < 1.0);
+        assert!((proj.anisotropy() - 55.65f64.to_radians().cos()).abs() < 0.005);
+        assert_eq!(proj.cast::<f32>().latitude(), 55.65);
+        assert_eq!(PlaneProjection::new_const(55.65).latitude(), 55.65);
+        assert!((PlaneProjection::new(0.0_f64).anisotropy() - 1.0).abs() < 0.01);
+
+        let debug = alloc::format!("{proj:?}");
+        assert!(debug.starts_with("PlaneProjection { latitude: 55.65, lat_scale: 111"));
+        assert!(debug.contains("anisotropy: 0.56"));
+        assert_eq!(
+            alloc::format!("{}", PlaneProjection::new(0.0)),
+            "plane projection at 0° (110574.276 m/° lat, 111319.491 m/° lon)"
+        );
+    }
+
+    #[test]
+    fn generic_new_test() {
+        let proj = PlaneProjection::<f32>::new(55.65);
+        let expected = PlaneProjection::new(55.65).cast::<f32>();
+        assert_eq!(proj.latitude(), 55.65);
+        assert_eq!(proj.lat_scale(), expected.lat_scale());
+        assert_eq!(proj.lon_scale(), expected.lon_scale());
+
+        let (malmo, lund) = ((55.60331, 13.00197), (55.70414, 13.1913));
+        assert_eq!(proj.distance(malmo, lund) as u32, 16373);
+        assert_eq!(proj.heading(lund, malmo) as u32, 226);
+
+        let sphere = PlaneProjection::<f32>::with_ellipsoid(55.65, Ellipsoid::SPHERE);
+        let expected = PlaneProjection::with_ellipsoid(55.65, Ellipsoid::SPHERE).cast::<f32>();
+        assert_eq!(sphere.ellipsoid(), Ellipsoid::SPHERE);
+        assert_eq!(sphere.lon_scale(), expected.lon_scale());
+    }
+
+    #[test]
+    fn try_new_test() {
+        let proj = PlaneProjection::try_new(55.65).unwrap();
+        assert_eq!(
+            proj.distance(MALMO_C, LUND_C),
+            PlaneProjection::new(55.65).distance(MALMO_C, LUND_C)
+        );
+        assert!(PlaneProjection::try_new(-80.0).is_ok());
+        assert_eq!(
+            PlaneProjection::try_new(-80.1).err(),
+            Some(UnsupportedLatitude { latitude: -80.1 })
+        );
+        assert!(PlaneProjection::try_new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn is_within_test() {
+        let proj = PlaneProjection::new(55.65);
+        let distance = proj.distance(MALMO_C, LUND_C);
+        assert!(proj.is_within(MALMO_C, LUND_C, distance + 0.001));
+        assert!(!proj.is_within(MALMO_C, LUND_C, distance - 1.0));
+        assert!(proj.is_within(MALMO_C, MALMO_C, 0.0));
+    }
+
+    #[test]
+    fn project_to_segment_test() {
+        let proj = PlaneProjection::new(0.0);
+        let segment = ((0.0, 1.0), (0.0, 3.0));
+        assert_eq!(proj.project_to_segment((0.0, 2.0), segment), (0.5, 0.0));
+        assert_eq!(
+            proj.project_to_segment((0.0, 0.0), segment),
+            (0.0, proj.square_distance((0.0, 0.0), (0.0, 1.0)))
+        );
+        assert_eq!(
+            proj.project_to_segment((0.0, 4.5), segment),
+            (1.0, proj.square_distance((0.0, 4.5), (0.0, 3.0)))
+        );
+        let (t, square_distance) = proj.project_to_segment((1.0, 1.5), segment);
+        assert_eq!(t, 0.25);
+        assert_eq!(
+            square_distance,
+            proj.square_distance_to_segment((1.0, 1.5), segment)
+        );
+
+        // zero-length segment
+        assert_eq!(
+            proj.project_to_segment((1.0, 2.0), ((3.0, 3.0), (3.0, 3.0))),
+            (0.0, proj.square_distance((1.0, 2.0), (3.0, 3.0)))
+        );
+    }
+
+    #[test]
+    fn closest_point_on_segment_test() {
+        let proj = PlaneProjection::new(0.0);
+        let segment = ((0.0, 1.0), (0.0, 3.0));
+        assert_eq!(
+            proj.closest_point_on_segment((1.0, 2.0), segment),
+            (0.0, 2.0)
+        );
+        assert_eq!(
+            proj.closest_point_on_segment((0.0, 0.0), segment),
+            (0.0, 1.0)
+        );
+        assert_eq!(
+            proj.closest_point_on_segment((0.0, 4.0), segment),
+            (0.0, 3.0)
+        );
+        assert_eq!(
+            proj.closest_point_on_segment((1.0, 2.0), ((3.0, 3.0), (3.0, 3.0))),
+            (3.0, 3.0)
+        );
+
+        // across the antimeridian
+        let segment = ((0.0, 179.0), (0.0, -179.0));
+        let snapped = proj.closest_point_on_segment((1.0, 179.5), segment);
+        assert!(proj.distance(snapped, (0.0, 179.5)) < 1e-6);
+
+        let proj = PlaneProjection::new(55.65);
+        let point = (55.68, 13.05);
+        let snapped = proj.closest_point_on_segment(point, (MALMO_C, LUND_C));
+        assert!(
+            (proj.distance(point, snapped) - proj.distance_to_segment(point, (MALMO_C, LUND_C)))
+                .abs()
+                < 1e-6
+        );
+        assert!(proj.distance_to_segment(snapped, (MALMO_C, LUND_C)) < 1e-6);
+    }
+
     #[test]
     fn distance_to_segment_test() {
         let proj = PlaneProjection::new(0.0);
@@ -227,4 +1130,159 @@ mod tests {
         assert_eq!(proj.heading(MALMO_C, LUND_C,) as i32, 46);
         assert_eq!(proj.heading(LUND_C, MALMO_C,) as i32, 180 + 46);
     }
+
+    #[test]
+    fn heading_f64_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.heading_f64((55.70, 13.19), (55.80, 13.19)), 0.0);
+        assert_eq!(proj.heading_f64((55.70, 13.19), (55.70, 13.29)), 90.0);
+        assert_eq!(proj.heading_f64((55.70, 13.19), (55.60, 13.19)), 180.0);
+        assert_eq!(proj.heading_f64((55.70, 13.19), (55.70, 13.09)), 270.0);
+
+        let heading = proj.heading_f64(LUND_C, MALMO_C);
+        assert!((heading - proj.heading(LUND_C, MALMO_C) as f64).abs() < 1e-4);
+        // Round trip via `destination()` stays within a micrometer with f64 heading
+        let distance = proj.distance(LUND_C, MALMO_C);
+        let destination = proj.destination(LUND_C, heading, distance);
+        assert!(proj.distance(destination, MALMO_C) < 1e-6);
+    }
+
+    #[test]
+    fn heading_fast_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.heading_fast((55.70, 13.19), (55.80, 13.19)), 0.0);
+        assert_eq!(proj.heading_fast((55.70, 13.19), (55.70, 13.29)), 90.0);
+        assert_eq!(proj.heading_fast((55.70, 13.19), (55.60, 13.19)), 180.0);
+        assert_eq!(proj.heading_fast((55.70, 13.19), (55.70, 13.09)), 270.0);
+        assert_eq!(
+            proj.heading_fast(LUND_C, LUND_C),
+            proj.heading(LUND_C, LUND_C)
+        );
+
+        // all directions are within the documented error
+        let mut max_error: f64 = 0.0;
+        for i in 0..3600 {
+            let target = proj.destination(LUND_C, i as f64 * 0.1, 1000.0);
+            let error = heading_difference(
+                proj.heading_f64(LUND_C, target),
+                proj.heading_fast(LUND_C, target) as f64,
+            );
+            max_error = max_error.max(error.abs());
+        }
+        assert!(max_error < 0.04, "{max_error}");
+        assert!(max_error > 0.01);
+    }
+
+    #[test]
+    fn destination_test() {
+        let proj = PlaneProjection::new(55.65);
+        let heading = proj.heading(LUND_C, MALMO_C) as f64;
+        let distance = proj.distance(LUND_C, MALMO_C);
+        let destination = proj.destination(LUND_C, heading, distance);
+        assert!(proj.distance(destination, MALMO_C) < 0.01);
+
+        let destination = proj.destination(LUND_C, 0.0, 1000.0);
+        assert_eq!(destination.1, LUND_C.1);
+        assert_eq!(proj.distance(LUND_C, destination).round(), 1000.0);
+        let destination = proj.destination(LUND_C, 270.0, 1000.0);
+        assert!((destination.0 - LUND_C.0).abs() < 1e-12);
+        assert_eq!(proj.distance(LUND_C, destination).round(), 1000.0);
+
+        // wraps over the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let destination = proj.destination((0.0, 179.999), 90.0, 1000.0);
+        assert!(destination.1 < -179.99);
+        assert_eq!(proj.distance((0.0, 179.999), destination).round(), 1000.0);
+    }
+
+    #[test]
+    fn offset_test() {
+        let proj = PlaneProjection::new(55.65);
+        let point = proj.offset(LUND_C, 300.0, 400.0);
+        assert!((proj.distance(LUND_C, point) - 500.0).abs() < 1e-6);
+        assert!(point.0 > LUND_C.0 && point.1 > LUND_C.1);
+
+        let point = proj.offset(LUND_C, -300.0, 0.0);
+        assert_eq!(point.0, LUND_C.0);
+        assert_eq!(proj.heading(LUND_C, point).round(), 270.0);
+
+        assert_eq!(proj.offset(LUND_C, 0.0, 0.0), LUND_C);
+    }
+
+    #[test]
+    fn interpolate_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.interpolate(MALMO_C, LUND_C, 0.0), MALMO_C);
+        assert_eq!(proj.interpolate(MALMO_C, LUND_C, 1.0), LUND_C);
+
+        let distance = proj.distance(MALMO_C, LUND_C);
+        let point = proj.interpolate(MALMO_C, LUND_C, 0.25);
+        assert!((proj.distance(MALMO_C, point) - distance * 0.25).abs() < 1e-6);
+        assert!(proj.distance_to_segment(point, (MALMO_C, LUND_C)) < 1e-6);
+
+        // extrapolation
+        let point = proj.interpolate(MALMO_C, LUND_C, 2.0);
+        assert!((proj.distance(LUND_C, point) - distance).abs() < 1e-6);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        assert_eq!(
+            proj.interpolate((0.0, 179.0), (0.0, -177.0), 0.5),
+            (0.0, -179.0)
+        );
+    }
+
+    #[test]
+    fn midpoint_test() {
+        let proj = PlaneProjection::new(55.65);
+        let midpoint = proj.midpoint(MALMO_C, LUND_C);
+        assert!((proj.distance(midpoint, MALMO_C) - proj.distance(midpoint, LUND_C)).abs() < 1e-6);
+        assert_eq!(proj.midpoint(MALMO_C, MALMO_C), MALMO_C);
+
+        let proj = PlaneProjection::new(0.0);
+        assert_eq!(proj.midpoint((1.0, 179.0), (-1.0, -179.0)), (0.0, 180.0));
+    }
+
+    #[test]
+    fn unproject_test() {
+        let proj = PlaneProjection::new(55.65);
+        for point in [MALMO_C, LUND_C, STOCKHOLM_C, (0.0, 0.0), (-33.86, 151.21)] {
+            let unprojected = proj.unproject(proj.project(point));
+            assert!((unprojected.0 - point.0).abs() < 1e-12);
+            assert!((unprojected.1 - point.1).abs() < 1e-12);
+        }
+
+        // vector math in the projection space
+        let (a, b) = (proj.project(MALMO_C), proj.project(LUND_C));
+        let middle = proj.unproject((a + b) * 0.5);
+        assert!((proj.distance(middle, MALMO_C) - proj.distance(middle, LUND_C)).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let proj = PlaneProjection::new(55.65);
+        let json = serde_json::to_string(&proj).unwrap();
+        let restored: PlaneProjection = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored.distance(MALMO_C, LUND_C),
+            proj.distance(MALMO_C, LUND_C)
+        );
+
+        let bbox = BBox {
+            min: MALMO_C,
+            max: LUND_C,
+        };
+        let json = serde_json::to_string(&bbox).unwrap();
+        assert_eq!(serde_json::from_str::<BBox>(&json).unwrap(), bbox);
+
+        let point = GeoPoint::try_from(MALMO_C).unwrap();
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(serde_json::from_str::<GeoPoint>(&json).unwrap(), point);
+        assert!(serde_json::from_str::<GeoPoint>("[91.0, 0.0]").is_err());
+
+        let nearest = proj.point_on_line(STOCKHOLM_C, &[MALMO_C, LUND_C]);
+        let json = serde_json::to_string(&nearest).unwrap();
+        assert_eq!(serde_json::from_str::<PointOnLine>(&json).unwrap(), nearest);
+    }
 }
@@ -0,0 +1,98 @@
+//! Float math functions that are only available in `std`, backed by `libm` in `no_std` builds.
+
+/// Mirrors the `std` float methods, so the same code compiles with and without `std`.
+pub(crate) trait Float: Sized {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($float:ty, sin_cos = $sincos:ident, atan2 = $atan2:ident, $($method:ident = $libm:ident),*) => {
+        impl Float for $float {
+            #[inline(always)]
+            fn sin_cos(self) -> (Self, Self) {
+                libm::$sincos(self)
+            }
+            #[inline(always)]
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+            $(
+                #[inline(always)]
+                fn $method(self) -> Self {
+                    libm::$libm(self)
+                }
+            )*
+        }
+    };
+}
+
+impl_float!(
+    f64,
+    sin_cos = sincos,
+    atan2 = atan2,
+    sin = sin,
+    cos = cos,
+    asin = asin,
+    atan = atan,
+    floor = floor,
+    ceil = ceil,
+    exp = exp,
+    ln = log
+);
+impl_float!(
+    f32,
+    sin_cos = sincosf,
+    atan2 = atan2f,
+    sin = sinf,
+    cos = cosf,
+    asin = asinf,
+    atan = atanf,
+    floor = floorf,
+    ceil = ceilf,
+    exp = expf,
+    ln = logf
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+
+    #[test]
+    fn libm_float_test() {
+        // The test harness links `std`, so `libm` results can be compared to the inherent methods
+        for x in [-100.0, -2.5, -1.0, -0.3, 0.0, 0.3, 1.0, 2.5, 100.0f64] {
+            let close = |a: f64, b: f64| (a - b).abs() <= 1e-15 * b.abs().max(1.0);
+            assert!(close(Float::sin(x), x.sin()));
+            assert!(close(Float::cos(x), x.cos()));
+            assert!(close(Float::sin_cos(x).0, x.sin()) && close(Float::sin_cos(x).1, x.cos()));
+            assert!(close(Float::atan(x), x.atan()));
+            assert!(close(Float::atan2(x, -1.5), x.atan2(-1.5)));
+            assert_eq!(Float::floor(x), x.floor());
+            assert_eq!(Float::ceil(x), x.ceil());
+            assert!(close(Float::exp(x / 10.0), (x / 10.0).exp()));
+            assert!(close(Float::ln(x.abs() + 1.0), (x.abs() + 1.0).ln()));
+            assert!(close(
+                Float::asin(x.clamp(-1.0, 1.0)),
+                x.clamp(-1.0, 1.0).asin()
+            ));
+
+            let (x, close) = (x as f32, |a: f32, b: f32| {
+                (a - b).abs() <= 1e-6 * b.abs().max(1.0)
+            });
+            assert!(close(Float::sin(x), x.sin()));
+            assert!(close(Float::cos(x), x.cos()));
+            assert!(close(Float::atan2(x, -1.5), x.atan2(-1.5)));
+            assert_eq!(Float::floor(x), x.floor());
+            assert!(close(Float::exp(x / 10.0), (x / 10.0).exp()));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{LatLon, PlaneProjection, wrap_lon};
+
+impl PlaneProjection {
+    /// Z-order (Morton) code of the square cell of `cell_size` meters in the projection space that contains
+    /// the point. Nearby points share long code prefixes, so codes are good cache and database sort keys.
+    /// Codes are only comparable between the same projection and cell size.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let a = proj.morton_code((55.7041417, 13.1913041), 1.0);
+    /// let b = proj.morton_code((55.7041420, 13.1913045), 1.0);
+    /// assert_eq!(a >> 8, b >> 8);
+    /// ```
+    pub fn morton_code(&self, point: LatLon, cell_size: f64) -> u64 {
+        // Shift to non-negative coordinates, so the cell indices fit into `u32`
+        let north = (point.0.clamp(-90.0, 90.0) + 90.0) * self.lat_scale / cell_size;
+        let east = (wrap_lon(point.1) + 180.0) * self.lon_scale / cell_size;
+        let cell = |value: f64| value.floor().clamp(0.0, u32::MAX as f64) as u32;
+        spread_bits(cell(east)) | spread_bits(cell(north)) << 1
+    }
+
+    /// Center of the cell with the Morton code, the inverse of [`PlaneProjection::morton_code()`].
+    pub fn morton_decode(&self, code: u64, cell_size: f64) -> LatLon {
+        let east = (compact_bits(code) as f64 + 0.5) * cell_size;
+        let north = (compact_bits(code >> 1) as f64 + 0.5) * cell_size;
+        (
+            north / self.lat_scale - 90.0,
+            wrap_lon(east / self.lon_scale - 180.0),
+        )
+    }
+}
+
+/// Spreads bits of the value into even bits of the result.
+#[inline(always)]
+fn spread_bits(value: u32) -> u64 {
+    let mut x = value as u64;
+    x = (x | x << 16) & 0x0000_ffff_0000_ffff;
+    x = (x | x << 8) & 0x00ff_00ff_00ff_00ff;
+    x = (x | x << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | x << 2) & 0x3333_3333_3333_3333;
+    (x | x << 1) & 0x5555_5555_5555_5555
+}
+
+/// Inverse of [`spread_bits()`], collecting even bits of the value.
+#[inline(always)]
+fn compact_bits(value: u64) -> u32 {
+    let mut x = value & 0x5555_5555_5555_5555;
+    x = (x | x >> 1) & 0x3333_3333_3333_3333;
+    x = (x | x >> 2) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | x >> 4) & 0x00ff_00ff_00ff_00ff;
+    x = (x | x >> 8) & 0x0000_ffff_0000_ffff;
+    (x | x >> 16) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn spread_bits_test() {
+        assert_eq!(spread_bits(0), 0);
+        assert_eq!(spread_bits(0b1011), 0b1000101);
+        assert_eq!(spread_bits(u32::MAX), 0x5555_5555_5555_5555);
+        for value in [0, 1, 12345, 0xdead_beef, u32::MAX] {
+            assert_eq!(compact_bits(spread_bits(value)), value);
+            assert_eq!(compact_bits(spread_bits(value) << 1 >> 1), value);
+        }
+    }
+
+    #[test]
+    fn morton_code_test() {
+        let proj = PlaneProjection::new(55.65);
+        for point in [LUND_C, MALMO_C] {
+            let code = proj.morton_code(point, 1.0);
+            // the cell center is within half of the cell diagonal
+            let center = proj.morton_decode(code, 1.0);
+            assert!(proj.distance(point, center) <= 0.5f64.sqrt());
+            assert_eq!(proj.morton_code(center, 1.0), code);
+        }
+        assert_ne!(
+            proj.morton_code(LUND_C, 1.0),
+            proj.morton_code(MALMO_C, 1.0)
+        );
+
+        // cells of the larger size are prefixes
+        let code = proj.morton_code(LUND_C, 1.0);
+        let coarse = proj.morton_code(LUND_C, 1024.0);
+        assert_eq!(code >> 20, coarse);
+
+        // neighbors in the same 4x4 block share the same code prefix
+        let block = proj.morton_code(LUND_C, 4.0);
+        let origin = proj.morton_decode(block << 4, 1.0);
+        for i in 0..16 {
+            let point = proj.offset(origin, (i % 4) as f64, (i / 4) as f64);
+            assert_eq!(proj.morton_code(point, 1.0) >> 4, block);
+        }
+
+        // wraps over the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        assert_eq!(
+            proj.morton_code((0.0, 180.5), 100.0),
+            proj.morton_code((0.0, -179.5), 100.0)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(not(feature = "std"))]
+use crate::Float as _;
+use crate::{LatLon, PlaneProjection, cross};
+use alloc::vec::Vec;
+
+/// How the offset segments are connected at the polyline vertices, see [`PlaneProjection::parallel_offset()`].
+/// On the inner side of a turn segments are always joined at their intersection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineJoin {
+    /// Extends the offset segments until they meet, unless the miter length exceeds `limit` times
+    /// the offset distance, in which case the joint is beveled.
+    Miter { limit: f64 },
+    /// Connects the ends of the offset segments directly, cutting the corner.
+    Bevel,
+}
+
+impl PlaneProjection {
+    /// Offsets the polyline laterally by `distance` meters, to the right of the path for positive values
+    /// and to the left for negative ones. Useful to build lane-level guides and corridor edges from road
+    /// centerlines. Repeated points are skipped and a polyline with a single point is returned as is.
+    ///
+    /// ```
+    /// use plane_projection::{LineJoin, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = [(55.60, 13.00), (55.61, 13.00), (55.61, 13.02)];
+    /// let left = proj.parallel_offset(&line, -10.0, LineJoin::Miter { limit: 2.0 });
+    /// assert_eq!(left.len(), 3);
+    /// assert_eq!(proj.cross_track_distance(left[1], (line[0], line[1])).round(), -10.0);
+    /// assert_eq!(proj.cross_track_distance(left[1], (line[1], line[2])).round(), -10.0);
+    /// ```
+    pub fn parallel_offset(&self, line: &[LatLon], distance: f64, join: LineJoin) -> Vec<LatLon> {
+        let Some(&origin) = line.first() else {
+            return Vec::new();
+        };
+        // Transform to local Cartesian coordinates with the first point as origin and skip repeated points
+        let mut points: Vec<(f64, f64)> = Vec::with_capacity(line.len());
+        for &point in line {
+            let point = self.project_relative(origin, point);
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+        if points.len() < 2 {
+            return line[..1].to_vec();
+        }
+
+        // Unit normal to the right of the segment direction, which is (North, East)
+        let normal = |a: (f64, f64), b: (f64, f64)| {
+            let (dy, dx) = (b.0 - a.0, b.1 - a.1);
+            let length = (dx * dx + dy * dy).sqrt();
+            (-dx / length, dy / length)
+        };
+        let shift =
+            |p: (f64, f64), n: (f64, f64), scale: f64| (p.0 + n.0 * scale, p.1 + n.1 * scale);
+
+        let mut result = Vec::with_capacity(points.len());
+        let mut prev_normal = normal(points[0], points[1]);
+        result.push(shift(points[0], prev_normal, distance));
+        for window in points.windows(3) {
+            let (vertex, next_normal) = (window[1], normal(window[1], window[2]));
+            // |n1 + n2| = 2 cos(alpha / 2), where alpha is the turn angle
+            let bisector = (prev_normal.0 + next_normal.0, prev_normal.1 + next_normal.1);
+            let square_length = bisector.0 * bisector.0 + bisector.1 * bisector.1;
+            // Right turns with positive offset and left turns with negative offset are on the inner side
+            let is_outer = cross(prev_normal, next_normal) * distance < 0.0;
+            let miter_ratio = 2.0 / square_length.sqrt();
+            let use_miter = match join {
+                _ if square_length < 1e-12 => false,
+                _ if !is_outer => true,
+                LineJoin::Miter { limit } => miter_ratio <= limit,
+                LineJoin::Bevel => miter_ratio <= 1.0 + 1e-12,
+            };
+            if use_miter {
+                result.push(shift(vertex, bisector, 2.0 * distance / square_length));
+            } else {
+                result.push(shift(vertex, prev_normal, distance));
+                result.push(shift(vertex, next_normal, distance));
+            }
+            prev_normal = next_normal;
+        }
+        result.push(shift(points[points.len() - 1], prev_normal, distance));
+
+        result
+            .into_iter()
+            .map(|p| self.unproject_relative(origin, p))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn parallel_offset_test() {
+        let proj = PlaneProjection::new(0.0);
+        let miter = LineJoin::Miter { limit: 2.0 };
+        assert!(proj.parallel_offset(&[], 10.0, miter).is_empty());
+        assert_eq!(
+            proj.parallel_offset(&[(0.0, 0.0)], 10.0, miter),
+            [(0.0, 0.0)]
+        );
+        assert_eq!(
+            proj.parallel_offset(&[(0.0, 0.0), (0.0, 0.0)], 10.0, miter),
+            [(0.0, 0.0)]
+        );
+
+        // straight line heading North is shifted to the East, repeated and collinear points collapse
+        let line = [(0.0, 0.0), (0.0, 0.0), (0.005, 0.0), (0.01, 0.0)];
+        let right = proj.parallel_offset(&line, 100.0, miter);
+        assert_eq!(right.len(), 3);
+        for (offset, point) in right.iter().zip([line[0], line[2], line[3]]) {
+            assert!((offset.0 - point.0).abs() < 1e-12);
+            assert!((proj.distance(*offset, point) - 100.0).abs() < 1e-6);
+            assert!(offset.1 > 0.0);
+        }
+
+        // right turn from North to East, where the right side is inner
+        let line = [(0.0, 0.0), (0.01, 0.0), (0.01, 0.01)];
+        let corner = proj.offset(line[1], 100.0, -100.0);
+        let right = proj.parallel_offset(&line, 100.0, LineJoin::Bevel);
+        assert_eq!(right.len(), 3);
+        assert!(proj.distance(right[1], corner) < 1e-6);
+
+        // and the left side is outer
+        let corner = proj.offset(line[1], -100.0, 100.0);
+        let left = proj.parallel_offset(&line, -100.0, miter);
+        assert_eq!(left.len(), 3);
+        assert!(proj.distance(left[1], corner) < 1e-6);
+        let left = proj.parallel_offset(&line, -100.0, LineJoin::Miter { limit: 1.4 });
+        assert_eq!(left.len(), 4);
+        let left = proj.parallel_offset(&line, -100.0, LineJoin::Bevel);
+        assert_eq!(left.len(), 4);
+        assert!(proj.distance(left[1], proj.offset(line[1], -100.0, 0.0)) < 1e-6);
+        assert!(proj.distance(left[2], proj.offset(line[1], 0.0, 100.0)) < 1e-6);
+
+        // U-turn is always beveled
+        let line = [(0.0, 0.0), (0.01, 0.0), (0.0, 0.0)];
+        assert_eq!(proj.parallel_offset(&line, 100.0, miter).len(), 4);
+
+        // across the antimeridian
+        let line = [(0.0, 179.99), (0.0, -179.99)];
+        let left = proj.parallel_offset(&line, -100.0, miter);
+        assert!(left[0].0 > 0.0 && left[1].0 > 0.0);
+        assert!((proj.polyline_length(&left) - proj.polyline_length(&line)).abs() < 1e-6);
+
+        let proj = PlaneProjection::new(55.65);
+        let line = [MALMO_C, LUND_C];
+        for point in proj.parallel_offset(&line, 500.0, miter) {
+            assert!((proj.cross_track_distance(point, (MALMO_C, LUND_C)) - 500.0).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,663 @@
+use crate::{Float, LatLon, PlaneProjection};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Pair of points in (lat, lon) format, such as the start and the end of a segment.
+type Pair<T> = ((T, T), (T, T));
+
+/// Number of points the batch distances kernel processes at once, an AVX register of `f64` values.
+/// Wider kernels measured slower, as the gather from (lat, lon) tuples stops fitting in registers.
+const LANES: usize = 4;
+
+impl PlaneProjection {
+    /// Finds the closest pair of points, returning their indices in ascending order and the distance in meters
+    /// between them. Returns `None` if there are less than two points.
+    pub fn closest_pair(&self, points: &[LatLon]) -> Option<(usize, usize, f64)> {
+        let &origin = points.first()?;
+        let mut projected = points
+            .iter()
+            .enumerate()
+            .map(|(i, &point)| (self.project_relative(origin, point), i))
+            .collect::<Vec<_>>();
+
+        // Sweep along the axis with the largest extent, so the sweep window stays small
+        let (min, max) = projected.iter().fold(
+            (
+                (f64::INFINITY, f64::INFINITY),
+                (f64::NEG_INFINITY, f64::NEG_INFINITY),
+            ),
+            |(min, max), &(p, _)| {
+                (
+                    (min.0.min(p.0), min.1.min(p.1)),
+                    (max.0.max(p.0), max.1.max(p.1)),
+                )
+            },
+        );
+        if max.1 - min.1 > max.0 - min.0 {
+            projected.iter_mut().for_each(|(p, _)| *p = (p.1, p.0));
+        }
+        projected.sort_by(|(a, _), (b, _)| a.0.total_cmp(&b.0));
+
+        let mut best: Option<(usize, usize, f64)> = None;
+        for (i, &(a, a_index)) in projected.iter().enumerate() {
+            for &(b, b_index) in projected[..i].iter().rev() {
+                let d0 = a.0 - b.0;
+                if best.is_some_and(|(_, _, best)| d0 * d0 >= best) {
+                    break;
+                }
+                let d1 = a.1 - b.1;
+                let square_distance = d0 * d0 + d1 * d1;
+                if best.is_none_or(|(_, _, best)| square_distance < best) {
+                    best = Some((a_index.min(b_index), a_index.max(b_index), square_distance));
+                }
+            }
+        }
+        best.map(|(a, b, square_distance)| (a, b, square_distance.sqrt()))
+    }
+
+    /// Distances in meters between every pair of `from` and `to` points in row-major order,
+    /// so the distance between `from[i]` and `to[j]` is at `i * to.len() + j` index.
+    pub fn distance_matrix(&self, from: &[LatLon], to: &[LatLon]) -> Vec<f64> {
+        let to = to
+            .iter()
+            .map(|&point| self.project(point))
+            .collect::<Vec<_>>();
+        let half_circle = 180.0 * self.lon_scale;
+        let mut matrix = vec![0.0; from.len() * to.len()];
+        if to.is_empty() {
+            return matrix;
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        let rows = matrix.chunks_mut(to.len()).zip(from.iter());
+        #[cfg(feature = "rayon")]
+        let rows = matrix.par_chunks_mut(to.len()).zip(from.par_iter());
+
+        rows.for_each(|(row, &a)| {
+            let a = self.project(a);
+            for (distance, b) in row.iter_mut().zip(&to) {
+                let lat_dist = a.0 - b.0;
+                // the same as `lon_diff`, but in the projected space
+                let mut lon_dist = a.1 - b.1;
+                if lon_dist > half_circle {
+                    lon_dist -= 2.0 * half_circle;
+                } else if lon_dist < -half_circle {
+                    lon_dist += 2.0 * half_circle;
+                }
+                *distance = (lat_dist * lat_dist + lon_dist * lon_dist).sqrt();
+            }
+        });
+        matrix
+    }
+
+    /// Finds up to `k` nearest to `query` candidates, returning their indices and distances in meters
+    /// sorted from the nearest to the farthest one. Equally distant candidates are ordered by index.
+    pub fn k_nearest(&self, candidates: &[LatLon], query: LatLon, k: usize) -> Vec<(usize, f64)> {
+        // Keep only `k` nearest candidates sorted by square distance
+        let mut nearest: Vec<(usize, f64)> = Vec::with_capacity(k.min(candidates.len()) + 1);
+        for (i, &candidate) in candidates.iter().enumerate() {
+            let square_distance = self.square_distance(query, candidate);
+            if nearest.len() == k
+                && nearest
+                    .last()
+                    .is_none_or(|&(_, max)| square_distance >= max)
+            {
+                continue;
+            }
+            let position = nearest.partition_point(|&(_, d)| d <= square_distance);
+            nearest.insert(position, (i, square_distance));
+            nearest.truncate(k);
+        }
+        nearest.iter_mut().for_each(|(_, d)| *d = d.sqrt());
+        nearest
+    }
+}
+
+impl<T: Float> PlaneProjection<T> {
+    /// Distances in meters from the `origin` to every point in `points`, written to `out`.
+    /// Points are processed 4 at a time with a branchless longitude wrap, so the compiler can keep every step in SIMD registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    pub fn distances(&self, origin: (T, T), points: &[(T, T)], out: &mut [T]) {
+        assert_eq!(points.len(), out.len(), "`points` and `out` lengths differ");
+        let mut chunks = out.chunks_exact_mut(LANES);
+        for (points, out) in points.chunks_exact(LANES).zip(&mut chunks) {
+            let lats = core::array::from_fn(|i| points[i].0);
+            let lons = core::array::from_fn(|i| points[i].1);
+            out.copy_from_slice(&self.distances_lanes(origin, lats, lons));
+        }
+        let points = &points[points.len() - points.len() % LANES..];
+        for (&(lat, lon), out) in points.iter().zip(chunks.into_remainder()) {
+            *out = self.branchless_distance(origin, lat, lon);
+        }
+    }
+
+    /// Distances in meters from the `origin` to every point given in separate `lats` and `lons` columns,
+    /// written to `out`, for data already stored in the columnar layout. Shares the kernel with
+    /// [`PlaneProjection::distances()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lats`, `lons` and `out` have different lengths.
+    pub fn distances_columnar(&self, origin: (T, T), lats: &[T], lons: &[T], out: &mut [T]) {
+        assert_eq!(lats.len(), lons.len(), "`lats` and `lons` lengths differ");
+        assert_eq!(lats.len(), out.len(), "`lats` and `out` lengths differ");
+        let (lat_chunks, lon_chunks) = (lats.chunks_exact(LANES), lons.chunks_exact(LANES));
+        let (lats, lons) = (lat_chunks.remainder(), lon_chunks.remainder());
+        let mut chunks = out.chunks_exact_mut(LANES);
+        for ((lats, lons), out) in lat_chunks.zip(lon_chunks).zip(&mut chunks) {
+            let lats = core::array::from_fn(|i| lats[i]);
+            let lons = core::array::from_fn(|i| lons[i]);
+            out.copy_from_slice(&self.distances_lanes(origin, lats, lons));
+        }
+        for ((&lat, &lon), out) in lats.iter().zip(lons).zip(chunks.into_remainder()) {
+            *out = self.branchless_distance(origin, lat, lon);
+        }
+    }
+
+    /// Distances in meters from every point in `points` to the `segment`, written to `out`,
+    /// see [`PlaneProjection::distance_to_segment()`]. The segment is projected once for all points,
+    /// for example to match a whole GPS trace against a candidate road edge.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj: PlaneProjection = PlaneProjection::new(55.65);
+    /// let segment = ((55.60, 13.00), (55.70, 13.00));
+    /// let trace = [(55.625, 13.01), (55.65, 13.00), (55.75, 13.00)];
+    /// let mut distances = [0.0; 3];
+    /// proj.distances_to_segment(&trace, segment, &mut distances);
+    /// assert_eq!(distances.map(|distance| distance.round()), [630.0, 0.0, 5567.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    pub fn distances_to_segment(&self, points: &[(T, T)], segment: Pair<T>, out: &mut [T]) {
+        assert_eq!(points.len(), out.len(), "`points` and `out` lengths differ");
+
+        let (start, end) = segment;
+        let segment = self.project_relative(start, end);
+        let square_length = segment.0 * segment.0 + segment.1 * segment.1;
+        // Zero-length segments project every point to the start
+        let inv_square_length = if square_length > T::ZERO {
+            T::ONE / square_length
+        } else {
+            T::ZERO
+        };
+
+        for (&(lat, lon), out) in points.iter().zip(out) {
+            let point = self.branchless_relative(start, lat, lon);
+            let t = (point.0 * segment.0 + point.1 * segment.1) * inv_square_length;
+            let t = if t < T::ZERO { T::ZERO } else { t };
+            let t = if t > T::ONE { T::ONE } else { t };
+            let (north, east) = (point.0 - segment.0 * t, point.1 - segment.1 * t);
+            *out = (north * north + east * east).sqrt();
+        }
+    }
+
+    /// Distances from the `origin` to [`LANES`] points at once, the kernel of [`PlaneProjection::distances()`]
+    /// and [`PlaneProjection::distances_columnar()`]. Every step runs over all lanes before the next one starts,
+    /// so the compiler turns each of them into vector instructions.
+    #[inline(always)]
+    fn distances_lanes(&self, origin: (T, T), lats: [T; LANES], lons: [T; LANES]) -> [T; LANES] {
+        let (half_circle, circle) = (T::from_f64(180.0), T::from_f64(360.0));
+        let north: [T; LANES] = core::array::from_fn(|i| (lats[i] - origin.0) * self.lat_scale);
+        let lon_dists: [T; LANES] = core::array::from_fn(|i| lons[i] - origin.1);
+        let east: [T; LANES] = core::array::from_fn(|i| {
+            let lon_dist = lon_dists[i];
+            let lon_wrap = if lon_dist > half_circle {
+                -circle
+            } else {
+                T::ZERO
+            } + if lon_dist < -half_circle {
+                circle
+            } else {
+                T::ZERO
+            };
+            (lon_dist + lon_wrap) * self.lon_scale
+        });
+        core::array::from_fn(|i| (north[i] * north[i] + east[i] * east[i]).sqrt())
+    }
+
+    /// The same as [`PlaneProjection::distance()`], but with branchless longitude wrap for batch calculations.
+    #[inline(always)]
+    fn branchless_distance(&self, origin: (T, T), lat: T, lon: T) -> T {
+        let (lat, lon) = self.branchless_relative(origin, lat, lon);
+        (lat * lat + lon * lon).sqrt()
+    }
+
+    /// The same as [`PlaneProjection::project_relative()`], but with branchless longitude wrap.
+    #[inline(always)]
+    fn branchless_relative(&self, origin: (T, T), lat: T, lon: T) -> (T, T) {
+        let lon_dist = lon - origin.1;
+        let lon_wrap = if lon_dist > T::from_f64(180.0) {
+            T::from_f64(-360.0)
+        } else {
+            T::ZERO
+        } + if lon_dist < T::from_f64(-180.0) {
+            T::from_f64(360.0)
+        } else {
+            T::ZERO
+        };
+        (
+            (lat - origin.0) * self.lat_scale,
+            (lon_dist + lon_wrap) * self.lon_scale,
+        )
+    }
+
+    /// Headings in degrees from the first to the second point of every pair, written to `out`,
+    /// see [`PlaneProjection::heading()`], for example to rotate thousands of map labels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pairs` and `out` have different lengths.
+    pub fn headings(&self, pairs: &[Pair<T>], out: &mut [f32]) {
+        assert_eq!(pairs.len(), out.len(), "`pairs` and `out` lengths differ");
+        for (&(a, b), out) in pairs.iter().zip(out) {
+            *out = self.heading(a, b);
+        }
+    }
+
+    /// Course in degrees of every segment of the polyline, written to `out`, so `out[i]` is the heading
+    /// from `line[i]` to `line[i + 1]`, see [`PlaneProjection::headings()`].
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = [(55.60, 13.00), (55.61, 13.00), (55.61, 13.01)];
+    /// let mut courses = [0.0; 2];
+    /// proj.segment_headings(&line, &mut courses);
+    /// assert_eq!(courses, [0.0, proj.heading(line[1], line[2])]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` length is not the number of the polyline segments, i.e. `line.len() - 1` or 0 for empty lines.
+    pub fn segment_headings(&self, line: &[(T, T)], out: &mut [f32]) {
+        assert_eq!(
+            line.len().saturating_sub(1),
+            out.len(),
+            "`out` length differs from the number of segments"
+        );
+        for (segment, out) in line.windows(2).zip(out) {
+            *out = self.heading(segment[0], segment[1]);
+        }
+    }
+
+    /// Projects all `points` to the plane projection space, see [`PlaneProjection::project()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    pub fn project_slice(&self, points: &[(T, T)], out: &mut [(T, T)]) {
+        assert_eq!(points.len(), out.len(), "`points` and `out` lengths differ");
+        for (&point, out) in points.iter().zip(out) {
+            *out = self.project(point).into();
+        }
+    }
+
+    /// Converts all `points` from the plane projection space back to (latitude, longitude),
+    /// see [`PlaneProjection::unproject()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    pub fn unproject_slice(&self, points: &[(T, T)], out: &mut [(T, T)]) {
+        assert_eq!(points.len(), out.len(), "`points` and `out` lengths differ");
+        for (&point, out) in points.iter().zip(out) {
+            *out = self.unproject(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BBox;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C, assert_batch, pseudo_random_points};
+
+    const AREA: BBox = BBox {
+        min: (55.0, 13.0),
+        max: (56.0, 14.0),
+    };
+    const ACROSS_ANTIMERIDIAN: BBox = BBox {
+        min: (-1.0, 179.0),
+        max: (1.0, 181.0),
+    };
+
+    #[test]
+    fn closest_pair_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.closest_pair(&[]), None);
+        assert_eq!(proj.closest_pair(&[MALMO_C]), None);
+        assert_eq!(
+            proj.closest_pair(&[LUND_C, MALMO_C]),
+            Some((0, 1, proj.distance(MALMO_C, LUND_C)))
+        );
+        let (i, j, distance) = proj.closest_pair(&[STOCKHOLM_C, LUND_C, MALMO_C]).unwrap();
+        assert_eq!((i, j), (1, 2));
+        assert!((distance - proj.distance(MALMO_C, LUND_C)).abs() < 1e-6);
+        assert_eq!(
+            proj.closest_pair(&[MALMO_C, LUND_C, MALMO_C]),
+            Some((0, 2, 0.0))
+        );
+
+        // compare with brute force on pseudo-random points
+        for (lat_range, lon_range) in [(1.0, 0.01), (0.01, 1.0), (0.5, 0.5)] {
+            let area = BBox {
+                min: (55.0, 13.0),
+                max: (55.0 + lat_range, 13.0 + lon_range),
+            };
+            let points = pseudo_random_points(500, area);
+            let mut expected = (0, 0, f64::INFINITY);
+            for i in 0..points.len() {
+                for j in i + 1..points.len() {
+                    let distance = proj.distance(points[i], points[j]);
+                    if distance < expected.2 {
+                        expected = (i, j, distance);
+                    }
+                }
+            }
+            let (i, j, distance) = proj.closest_pair(&points).unwrap();
+            assert!((distance - expected.2).abs() < 1e-6);
+            assert!((proj.distance(points[i], points[j]) - expected.2).abs() < 1e-6);
+        }
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let points = [(0.0, 179.999), (0.0, 170.0), (0.0, -179.999)];
+        assert_eq!(
+            proj.closest_pair(&points).map(|(i, j, _)| (i, j)),
+            Some((0, 2))
+        );
+    }
+
+    #[test]
+    fn distance_matrix_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.distance_matrix(&[], &[MALMO_C]).is_empty());
+        assert!(proj.distance_matrix(&[MALMO_C], &[]).is_empty());
+
+        let from = [MALMO_C, LUND_C];
+        let to = [LUND_C, STOCKHOLM_C, MALMO_C];
+        let matrix = proj.distance_matrix(&from, &to);
+        assert_eq!(matrix.len(), 6);
+        for (i, &a) in from.iter().enumerate() {
+            for (j, &b) in to.iter().enumerate() {
+                assert!((matrix[i * to.len() + j] - proj.distance(a, b)).abs() < 1e-6);
+            }
+        }
+        assert_eq!(matrix[2], 0.0);
+        assert_eq!(matrix[3], 0.0);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let matrix = proj.distance_matrix(
+            &[(0.0, 179.5), (0.0, -179.5)],
+            &[(0.0, -179.5), (0.0, 179.5)],
+        );
+        let expected = proj.distance((0.0, 179.5), (0.0, -179.5));
+        for distance in [matrix[0], matrix[3]] {
+            assert!((distance - expected).abs() < 1e-6);
+        }
+        assert_eq!((matrix[1], matrix[2]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn k_nearest_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.k_nearest(&[], MALMO_C, 3).is_empty());
+        assert!(proj.k_nearest(&[MALMO_C, LUND_C], MALMO_C, 0).is_empty());
+
+        let candidates = [STOCKHOLM_C, LUND_C, MALMO_C, LUND_C];
+        assert_eq!(proj.k_nearest(&candidates, MALMO_C, 1), [(2, 0.0)]);
+        let distance = proj.distance(MALMO_C, LUND_C);
+        assert_eq!(
+            proj.k_nearest(&candidates, MALMO_C, 3),
+            [(2, 0.0), (1, distance), (3, distance)]
+        );
+        let nearest = proj.k_nearest(&candidates, MALMO_C, 10);
+        assert_eq!(nearest.len(), 4);
+        assert_eq!(nearest[3], (0, proj.distance(MALMO_C, STOCKHOLM_C)));
+
+        // compare with full sort on pseudo-random points
+        let candidates = pseudo_random_points(1000, AREA);
+        let mut expected = candidates
+            .iter()
+            .map(|&candidate| proj.distance(MALMO_C, candidate))
+            .enumerate()
+            .collect::<Vec<_>>();
+        expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+        expected.truncate(10);
+        assert_eq!(proj.k_nearest(&candidates, MALMO_C, 10), expected);
+    }
+
+    #[test]
+    fn distances_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.distances(MALMO_C, &[], &mut []);
+
+        // full lanes and the remainder
+        assert_batch(
+            &pseudo_random_points(19, AREA),
+            |points, out| proj.distances(MALMO_C, points, out),
+            |point| proj.distance(MALMO_C, point),
+            0.0,
+        );
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let points = [
+            (0.0, -179.5),
+            (0.0, 179.5),
+            (0.0, 0.0),
+            (1.0, -179.0),
+            (0.0, -179.9),
+        ];
+        let mut out = [0.0; 5];
+        proj.distances((0.0, 179.9), &points, &mut out);
+        for (&point, &distance) in points.iter().zip(&out) {
+            assert_eq!(distance, proj.distance((0.0, 179.9), point));
+        }
+        let points = pseudo_random_points(19, ACROSS_ANTIMERIDIAN);
+        for origin in [(0.0, 179.9), (0.0, -179.9)] {
+            assert_batch(
+                &points,
+                |points, out| proj.distances(origin, points, out),
+                |point| proj.distance(origin, point),
+                0.0,
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`points` and `out` lengths differ")]
+    fn distances_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.distances(MALMO_C, &[MALMO_C, LUND_C], &mut [0.0]);
+    }
+
+    #[test]
+    fn distances_columnar_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.distances_columnar(MALMO_C, &[], &[], &mut []);
+
+        let points = [MALMO_C, LUND_C, STOCKHOLM_C, (55.0, 13.0), (56.0, 14.0)];
+        let lats = points.map(|point| point.0);
+        let lons = points.map(|point| point.1);
+        let mut out = [0.0; 5];
+        proj.distances_columnar(LUND_C, &lats, &lons, &mut out);
+        for (&point, &distance) in points.iter().zip(&out) {
+            assert_eq!(distance, proj.distance(LUND_C, point));
+        }
+
+        // full lanes and the remainder
+        for (origin, area) in [(LUND_C, AREA), ((0.0, -179.9), ACROSS_ANTIMERIDIAN)] {
+            let proj = PlaneProjection::new(origin.0);
+            assert_batch(
+                &pseudo_random_points(19, area),
+                |points, out| {
+                    let lats = points.iter().map(|point| point.0).collect::<Vec<_>>();
+                    let lons = points.iter().map(|point| point.1).collect::<Vec<_>>();
+                    proj.distances_columnar(origin, &lats, &lons, out);
+                },
+                |point| proj.distance(origin, point),
+                0.0,
+            );
+        }
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let mut out = [0.0; 3];
+        proj.distances_columnar(
+            (0.0, -179.9),
+            &[0.0, 1.0, 0.0],
+            &[179.5, -179.0, 0.0],
+            &mut out,
+        );
+        assert_eq!(out[0], proj.distance((0.0, -179.9), (0.0, 179.5)));
+        assert_eq!(out[1], proj.distance((0.0, -179.9), (1.0, -179.0)));
+        assert_eq!(out[2], proj.distance((0.0, -179.9), (0.0, 0.0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "`lats` and `lons` lengths differ")]
+    fn distances_columnar_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.distances_columnar(MALMO_C, &[55.0, 56.0], &[13.0], &mut [0.0; 2]);
+    }
+
+    #[test]
+    fn distances_to_segment_test() {
+        let proj = PlaneProjection::new(55.65);
+        let segment = (MALMO_C, LUND_C);
+        proj.distances_to_segment(&[], segment, &mut []);
+
+        // points before, along and after the segment
+        let area = BBox {
+            min: (55.5, 12.9),
+            max: (55.5 + 1.0 / 3.0, 13.4),
+        };
+        let mut points = pseudo_random_points(11, area);
+        points.extend([MALMO_C, LUND_C, proj.midpoint(MALMO_C, LUND_C)]);
+        assert_batch(
+            &points,
+            |points, out| proj.distances_to_segment(points, segment, out),
+            |point| proj.distance_to_segment(point, segment),
+            1e-6,
+        );
+
+        // zero-length segment
+        let mut out = [0.0; 4];
+        proj.distances_to_segment(&[LUND_C; 4], (MALMO_C, MALMO_C), &mut out);
+        assert_eq!(out, [proj.distance(MALMO_C, LUND_C); 4]);
+
+        // across the antimeridian
+        let proj: PlaneProjection = PlaneProjection::new(0.0);
+        let points = [(0.0, 179.5), (1.0, -179.5), (0.0, 0.0), (-1.0, 179.9)];
+        let segment = ((0.0, 179.9), (0.0, -179.9));
+        let mut out = [0.0; 4];
+        proj.distances_to_segment(&points, segment, &mut out);
+        for (&point, &distance) in points.iter().zip(&out) {
+            let expected = proj.distance_to_segment(point, segment);
+            assert!(
+                (distance - expected).abs() < 1e-6,
+                "{distance} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`points` and `out` lengths differ")]
+    fn distances_to_segment_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.distances_to_segment(&[MALMO_C], (MALMO_C, LUND_C), &mut []);
+    }
+
+    #[test]
+    fn headings_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.headings(&[], &mut []);
+
+        // the first pair has zero length
+        let pairs = pseudo_random_points(11, AREA)
+            .into_iter()
+            .enumerate()
+            .map(|(i, point)| (point, if i == 0 { point } else { MALMO_C }))
+            .collect::<Vec<_>>();
+        assert_batch(
+            &pairs,
+            |pairs, out| proj.headings(pairs, out),
+            |(a, b)| proj.heading(a, b),
+            0.0,
+        );
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let mut out = [0.0; 2];
+        proj.headings(
+            &[((0.0, 179.5), (0.0, -179.5)), ((0.0, -179.5), (0.0, 179.5))],
+            &mut out,
+        );
+        assert_eq!(out, [90.0, 270.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`pairs` and `out` lengths differ")]
+    fn headings_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.headings(&[(MALMO_C, LUND_C)], &mut [0.0; 2]);
+    }
+
+    #[test]
+    fn segment_headings_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.segment_headings(&[], &mut []);
+        proj.segment_headings(&[MALMO_C], &mut []);
+
+        let line = pseudo_random_points(11, AREA);
+        let mut out = vec![f32::NAN; line.len() - 1];
+        proj.segment_headings(&line, &mut out);
+        for (segment, &heading) in line.windows(2).zip(&out) {
+            assert_eq!(heading, proj.heading(segment[0], segment[1]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`out` length differs from the number of segments")]
+    fn segment_headings_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.segment_headings(&[MALMO_C, LUND_C, STOCKHOLM_C], &mut [0.0; 3]);
+    }
+
+    #[test]
+    fn project_slice_test() {
+        let proj = PlaneProjection::new(55.65);
+        let points = [MALMO_C, LUND_C, STOCKHOLM_C];
+        let mut projected = [(0.0, 0.0); 3];
+        proj.project_slice(&points, &mut projected);
+        for (&point, &projected) in points.iter().zip(&projected) {
+            assert_eq!(projected, proj.project(point).into());
+        }
+
+        let mut unprojected = [(0.0, 0.0); 3];
+        proj.unproject_slice(&projected, &mut unprojected);
+        for (&projected, &unprojected) in projected.iter().zip(&unprojected) {
+            assert_eq!(unprojected, proj.unproject(projected));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`points` and `out` lengths differ")]
+    fn project_slice_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.project_slice(&[MALMO_C], &mut [(0.0, 0.0); 2]);
+    }
+}
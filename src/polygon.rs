@@ -0,0 +1,796 @@
+use crate::predicates::{orient2d, within_box};
+use crate::{DegenerateGeometry, DegeneratePolicy, LatLon, PlaneProjection, dot};
+use alloc::vec::Vec;
+
+/// Winding order of a polygon ring, see [`PlaneProjection::ensure_winding()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    /// Used by shapefiles for the outer rings and by GeoJSON for the holes.
+    Clockwise,
+    /// Used by GeoJSON for the outer rings and by shapefiles for the holes.
+    Counterclockwise,
+}
+
+impl PlaneProjection {
+    /// Area in square meters of the polygon ring, which can be either closed or not.
+    pub fn area(&self, ring: &[LatLon]) -> f64 {
+        self.signed_area(ring).abs()
+    }
+
+    /// Signed area in square meters of the polygon ring, which can be either closed or not.
+    /// It is positive for counterclockwise rings and negative for clockwise ones, see [`Orientation`].
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let ring = [(55.60, 13.00), (55.60, 13.01), (55.61, 13.01), (55.61, 13.00)];
+    /// assert!(proj.signed_area(&ring) > 0.0);
+    /// assert_eq!(proj.signed_area(&ring), -proj.signed_area(&[ring[0], ring[3], ring[2], ring[1]]));
+    /// ```
+    pub fn signed_area(&self, ring: &[LatLon]) -> f64 {
+        let Some(&origin) = ring.first() else {
+            return 0.0;
+        };
+        // Shoelace formula in the local Cartesian coordinates, the first vertex is at (0.0, 0.0)
+        let mut prev = self.project_relative(origin, ring[ring.len() - 1]);
+        let mut doubled_area = 0.0;
+        for &point in ring {
+            let point = self.project_relative(origin, point);
+            doubled_area += point.0 * prev.1 - prev.0 * point.1;
+            prev = point;
+        }
+        doubled_area * 0.5
+    }
+
+    /// Checks if the polygon ring goes clockwise, i.e. its [`PlaneProjection::signed_area()`] is negative.
+    /// Degenerate rings with zero area are neither clockwise nor counterclockwise.
+    #[inline(always)]
+    pub fn is_clockwise(&self, ring: &[LatLon]) -> bool {
+        self.signed_area(ring) < 0.0
+    }
+
+    /// Reverses the polygon ring in place if it doesn't go in the `orientation` order, to normalize rings
+    /// from mixed sources. Closed rings stay closed and degenerate rings with zero area are left as is.
+    ///
+    /// ```
+    /// use plane_projection::{Orientation, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let mut ring = [(55.60, 13.00), (55.61, 13.00), (55.61, 13.01), (55.60, 13.01)];
+    /// assert!(proj.is_clockwise(&ring));
+    /// proj.ensure_winding(&mut ring, Orientation::Counterclockwise);
+    /// assert!(!proj.is_clockwise(&ring));
+    /// ```
+    pub fn ensure_winding(&self, ring: &mut [LatLon], orientation: Orientation) {
+        let signed_area = self.signed_area(ring);
+        let reverse = match orientation {
+            Orientation::Clockwise => signed_area > 0.0,
+            Orientation::Counterclockwise => signed_area < 0.0,
+        };
+        if reverse {
+            ring.reverse();
+        }
+    }
+
+    /// Area in square meters of the polygon with the outer ring `exterior` and inner rings `holes`.
+    pub fn polygon_area(&self, exterior: &[LatLon], holes: &[impl AsRef<[LatLon]>]) -> f64 {
+        holes.iter().fold(self.area(exterior), |area, hole| {
+            area - self.area(hole.as_ref())
+        })
+    }
+
+    /// Checks if the point is inside the polygon ring, which can be either closed or not.
+    /// The side of every edge is decided exactly for the projected points, so the results near the boundary
+    /// don't depend on rounding errors. Points exactly on the boundary may be treated either as inside or outside,
+    /// use [`PlaneProjection::on_boundary()`] to tell them apart.
+    pub fn contains(&self, ring: &[LatLon], point: LatLon) -> bool {
+        let Some(&origin) = ring.first() else {
+            return false;
+        };
+        // Ray casting to the East in the local Cartesian coordinates with the first vertex as origin
+        let point = self.project_relative(origin, point);
+        let mut prev = self.project_relative(origin, ring[ring.len() - 1]);
+        let mut inside = false;
+        for &vertex in ring {
+            let vertex = self.project_relative(origin, vertex);
+            let northward = vertex.0 > point.0;
+            if northward != (prev.0 > point.0) {
+                // The ray crosses the edge if the point is to the West of it, i.e. to the left of the edges going
+                // North and to the right of the edges going South
+                let side = orient2d(prev, vertex, point);
+                if (northward && side < 0.0) || (!northward && side > 0.0) {
+                    inside = !inside;
+                }
+            }
+            prev = vertex;
+        }
+        inside
+    }
+
+    /// Checks if the point lies exactly on the boundary of the polygon ring, which can be either closed or not,
+    /// as decided for the projected points. Together with [`PlaneProjection::contains()`] this allows
+    /// to treat the boundary as either part of the polygon or not.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let ring = [(55.60, 13.00), (55.60, 13.02), (55.62, 13.02), (55.62, 13.00)];
+    /// assert!(proj.on_boundary(&ring, (55.60, 13.01)));
+    /// assert!(proj.on_boundary(&ring, ring[2]));
+    /// assert!(!proj.on_boundary(&ring, (55.61, 13.01)));
+    /// ```
+    pub fn on_boundary(&self, ring: &[LatLon], point: LatLon) -> bool {
+        let Some(&origin) = ring.first() else {
+            return false;
+        };
+        let point = self.project_relative(origin, point);
+        let mut prev = self.project_relative(origin, ring[ring.len() - 1]);
+        ring.iter().any(|&vertex| {
+            let vertex = self.project_relative(origin, vertex);
+            let edge = (prev, vertex);
+            prev = vertex;
+            orient2d(edge.0, edge.1, point) == 0.0 && within_box(point, edge)
+        })
+    }
+
+    /// Orientation of the triangle `a`, `b`, `c`, or `None` if the points are collinear, including repeated points.
+    /// The result is exact for the projected points and consistent for any order of the arguments,
+    /// which makes it suitable for geometric algorithms that break on nearly-collinear inputs.
+    ///
+    /// ```
+    /// use plane_projection::{Orientation, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let (a, b) = ((55.60, 13.00), (55.60, 13.02));
+    /// assert_eq!(proj.orientation(a, b, (55.61, 13.01)), Some(Orientation::Counterclockwise));
+    /// assert_eq!(proj.orientation(a, b, (55.59, 13.01)), Some(Orientation::Clockwise));
+    /// assert_eq!(proj.orientation(a, b, (55.60, 13.01)), None);
+    /// ```
+    pub fn orientation(&self, a: LatLon, b: LatLon, c: LatLon) -> Option<Orientation> {
+        // The same origin for any order of the arguments, so the projected points are the same
+        let origin = [b, c]
+            .into_iter()
+            .fold(a, |min, point| if point < min { point } else { min });
+        let [a, b, c] = [a, b, c].map(|point| self.project_relative(origin, point));
+        let side = orient2d(a, b, c);
+        if side > 0.0 {
+            Some(Orientation::Clockwise)
+        } else if side < 0.0 {
+            Some(Orientation::Counterclockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Area-weighted centroid of the polygon ring, which can be either closed or not.
+    /// For degenerate rings with zero area the average of vertices is returned instead.
+    /// Returns `None` if the ring is empty.
+    pub fn centroid(&self, ring: &[LatLon]) -> Option<LatLon> {
+        let &origin = ring.first()?;
+        let mut prev = self.project_relative(origin, ring[ring.len() - 1]);
+        let (mut doubled_area, mut north, mut east) = (0.0, 0.0, 0.0);
+        for &point in ring {
+            let point = self.project_relative(origin, point);
+            let cross = prev.0 * point.1 - point.0 * prev.1;
+            doubled_area += cross;
+            north += (prev.0 + point.0) * cross;
+            east += (prev.1 + point.1) * cross;
+            prev = point;
+        }
+        if doubled_area == 0.0 {
+            let vertices = match ring {
+                [first, rest @ .., last] if first == last => &ring[..rest.len() + 1],
+                _ => ring,
+            };
+            return self.weighted_centroid(vertices.iter().map(|&point| (point, 1.0)));
+        }
+        let scale = 1.0 / (3.0 * doubled_area);
+        Some(self.unproject_relative(origin, (north * scale, east * scale)))
+    }
+
+    /// Weighted centroid of the (point, weight) pairs in the projected space.
+    /// Returns `None` if there are no points or the total weight is zero.
+    pub fn weighted_centroid(
+        &self,
+        points: impl IntoIterator<Item = (LatLon, f64)>,
+    ) -> Option<LatLon> {
+        let mut points = points.into_iter().peekable();
+        let &(origin, _) = points.peek()?;
+        let (mut total_weight, mut north, mut east) = (0.0, 0.0, 0.0);
+        for (point, weight) in points {
+            let point = self.project_relative(origin, point);
+            total_weight += weight;
+            north += point.0 * weight;
+            east += point.1 * weight;
+        }
+        if total_weight == 0.0 {
+            return None;
+        }
+        Some(self.unproject_relative(origin, (north / total_weight, east / total_weight)))
+    }
+
+    /// Convex hull of the points in counterclockwise order, without repeating the first point.
+    /// Points that lie on the hull edges are not included.
+    pub fn convex_hull(&self, points: &[LatLon]) -> Vec<LatLon> {
+        let Some(&origin) = points.first() else {
+            return Vec::new();
+        };
+        let mut projected = points
+            .iter()
+            .map(|&point| (self.project_relative(origin, point), point))
+            .collect::<Vec<_>>();
+        // Andrew's monotone chain, sorted from West to East
+        projected.sort_by(|(a, _), (b, _)| a.1.total_cmp(&b.1).then(a.0.total_cmp(&b.0)));
+        projected.dedup_by(|(a, _), (b, _)| a == b);
+        if projected.len() < 3 {
+            return projected.into_iter().map(|(_, point)| point).collect();
+        }
+
+        // Both lower and upper hulls keep only counterclockwise turns
+        let turns_left = |a: (f64, f64), b: (f64, f64), c: (f64, f64)| orient2d(a, b, c) < 0.0;
+        let mut hull: Vec<((f64, f64), LatLon)> = Vec::with_capacity(projected.len() + 1);
+        for &(p, point) in &projected {
+            while hull.len() >= 2 && !turns_left(hull[hull.len() - 2].0, hull[hull.len() - 1].0, p)
+            {
+                hull.pop();
+            }
+            hull.push((p, point));
+        }
+        let lower_len = hull.len() + 1;
+        for &(p, point) in projected.iter().rev().skip(1) {
+            while hull.len() >= lower_len
+                && !turns_left(hull[hull.len() - 2].0, hull[hull.len() - 1].0, p)
+            {
+                hull.pop();
+            }
+            hull.push((p, point));
+        }
+        // The last point is the same as the first one
+        hull.pop();
+        hull.into_iter().map(|(_, point)| point).collect()
+    }
+
+    /// Checks if the polygon ring, which can be either closed or not, doesn't intersect or touch itself,
+    /// for example to validate user-drawn geofences. See [`PlaneProjection::self_intersection()`].
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// assert!(proj.is_simple(&[(55.6, 13.0), (55.6, 13.1), (55.7, 13.1), (55.7, 13.0)]));
+    /// // bowtie
+    /// assert!(!proj.is_simple(&[(55.6, 13.0), (55.6, 13.1), (55.7, 13.0), (55.7, 13.1)]));
+    /// ```
+    pub fn is_simple(&self, ring: &[LatLon]) -> bool {
+        self.self_intersection(ring).is_none()
+    }
+
+    /// Finds a pair of polygon ring segments that intersect or touch each other, except for the shared vertices
+    /// of the neighbouring ones, or a spike where the ring goes back over itself. Each segment is identified by
+    /// the index `i` of its start, so it is `(ring[i], ring[i + 1])`, or the closing one from the last point
+    /// to the first one if the ring is not closed. Repeated consecutive points are ignored.
+    ///
+    /// Edges are swept from South to North, so only the ones overlapping in latitude are compared,
+    /// making it close to O(n log n) for typical rings.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let bowtie = [(55.6, 13.0), (55.6, 13.1), (55.7, 13.0), (55.7, 13.1), (55.6, 13.0)];
+    /// assert_eq!(proj.self_intersection(&bowtie), Some((1, 3)));
+    /// ```
+    pub fn self_intersection(&self, ring: &[LatLon]) -> Option<(usize, usize)> {
+        let &origin = ring.first()?;
+        // Projected vertices with the index of the last point in each run of repeated points
+        let mut vertices: Vec<(usize, (f64, f64))> = Vec::with_capacity(ring.len());
+        for (i, &point) in ring.iter().enumerate() {
+            let point = self.project_relative(origin, point);
+            match vertices.last_mut() {
+                Some(last) if last.1 == point => last.0 = i,
+                _ => vertices.push((i, point)),
+            }
+        }
+        if vertices.len() > 1 && vertices[0].1 == vertices[vertices.len() - 1].1 {
+            vertices.pop();
+        }
+        let n = vertices.len();
+        if n < 2 {
+            return None;
+        }
+
+        let edge = |k: usize| (vertices[k].1, vertices[(k + 1) % n].1);
+        let south = |k: usize| {
+            let (a, b) = edge(k);
+            a.0.min(b.0)
+        };
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| south(a).total_cmp(&south(b)));
+        for (i, &a) in order.iter().enumerate() {
+            let (a0, a1) = edge(a);
+            let north = a0.0.max(a1.0);
+            for &b in order[i + 1..].iter().take_while(|&&b| south(b) <= north) {
+                let (b0, b1) = edge(b);
+                let intersects = if (a + 1) % n == b {
+                    folds(a0, a1, b1)
+                } else if (b + 1) % n == a {
+                    folds(b0, b1, a1)
+                } else {
+                    segments_touch((a0, a1), (b0, b1))
+                };
+                if intersects {
+                    let (a, b) = (vertices[a].0, vertices[b].0);
+                    return Some((a.min(b), a.max(b)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Same as [`PlaneProjection::self_intersection()`], but repeated consecutive points are handled according
+    /// to the `policy`, so [`DegeneratePolicy::Reject`] reports the index of the first point that repeats
+    /// the previous one. The last point of a closed ring repeating the first one is not degenerate.
+    ///
+    /// ```
+    /// use plane_projection::{DegenerateGeometry, DegeneratePolicy, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let ring = [(55.6, 13.0), (55.6, 13.1), (55.6, 13.1), (55.7, 13.1), (55.6, 13.0)];
+    /// assert_eq!(proj.self_intersection_with(&ring, DegeneratePolicy::Ignore), Ok(None));
+    /// assert_eq!(
+    ///     proj.self_intersection_with(&ring, DegeneratePolicy::Reject),
+    ///     Err(DegenerateGeometry { index: 2 })
+    /// );
+    /// ```
+    pub fn self_intersection_with(
+        &self,
+        ring: &[LatLon],
+        policy: DegeneratePolicy,
+    ) -> Result<Option<(usize, usize)>, DegenerateGeometry> {
+        if policy == DegeneratePolicy::Reject {
+            let repeated = ring
+                .windows(2)
+                .position(|pair| self.project_relative(pair[0], pair[1]) == (0.0, 0.0));
+            if let Some(i) = repeated {
+                return Err(DegenerateGeometry { index: i + 1 });
+            }
+        }
+        // Repeated vertices are merged for both other policies, as zero-length edges lie on their neighbours
+        Ok(self.self_intersection(ring))
+    }
+}
+
+/// Checks if the path `a -> b -> c` in the projection space turns back over itself.
+fn folds(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> bool {
+    let (ab, bc) = ((b.0 - a.0, b.1 - a.1), (c.0 - b.0, c.1 - b.1));
+    orient2d(a, b, c) == 0.0 && dot(ab, bc) < 0.0
+}
+
+/// Checks if two segments in the projection space have at least one common point.
+fn segments_touch(a: ((f64, f64), (f64, f64)), b: ((f64, f64), (f64, f64))) -> bool {
+    let (d1, d2) = (orient2d(b.0, b.1, a.0), orient2d(b.0, b.1, a.1));
+    let (d3, d4) = (orient2d(a.0, a.1, b.0), orient2d(a.0, a.1, b.1));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+    (d1 == 0.0 && within_box(a.0, b))
+        || (d2 == 0.0 && within_box(a.1, b))
+        || (d3 == 0.0 && within_box(b.0, a))
+        || (d4 == 0.0 && within_box(b.1, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    #[test]
+    fn area_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.area(&[]), 0.0);
+        assert_eq!(proj.area(&[(55.65, 13.0)]), 0.0);
+        assert_eq!(proj.area(&[(55.65, 13.0), (55.66, 13.0)]), 0.0);
+
+        // 1km x 1km square, both in clockwise and counterclockwise order
+        let square = [
+            (55.65, 13.0),
+            proj.offset((55.65, 13.0), 1000.0, 0.0),
+            proj.offset((55.65, 13.0), 1000.0, 1000.0),
+            proj.offset((55.65, 13.0), 0.0, 1000.0),
+        ];
+        assert_eq!(proj.area(&square).round(), 1_000_000.0);
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(proj.area(&reversed).round(), 1_000_000.0);
+
+        // closed ring gives the same area
+        let closed = [square[0], square[1], square[2], square[3], square[0]];
+        assert_eq!(proj.area(&closed).round(), 1_000_000.0);
+
+        // triangle across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let triangle = [(0.0, 179.99), (0.0, -179.99), (0.01, 179.99)];
+        let expected =
+            proj.distance(triangle[0], triangle[1]) * proj.distance(triangle[0], triangle[2]) * 0.5;
+        assert!((proj.area(&triangle) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn signed_area_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.signed_area(&[]), 0.0);
+        assert_eq!(proj.signed_area(&[LUND_C, MALMO_C]), 0.0);
+
+        // East, then North, so counterclockwise
+        let square = [(0.0, 0.0), (1000.0, 0.0), (1000.0, 1000.0), (0.0, 1000.0)]
+            .map(|(east, north)| proj.offset(LUND_C, east, north));
+        assert_eq!(proj.signed_area(&square).round(), 1_000_000.0);
+        assert!(!proj.is_clockwise(&square));
+        let mut reversed = square;
+        reversed.reverse();
+        assert_eq!(proj.signed_area(&reversed).round(), -1_000_000.0);
+        assert!(proj.is_clockwise(&reversed));
+        assert!(!proj.is_clockwise(&[LUND_C, MALMO_C]));
+
+        // generated shapes go counterclockwise
+        assert!(proj.signed_area(&proj.circle(LUND_C, 100.0, 16)) > 0.0);
+        assert!(proj.signed_area(&proj.sector(LUND_C, 100.0, 10.0, 20.0, 4)) > 0.0);
+    }
+
+    #[test]
+    fn ensure_winding_test() {
+        let proj = PlaneProjection::new(55.65);
+        let square = [
+            (0.0, 0.0),
+            (1000.0, 0.0),
+            (1000.0, 1000.0),
+            (0.0, 1000.0),
+            (0.0, 0.0),
+        ]
+        .map(|(east, north)| proj.offset(LUND_C, east, north));
+
+        let mut ring = square;
+        proj.ensure_winding(&mut ring, Orientation::Counterclockwise);
+        assert_eq!(ring, square);
+        proj.ensure_winding(&mut ring, Orientation::Clockwise);
+        assert!(proj.is_clockwise(&ring));
+        // still closed
+        assert_eq!(ring[0], ring[4]);
+        assert_eq!(ring[1], square[3]);
+        proj.ensure_winding(&mut ring, Orientation::Clockwise);
+        assert!(proj.is_clockwise(&ring));
+        proj.ensure_winding(&mut ring, Orientation::Counterclockwise);
+        assert_eq!(ring, square);
+
+        let mut line = [LUND_C, MALMO_C];
+        proj.ensure_winding(&mut line, Orientation::Clockwise);
+        assert_eq!(line, [LUND_C, MALMO_C]);
+        proj.ensure_winding(&mut [], Orientation::Clockwise);
+    }
+
+    #[test]
+    fn contains_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(!proj.contains(&[], (55.65, 13.0)));
+        assert!(!proj.contains(&[(55.65, 13.0)], (55.65, 13.0)));
+
+        // L-shaped polygon
+        let ring = [
+            (55.60, 13.00),
+            (55.60, 13.20),
+            (55.65, 13.20),
+            (55.65, 13.05),
+            (55.70, 13.05),
+            (55.70, 13.00),
+        ];
+        assert!(proj.contains(&ring, (55.62, 13.10)));
+        assert!(proj.contains(&ring, (55.68, 13.02)));
+        assert!(!proj.contains(&ring, (55.68, 13.10)));
+        assert!(!proj.contains(&ring, (55.62, 13.30)));
+        assert!(!proj.contains(&ring, (55.62, 12.90)));
+        assert!(!proj.contains(&ring, (55.75, 13.02)));
+
+        // closed and reversed rings give the same results
+        let mut closed = ring.to_vec();
+        closed.push(ring[0]);
+        assert!(proj.contains(&closed, (55.62, 13.10)));
+        assert!(!proj.contains(&closed, (55.68, 13.10)));
+        closed.reverse();
+        assert!(proj.contains(&closed, (55.62, 13.10)));
+        assert!(!proj.contains(&closed, (55.68, 13.10)));
+
+        // the ray goes through vertices
+        assert!(proj.contains(&ring, (55.65, 13.02)));
+        assert!(!proj.contains(&ring, (55.70, 12.90)));
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let ring = [(-1.0, 179.0), (-1.0, -179.0), (1.0, -179.0), (1.0, 179.0)];
+        assert!(proj.contains(&ring, (0.0, 180.0)));
+        assert!(proj.contains(&ring, (0.0, 179.5)));
+        assert!(proj.contains(&ring, (0.0, -179.5)));
+        assert!(!proj.contains(&ring, (0.0, 178.5)));
+        assert!(!proj.contains(&ring, (0.0, 0.0)));
+    }
+
+    #[test]
+    fn on_boundary_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(!proj.on_boundary(&[], LUND_C));
+        assert!(proj.on_boundary(&[LUND_C], LUND_C));
+        assert!(!proj.on_boundary(&[MALMO_C], LUND_C));
+
+        let square = [(0.0, 0.0), (1000.0, 0.0), (1000.0, 1000.0), (0.0, 1000.0)]
+            .map(|(east, north)| proj.offset(LUND_C, east, north));
+        for point in square {
+            assert!(proj.on_boundary(&square, point));
+        }
+        // the closing edge of the not closed ring
+        assert!(proj.on_boundary(&square, proj.offset(LUND_C, 0.0, 500.0)));
+        assert!(!proj.on_boundary(&square, proj.offset(LUND_C, -1e-6, 500.0)));
+        assert!(!proj.on_boundary(&square, proj.offset(LUND_C, 500.0, 500.0)));
+        // beyond the edge ends
+        assert!(!proj.on_boundary(&square, proj.offset(LUND_C, 0.0, 1500.0)));
+    }
+
+    #[test]
+    fn orientation_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.orientation(LUND_C, LUND_C, MALMO_C), None);
+        assert_eq!(proj.orientation(LUND_C, MALMO_C, LUND_C), None);
+        let square = [(0.0, 0.0), (1000.0, 0.0), (1000.0, 1000.0)]
+            .map(|(east, north)| proj.offset(LUND_C, east, north));
+        assert_eq!(
+            proj.orientation(square[0], square[1], square[2]),
+            Some(Orientation::Counterclockwise)
+        );
+        assert_eq!(
+            proj.orientation(square[2], square[1], square[0]),
+            Some(Orientation::Clockwise)
+        );
+
+        // consistent for any order of nearly-collinear points
+        let opposite = |orientation: Option<Orientation>| {
+            orientation.map(|orientation| match orientation {
+                Orientation::Clockwise => Orientation::Counterclockwise,
+                Orientation::Counterclockwise => Orientation::Clockwise,
+            })
+        };
+        let (a, b) = (MALMO_C, LUND_C);
+        let middle = proj.midpoint(a, b);
+        let (mut clockwise, mut counterclockwise) = (0, 0);
+        for i in -20..=20 {
+            for j in -20..=20 {
+                let c = (
+                    middle.0 + i as f64 * f64::EPSILON * 64.0,
+                    middle.1 + j as f64 * f64::EPSILON * 16.0,
+                );
+                let orientation = proj.orientation(a, b, c);
+                assert_eq!(proj.orientation(b, c, a), orientation);
+                assert_eq!(proj.orientation(c, a, b), orientation);
+                assert_eq!(proj.orientation(b, a, c), opposite(orientation));
+                assert_eq!(proj.orientation(a, c, b), opposite(orientation));
+                match orientation {
+                    Some(Orientation::Clockwise) => clockwise += 1,
+                    Some(Orientation::Counterclockwise) => counterclockwise += 1,
+                    None => {}
+                }
+            }
+        }
+        assert!(clockwise > 0 && counterclockwise > 0);
+    }
+
+    #[test]
+    fn centroid_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.centroid(&[]), None);
+        assert_eq!(proj.centroid(&[(55.65, 13.0)]), Some((55.65, 13.0)));
+        let centroid = proj.centroid(&[(55.6, 13.0), (55.7, 13.2)]).unwrap();
+        assert!((centroid.0 - 55.65).abs() < 1e-12 && (centroid.1 - 13.1).abs() < 1e-12);
+
+        let origin = (55.65, 13.0);
+        let square = [
+            origin,
+            proj.offset(origin, 1000.0, 0.0),
+            proj.offset(origin, 1000.0, 1000.0),
+            proj.offset(origin, 0.0, 1000.0),
+        ];
+        let expected = proj.offset(origin, 500.0, 500.0);
+        assert!(proj.distance(proj.centroid(&square).unwrap(), expected) < 1e-6);
+        let closed = [square[0], square[1], square[2], square[3], square[0]];
+        assert!(proj.distance(proj.centroid(&closed).unwrap(), expected) < 1e-6);
+
+        // L-shaped polygon, made of 2x1 and 1x1 km rectangles
+        let ring = [
+            origin,
+            proj.offset(origin, 2000.0, 0.0),
+            proj.offset(origin, 2000.0, 1000.0),
+            proj.offset(origin, 1000.0, 1000.0),
+            proj.offset(origin, 1000.0, 2000.0),
+            proj.offset(origin, 0.0, 2000.0),
+        ];
+        let expected = proj.offset(origin, 2500.0 / 3.0, 2500.0 / 3.0);
+        assert!(proj.distance(proj.centroid(&ring).unwrap(), expected) < 1e-6);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let centroid = proj
+            .centroid(&[(-1.0, 179.0), (-1.0, -179.0), (1.0, -179.0), (1.0, 179.0)])
+            .unwrap();
+        assert!(centroid.0.abs() < 1e-12 && (centroid.1.abs() - 180.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn weighted_centroid_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.weighted_centroid([]), None);
+        assert_eq!(proj.weighted_centroid([((55.65, 13.0), 0.0)]), None);
+        assert_eq!(
+            proj.weighted_centroid([((55.65, 13.0), 2.0)]),
+            Some((55.65, 13.0))
+        );
+
+        let centroid = proj
+            .weighted_centroid([((55.6, 13.0), 3.0), ((55.7, 13.4), 1.0)])
+            .unwrap();
+        assert!((centroid.0 - 55.625).abs() < 1e-12 && (centroid.1 - 13.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn convex_hull_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.convex_hull(&[]).is_empty());
+        assert_eq!(proj.convex_hull(&[(55.65, 13.0)]), [(55.65, 13.0)]);
+        assert_eq!(
+            proj.convex_hull(&[(55.65, 13.0), (55.65, 13.0)]),
+            [(55.65, 13.0)]
+        );
+        assert_eq!(
+            proj.convex_hull(&[(55.7, 13.1), (55.6, 13.0)]),
+            [(55.6, 13.0), (55.7, 13.1)]
+        );
+        // collinear
+        assert_eq!(
+            proj.convex_hull(&[(55.5, 13.0), (56.0, 13.5), (55.75, 13.25)]),
+            [(55.5, 13.0), (56.0, 13.5)]
+        );
+
+        // square with inner points and points on edges, starting from the South-West corner
+        let points = [
+            (55.65, 13.1),
+            (55.7, 13.0),
+            (55.6, 13.1),
+            (55.6, 13.2),
+            (55.62, 13.05),
+            (55.7, 13.2),
+            (55.6, 13.0),
+            (55.65, 13.2),
+            (55.68, 13.15),
+        ];
+        let hull = proj.convex_hull(&points);
+        assert_eq!(
+            hull,
+            [(55.6, 13.0), (55.6, 13.2), (55.7, 13.2), (55.7, 13.0)]
+        );
+        assert_eq!(
+            proj.area(&hull),
+            proj.area(&[(55.6, 13.0), (55.6, 13.2), (55.7, 13.2), (55.7, 13.0)])
+        );
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let hull = proj.convex_hull(&[
+            (0.0, 180.0),
+            (-1.0, 179.0),
+            (1.0, -179.0),
+            (1.0, 179.0),
+            (-1.0, -179.0),
+        ]);
+        assert_eq!(
+            hull,
+            [(-1.0, 179.0), (-1.0, -179.0), (1.0, -179.0), (1.0, 179.0)]
+        );
+    }
+
+    #[test]
+    fn self_intersection_with_test() {
+        let proj = PlaneProjection::new(55.65);
+        let square = [(0.0, 0.0), (1000.0, 0.0), (1000.0, 1000.0), (0.0, 1000.0)]
+            .map(|(east, north)| proj.offset(LUND_C, east, north));
+        let closed = [square[0], square[1], square[2], square[3], square[0]];
+        let bowtie = [square[0], square[1], square[1], square[3], square[2]];
+        for policy in [DegeneratePolicy::AsPoint, DegeneratePolicy::Ignore] {
+            assert_eq!(proj.self_intersection_with(&closed, policy), Ok(None));
+            assert_eq!(
+                proj.self_intersection_with(&bowtie, policy),
+                Ok(Some((2, 4)))
+            );
+        }
+        assert_eq!(
+            proj.self_intersection_with(&closed, DegeneratePolicy::Reject),
+            Ok(None)
+        );
+        assert_eq!(
+            proj.self_intersection_with(&bowtie, DegeneratePolicy::Reject),
+            Err(DegenerateGeometry { index: 2 })
+        );
+        assert_eq!(
+            proj.self_intersection_with(&[], DegeneratePolicy::Reject),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn self_intersection_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.self_intersection(&[]), None);
+        assert_eq!(proj.self_intersection(&[LUND_C]), None);
+        assert!(proj.is_simple(&[MALMO_C, LUND_C, STOCKHOLM_C]));
+
+        let square = [(0.0, 0.0), (1000.0, 0.0), (1000.0, 1000.0), (0.0, 1000.0)]
+            .map(|(east, north)| proj.offset(LUND_C, east, north));
+        assert!(proj.is_simple(&square));
+        // closed and with repeated points
+        let ring = [
+            square[0], square[1], square[1], square[2], square[3], square[0],
+        ];
+        assert!(proj.is_simple(&ring));
+
+        // bowtie, where only the opposite segments intersect
+        let bowtie = [square[0], square[1], square[3], square[2]];
+        assert_eq!(proj.self_intersection(&bowtie), Some((1, 3)));
+        // the closing segment of the not closed ring
+        let bowtie = [square[3], square[2], square[0], square[1]];
+        assert_eq!(proj.self_intersection(&bowtie), Some((1, 3)));
+        // indices account for the repeated points
+        let bowtie = [
+            square[0], square[0], square[1], square[3], square[3], square[2],
+        ];
+        assert_eq!(proj.self_intersection(&bowtie), Some((2, 5)));
+
+        // touching at a vertex
+        let center = proj.offset(LUND_C, 500.0, 500.0);
+        let ring = [square[0], square[1], center, square[2], square[3], center];
+        assert_eq!(proj.self_intersection(&ring), Some((1, 5)));
+
+        // a spike going back over the previous segment
+        let ring = [
+            square[0],
+            square[1],
+            proj.offset(LUND_C, 500.0, 0.0),
+            square[3],
+        ];
+        assert_eq!(proj.self_intersection(&ring), Some((0, 1)));
+        assert!(!proj.is_simple(&[MALMO_C, LUND_C]));
+        assert!(!proj.is_simple(&[MALMO_C, LUND_C, MALMO_C]));
+    }
+
+    #[test]
+    fn polygon_area_test() {
+        let proj = PlaneProjection::new(55.65);
+        let origin = (55.65, 13.0);
+        let square = |size: f64, shift: f64| {
+            [
+                proj.offset(origin, shift, shift),
+                proj.offset(origin, shift + size, shift),
+                proj.offset(origin, shift + size, shift + size),
+                proj.offset(origin, shift, shift + size),
+            ]
+        };
+        let no_holes: [&[LatLon]; 0] = [];
+        assert_eq!(
+            proj.polygon_area(&square(1000.0, 0.0), &no_holes).round(),
+            1_000_000.0
+        );
+        assert_eq!(
+            proj.polygon_area(
+                &square(1000.0, 0.0),
+                &[square(100.0, 100.0), square(200.0, 500.0)]
+            )
+            .round(),
+            1_000_000.0 - 10_000.0 - 40_000.0
+        );
+    }
+}
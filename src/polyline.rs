@@ -0,0 +1,1135 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::predicates::orient2d;
+use crate::{Float, LatLon, PlaneProjection, dot, lon_diff};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use {crate::PARALLEL_MIN_LEN, rayon::prelude::*};
+
+/// The closest point on a polyline to some other point, see [`PlaneProjection::point_on_line()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointOnLine {
+    /// Closest point on the polyline.
+    pub point: LatLon,
+    /// Index of the segment `(line[index], line[index + 1])` the closest point belongs to.
+    pub index: usize,
+    /// Position of the closest point on the segment, where 0.0=start, 1.0=end of segment.
+    pub t: f64,
+    /// Distance in meters from the original point to the closest one.
+    pub distance: f64,
+}
+
+impl<T: Float> PlaneProjection<T> {
+    /// Length in meters of the polyline, i.e. sum of distances between consecutive points.
+    pub fn polyline_length(&self, points: &[(T, T)]) -> T {
+        #[cfg(not(feature = "rayon"))]
+        let length = self.serial_length(points);
+
+        // Partial sums of fixed-size chunks are combined in index order,
+        // so the result doesn't depend on the thread scheduling
+        #[cfg(feature = "rayon")]
+        let length = {
+            let segments = points.len().saturating_sub(1);
+            (0..segments.div_ceil(PARALLEL_MIN_LEN))
+                .into_par_iter()
+                .map(|chunk| {
+                    let start = chunk * PARALLEL_MIN_LEN;
+                    let end = segments.min(start + PARALLEL_MIN_LEN);
+                    self.serial_length(&points[start..=end])
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .sum()
+        };
+
+        length
+    }
+
+    /// Sum of distances between consecutive points on a single thread.
+    fn serial_length(&self, points: &[(T, T)]) -> T {
+        points
+            .windows(2)
+            .map(|segment| self.distance(segment[0], segment[1]))
+            .sum()
+    }
+
+    /// Running length in meters of the polyline at each of its points, starting with 0.0 at the first point,
+    /// so `along()` lookups, route progress and slicing can share one table of distances calculated once.
+    /// Yields nothing for an empty polyline.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = [(55.60, 13.00), (55.61, 13.00), (55.61, 13.02)];
+    /// let distances = proj.cumulative_distances(&line).map(f64::round).collect::<Vec<_>>();
+    /// assert_eq!(distances, [0.0, 1113.0, 2372.0]);
+    /// ```
+    pub fn cumulative_distances(&self, line: &[(T, T)]) -> impl Iterator<Item = T> {
+        let first = line.first().map(|_| T::ZERO);
+        let running = line.windows(2).scan(T::ZERO, |traveled, segment| {
+            *traveled += self.distance(segment[0], segment[1]);
+            Some(*traveled)
+        });
+        first.into_iter().chain(running)
+    }
+
+    /// Same as [`PlaneProjection::cumulative_distances()`], but writes the running lengths to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` and `out` have different lengths.
+    pub fn cumulative_distances_into(&self, line: &[(T, T)], out: &mut [T]) {
+        assert_eq!(line.len(), out.len(), "`line` and `out` lengths differ");
+        for (out, distance) in out.iter_mut().zip(self.cumulative_distances(line)) {
+            *out = distance;
+        }
+    }
+}
+
+impl PlaneProjection {
+    /// Checks if the point is within `max_distance` meters from the polyline,
+    /// stopping at the first segment that is close enough.
+    pub fn is_within_polyline(&self, point: LatLon, line: &[LatLon], max_distance: f64) -> bool {
+        let square_max_distance = max_distance * max_distance;
+        match line {
+            [] => false,
+            [single] => self.square_distance(point, *single) <= square_max_distance,
+            _ => line.windows(2).any(|segment| {
+                self.square_distance_to_segment(point, (segment[0], segment[1]))
+                    <= square_max_distance
+            }),
+        }
+    }
+
+    /// Checks if the point is within the corridor of `width` meters centered on the polyline,
+    /// i.e. within `width / 2` meters from it, like "is the vehicle still on its route ±50 m".
+    /// Segments are rejected by their bounding boxes first and the check stops at the first segment
+    /// that is close enough, so it is much cheaper than building a [`PlaneProjection::buffer()`] polygon.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let route = [(55.60, 13.00), (55.61, 13.00), (55.61, 13.02)];
+    /// assert!(proj.within_corridor(proj.offset((55.605, 13.0), 45.0, 0.0), &route, 100.0));
+    /// assert!(!proj.within_corridor(proj.offset((55.605, 13.0), 55.0, 0.0), &route, 100.0));
+    /// ```
+    pub fn within_corridor(&self, point: LatLon, line: &[LatLon], width: f64) -> bool {
+        let half_width = width * 0.5;
+        let (lat_margin, lon_margin) = (half_width / self.lat_scale, half_width / self.lon_scale);
+        let square_half_width = half_width * half_width;
+        match line {
+            [] => false,
+            [single] => self.square_distance(point, *single) <= square_half_width,
+            _ => line.windows(2).any(|segment| {
+                let (a, b) = (segment[0], segment[1]);
+                let (lat_a, lat_b) = (a.0 - point.0, b.0 - point.0);
+                let (lon_a, lon_b) = (lon_diff(a.1, point.1), lon_diff(b.1, point.1));
+                // Both ends are beyond the same side of the box around the point
+                let outside = (lat_a > lat_margin && lat_b > lat_margin)
+                    || (lat_a < -lat_margin && lat_b < -lat_margin)
+                    || (lon_a > lon_margin && lon_b > lon_margin)
+                    || (lon_a < -lon_margin && lon_b < -lon_margin);
+                !outside && self.square_distance_to_segment(point, (a, b)) <= square_half_width
+            }),
+        }
+    }
+
+    /// Point located `distance` meters along the polyline from its start.
+    /// Distances outside the polyline length are clamped to its first or last point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn along(&self, line: &[LatLon], distance: f64) -> LatLon {
+        if distance <= 0.0 {
+            return line[0];
+        }
+        let mut traveled = 0.0;
+        for segment in line.windows(2) {
+            let length = self.distance(segment[0], segment[1]);
+            if traveled + length > distance {
+                return self.interpolate(segment[0], segment[1], (distance - traveled) / length);
+            }
+            traveled += length;
+        }
+        line[line.len() - 1]
+    }
+
+    /// Finds the closest to `point` location on the polyline.
+    /// If there are several equally close locations, the first one along the polyline is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn point_on_line(&self, point: LatLon, line: &[LatLon]) -> PointOnLine {
+        let (index, t, square_distance) = self.nearest_segment(point, line);
+        PointOnLine {
+            point: match line.get(index + 1) {
+                Some(&next) => self.interpolate(line[index], next, t),
+                None => line[index],
+            },
+            index,
+            t,
+            distance: square_distance.sqrt(),
+        }
+    }
+
+    /// Same as [`PlaneProjection::point_on_line()`], but also returns the distance in meters along the polyline
+    /// from its start to the closest location, calculated in the same pass over the polyline.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = [(55.60, 13.00), (55.70, 13.00), (55.70, 13.20)];
+    /// let (nearest, progress) = proj.route_progress((55.71, 13.10), &line);
+    /// assert_eq!(nearest.index, 1);
+    /// assert_eq!(progress.round(), (proj.distance(line[0], line[1]) + proj.distance(line[1], nearest.point)).round());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn route_progress(&self, point: LatLon, line: &[LatLon]) -> (PointOnLine, f64) {
+        let mut nearest = (0, 0.0, self.square_distance(point, line[0]), 0.0);
+        let mut traveled = 0.0;
+        for (i, segment) in line.windows(2).enumerate() {
+            let (t, square_distance) = self.project_to_segment(point, (segment[0], segment[1]));
+            let length = self.distance(segment[0], segment[1]);
+            if i == 0 || square_distance < nearest.2 {
+                nearest = (i, t, square_distance, traveled + t * length);
+            }
+            traveled += length;
+        }
+
+        let (index, t, square_distance, progress) = nearest;
+        let point_on_line = PointOnLine {
+            point: match line.get(index + 1) {
+                Some(&next) => self.interpolate(line[index], next, t),
+                None => line[index],
+            },
+            index,
+            t,
+            distance: square_distance.sqrt(),
+        };
+        (point_on_line, progress)
+    }
+
+    /// Distance in meters from the point to the polyline and the index of the nearest segment
+    /// `(line[index], line[index + 1])`, which is 0 for a polyline with a single point.
+    /// Cheaper than [`PlaneProjection::point_on_line()`] when the closest location itself is not needed.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = [(55.60, 13.00), (55.70, 13.00), (55.70, 13.20)];
+    /// let (distance, index) = proj.distance_to_polyline((55.71, 13.10), &line);
+    /// assert_eq!((distance.round(), index), (1113.0, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn distance_to_polyline(&self, point: LatLon, line: &[LatLon]) -> (f64, usize) {
+        let (index, _, square_distance) = self.nearest_segment(point, line);
+        (square_distance.sqrt(), index)
+    }
+
+    /// Index of the segment closest to the point, the projection parameter `t` on it and the square distance.
+    /// A polyline with a single point is treated as a zero-length segment at index 0.
+    fn nearest_segment(&self, point: LatLon, line: &[LatLon]) -> (usize, f64, f64) {
+        #[cfg(not(feature = "rayon"))]
+        let segments = line.windows(2);
+        #[cfg(feature = "rayon")]
+        let segments = line.par_windows(2).with_min_len(PARALLEL_MIN_LEN);
+
+        segments
+            .enumerate()
+            .map(|(i, segment)| {
+                let (t, square_distance) = self.project_to_segment(point, (segment[0], segment[1]));
+                (i, t, square_distance)
+            })
+            // tie-break by index to always pick the first segment, even with parallel search
+            .min_by(|a, b| a.2.total_cmp(&b.2).then(a.0.cmp(&b.0)))
+            .unwrap_or((0, 0.0, self.square_distance(point, line[0])))
+    }
+
+    /// Part of the polyline between the closest to `start` and `stop` points on it, see
+    /// [`PlaneProjection::point_on_line()`]. The order of the original polyline is preserved,
+    /// so the result is the same if `start` and `stop` are swapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn line_slice(&self, start: LatLon, stop: LatLon, line: &[LatLon]) -> Vec<LatLon> {
+        let mut start = self.point_on_line(start, line);
+        let mut stop = self.point_on_line(stop, line);
+        if (start.index, start.t) > (stop.index, stop.t) {
+            core::mem::swap(&mut start, &mut stop);
+        }
+
+        let mut slice = vec![start.point];
+        for &point in &line[start.index + 1..=stop.index] {
+            if slice[slice.len() - 1] != point {
+                slice.push(point);
+            }
+        }
+        if slice[slice.len() - 1] != stop.point || slice.len() == 1 {
+            slice.push(stop.point);
+        }
+        slice
+    }
+
+    /// Part of the polyline between `start` and `stop` distances in meters from its start,
+    /// clamped to the polyline. Like [`PlaneProjection::line_slice()`], the result is the same
+    /// if `start` and `stop` are swapped. Returns an empty polyline if `start` exceeds the polyline length.
+    pub fn line_slice_along(&self, start: f64, stop: f64, line: &[LatLon]) -> Vec<LatLon> {
+        let (start, stop) = if start > stop {
+            (stop, start)
+        } else {
+            (start, stop)
+        };
+
+        let mut slice = Vec::new();
+        let mut traveled = 0.0;
+        for segment in line.windows(2) {
+            let length = self.distance(segment[0], segment[1]);
+            let cut = |distance: f64| {
+                let t = if length > 0.0 {
+                    ((distance - traveled) / length).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                self.interpolate(segment[0], segment[1], t)
+            };
+
+            if slice.is_empty() && (traveled + length > start || traveled + length >= stop) {
+                slice.push(cut(start));
+            }
+            if traveled + length >= stop {
+                slice.push(cut(stop));
+                return slice;
+            }
+            if !slice.is_empty() {
+                slice.push(segment[1]);
+            }
+            traveled += length;
+        }
+        slice
+    }
+
+    /// Resamples the polyline into points spaced `step` meters apart along it, starting from the first point.
+    /// The last point is always kept, so the last leg may be shorter than `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    pub fn resample(&self, line: &[LatLon], step: f64) -> Vec<LatLon> {
+        assert!(step > 0.0, "`step` must be positive");
+        let Some(&first) = line.first() else {
+            return Vec::new();
+        };
+        let mut result = vec![first];
+        let mut count = 1;
+        let mut traveled = 0.0;
+        for segment in line.windows(2) {
+            let length = self.distance(segment[0], segment[1]);
+            // Multiplication instead of accumulation keeps the spacing exact on long polylines
+            while step * (count as f64) < traveled + length {
+                let t = (step * (count as f64) - traveled) / length;
+                result.push(self.interpolate(segment[0], segment[1], t));
+                count += 1;
+            }
+            traveled += length;
+        }
+        if line.len() > 1 {
+            result.push(line[line.len() - 1]);
+        }
+        result
+    }
+
+    /// Resamples the polyline into `count` points that divide it into `count - 1` legs of equal length,
+    /// keeping the first and the last points. Useful to normalize tracks of different lengths to the same size.
+    pub fn resample_to_count(&self, line: &[LatLon], count: usize) -> Vec<LatLon> {
+        let (Some(&first), Some(&last)) = (line.first(), line.last()) else {
+            return Vec::new();
+        };
+        if count < 2 {
+            return line[..count].to_vec();
+        }
+        let step = self.polyline_length(line) / (count - 1) as f64;
+        let mut result = Vec::with_capacity(count);
+        result.push(first);
+        let mut traveled = 0.0;
+        for segment in line.windows(2) {
+            let length = self.distance(segment[0], segment[1]);
+            while result.len() < count - 1 && step * (result.len() as f64) < traveled + length {
+                let t = (step * (result.len() as f64) - traveled) / length;
+                result.push(self.interpolate(segment[0], segment[1], t));
+            }
+            traveled += length;
+        }
+        // Rounding errors may leave the last samples right at the end of the polyline
+        result.resize(count, last);
+        result
+    }
+
+    /// Densifies the polyline by splitting every segment longer than `max_segment` meters into equal parts,
+    /// so no segment exceeds it. Unlike [`PlaneProjection::resample()`], all original points are kept.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = proj.densify(&[(55.65, 13.0), (55.65, 13.1)], 1000.0);
+    /// // The segment is 6.3km long, so it is split into 7 parts
+    /// assert_eq!(line.len(), 8);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_segment` is not positive.
+    pub fn densify(&self, line: &[LatLon], max_segment: f64) -> Vec<LatLon> {
+        assert!(max_segment > 0.0, "`max_segment` must be positive");
+        let Some(&first) = line.first() else {
+            return Vec::new();
+        };
+        let mut result = vec![first];
+        for segment in line.windows(2) {
+            let parts = (self.distance(segment[0], segment[1]) / max_segment).ceil() as usize;
+            for i in 1..parts {
+                let t = i as f64 / parts as f64;
+                result.push(self.interpolate(segment[0], segment[1], t));
+            }
+            result.push(segment[1]);
+        }
+        result
+    }
+
+    /// Simplifies the polyline using Douglas-Peucker algorithm, keeping only points that deviate
+    /// more than `tolerance` meters from the simplified polyline. The first and the last points are always kept.
+    pub fn simplify(&self, line: &[LatLon], tolerance: f64) -> Vec<LatLon> {
+        if line.len() < 3 {
+            return line.to_vec();
+        }
+        let keep = self.douglas_peucker(line, tolerance);
+        line.iter()
+            .zip(keep)
+            .filter_map(|(&point, keep)| keep.then_some(point))
+            .collect()
+    }
+
+    /// Same as [`PlaneProjection::simplify()`], but keeps extra points until the simplified polyline
+    /// doesn't intersect itself and none of the reference `points` switches the side of it,
+    /// so simplified admin boundaries still contain or exclude the same places.
+    /// Closed rings, where the last point repeats the first one, stay valid polygons with at least 3 vertices.
+    /// The input polyline is expected to be simple. Only new shortcuts and their neighbours are rechecked
+    /// after every refinement and segments are pruned by their bounding boxes, so boundaries with
+    /// hundreds of thousands of points are fine.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let line = [(55.65, 13.0), (55.6502, 13.01), (55.65, 13.02)];
+    /// // The reference point is between the line and its shortcut
+    /// let points = [(55.6501, 13.01)];
+    /// assert_eq!(proj.simplify(&line, 50.0).len(), 2);
+    /// assert_eq!(proj.simplify_preserve_topology(&line, 50.0, &points), line);
+    /// ```
+    pub fn simplify_preserve_topology(
+        &self,
+        line: &[LatLon],
+        tolerance: f64,
+        points: &[LatLon],
+    ) -> Vec<LatLon> {
+        if line.len() < 3 {
+            return line.to_vec();
+        }
+        let closed = line[0] == line[line.len() - 1];
+        let mut keep = self.douglas_peucker(line, tolerance);
+        // Bounding boxes are calculated relative to the first point, so they are continuous across the antimeridian
+        let projected = |point: LatLon| self.project_relative(line[0], point);
+        // Segments starting at these points are new and have to be checked, initially all of them
+        let mut new = vec![true; line.len()];
+        loop {
+            let kept: Vec<usize> = (0..line.len()).filter(|&i| keep[i]).collect();
+            let segments: Vec<(usize, usize)> = kept.windows(2).map(|w| (w[0], w[1])).collect();
+            let count = segments.len();
+            // Original segments can't be split any further
+            let shortcut = |k: usize| segments[k].1 != segments[k].0 + 1;
+            let is_new = |k: usize| new[segments[k].0];
+            let next = |k: usize| (k + 1 < count).then_some(k + 1).or(closed.then_some(0));
+            let prev = |k: usize| k.checked_sub(1).or(closed.then_some(count - 1));
+            // Closed rings need at least 3 segments to enclose anything
+            let degenerate = closed && count < 3;
+
+            let mut split = vec![false; count];
+            for k in (0..count).filter(|&k| shortcut(k)) {
+                let (first, last) = segments[k];
+                if degenerate {
+                    split[k] = true;
+                    continue;
+                }
+                // Shortcuts going back over their neighbours
+                let folds = next(k).is_some_and(|m| {
+                    (is_new(k) || is_new(m))
+                        && self.folds(line[last], line[first], line[segments[m].1])
+                }) || prev(k).is_some_and(|m| {
+                    (is_new(k) || is_new(m))
+                        && self.folds(line[first], line[last], line[segments[m].0])
+                });
+                // Any reference point between the original part and its shortcut changes the side
+                split[k] = folds || is_new(k) && self.encloses_any(line, first, last, points);
+            }
+
+            // Crossings of non-adjacent segments, where at least one of them is new, over segments sorted
+            // by their southmost point, so only segments overlapping in latitude are compared
+            let boxes = segments
+                .iter()
+                .map(|&(first, last)| {
+                    let (a, b) = (projected(line[first]), projected(line[last]));
+                    (a.0.min(b.0), a.0.max(b.0), a.1.min(b.1), a.1.max(b.1))
+                })
+                .collect::<Vec<_>>();
+            let mut order = (0..count).collect::<Vec<_>>();
+            order.sort_by(|&a, &b| boxes[a].0.total_cmp(&boxes[b].0));
+            for (i, &k) in order.iter().enumerate() {
+                for &m in &order[i + 1..] {
+                    if boxes[m].0 > boxes[k].1 {
+                        break;
+                    }
+                    let adjacent = next(k) == Some(m) || prev(k) == Some(m);
+                    let pending = shortcut(k) && !split[k] || shortcut(m) && !split[m];
+                    if adjacent
+                        || !pending
+                        || !(is_new(k) || is_new(m))
+                        || boxes[m].2 > boxes[k].3
+                        || boxes[k].2 > boxes[m].3
+                    {
+                        continue;
+                    }
+                    let (a, b) = (segments[k], segments[m]);
+                    let segment = |(first, last): (usize, usize)| (line[first], line[last]);
+                    if self.segments_intersect(segment(a), segment(b)).is_some() {
+                        split[k] |= shortcut(k);
+                        split[m] |= shortcut(m);
+                    }
+                }
+            }
+
+            new.fill(false);
+            let mut refined = false;
+            for (&(first, last), _) in segments.iter().zip(split).filter(|&(_, split)| split) {
+                let (farthest, _) = self.farthest_from_segment(line, first, last);
+                // There is nothing to split at if all distances are NaN
+                if farthest != first && !keep[farthest] {
+                    keep[farthest] = true;
+                    (new[first], new[farthest]) = (true, true);
+                    refined = true;
+                }
+            }
+            if !refined {
+                break;
+            }
+        }
+        line.iter()
+            .zip(keep)
+            .filter_map(|(&point, keep)| keep.then_some(point))
+            .collect()
+    }
+
+    /// Checks if any of the `points` is between the `line` part from `first` to `last` and the shortcut
+    /// between them, rejecting the points outside the part bounding box first.
+    fn encloses_any(&self, line: &[LatLon], first: usize, last: usize, points: &[LatLon]) -> bool {
+        if points.is_empty() {
+            return false;
+        }
+        let part = &line[first..=last];
+        let (mut min, mut max) = (
+            (f64::INFINITY, f64::INFINITY),
+            (f64::NEG_INFINITY, f64::NEG_INFINITY),
+        );
+        for &point in part {
+            let p = self.project_relative(line[0], point);
+            min = (min.0.min(p.0), min.1.min(p.1));
+            max = (max.0.max(p.0), max.1.max(p.1));
+        }
+        points.iter().any(|&point| {
+            let p = self.project_relative(line[0], point);
+            let within = p.0 >= min.0 && p.0 <= max.0 && p.1 >= min.1 && p.1 <= max.1;
+            within && self.contains(part, point)
+        })
+    }
+
+    /// Douglas-Peucker mask of the `line` points to keep for [`PlaneProjection::simplify()`].
+    fn douglas_peucker(&self, line: &[LatLon], tolerance: f64) -> Vec<bool> {
+        let square_tolerance = tolerance * tolerance;
+        let mut keep = vec![false; line.len()];
+        keep[0] = true;
+        keep[line.len() - 1] = true;
+
+        let mut stack = vec![(0, line.len() - 1)];
+        while let Some((first, last)) = stack.pop() {
+            let (farthest, square_distance) = self.farthest_from_segment(line, first, last);
+            if square_distance > square_tolerance {
+                keep[farthest] = true;
+                stack.push((first, farthest));
+                stack.push((farthest, last));
+            }
+        }
+        keep
+    }
+
+    /// Index and square distance of the point between `line[first]` and `line[last]`
+    /// farthest from the segment between them, or `first` with negative infinity if there are no such points.
+    fn farthest_from_segment(&self, line: &[LatLon], first: usize, last: usize) -> (usize, f64) {
+        let segment = (line[first], line[last]);
+        let (mut farthest, mut max_square_distance) = (first, f64::NEG_INFINITY);
+        for (i, &point) in line.iter().enumerate().take(last).skip(first + 1) {
+            let square_distance = self.square_distance_to_segment(point, segment);
+            if square_distance > max_square_distance {
+                (farthest, max_square_distance) = (i, square_distance);
+            }
+        }
+        (farthest, max_square_distance)
+    }
+
+    /// Checks if two segments sharing the `shared` vertex go back over each other.
+    fn folds(&self, shared: LatLon, a: LatLon, b: LatLon) -> bool {
+        let (a, b) = (
+            self.project_relative(shared, a),
+            self.project_relative(shared, b),
+        );
+        orient2d((0.0, 0.0), a, b) == 0.0 && dot(a, b) > 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    #[test]
+    fn polyline_length_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.polyline_length(&[]), 0.0);
+        assert_eq!(proj.polyline_length(&[MALMO_C]), 0.0);
+        assert_eq!(
+            proj.polyline_length(&[MALMO_C, LUND_C]),
+            proj.distance(MALMO_C, LUND_C)
+        );
+        assert_eq!(
+            proj.polyline_length(&[MALMO_C, LUND_C, MALMO_C]).round() as u32,
+            2 * 16374
+        );
+
+        // long polylines give the same result every time, even when summed in parallel
+        let line = (0..5000)
+            .map(|i| proj.offset(MALMO_C, (i as f64 * 0.7).sin() * 50.0, i as f64 * 10.0))
+            .collect::<Vec<_>>();
+        let length = proj.polyline_length(&line);
+        for _ in 0..10 {
+            assert_eq!(proj.polyline_length(&line), length);
+        }
+        let serial = line
+            .windows(2)
+            .map(|segment| proj.distance(segment[0], segment[1]))
+            .sum::<f64>();
+        assert!((length - serial).abs() < 1e-6);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        assert_eq!(
+            proj.polyline_length(&[(0.0, 179.5), (0.0, -179.5), (0.0, -179.0)]),
+            proj.distance((0.0, 179.5), (0.0, -179.0))
+        );
+    }
+
+    #[test]
+    fn cumulative_distances_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.cumulative_distances(&[]).count(), 0);
+        assert!(proj.cumulative_distances(&[MALMO_C]).eq([0.0]));
+
+        let line = [MALMO_C, LUND_C, STOCKHOLM_C, MALMO_C];
+        let distances = proj.cumulative_distances(&line).collect::<Vec<_>>();
+        assert_eq!(distances.len(), line.len());
+        assert_eq!(distances[0], 0.0);
+        for (segment, pair) in line.windows(2).zip(distances.windows(2)) {
+            let length = proj.distance(segment[0], segment[1]);
+            assert!((pair[1] - pair[0] - length).abs() < 1e-6);
+        }
+        assert!((distances[3] - proj.polyline_length(&line)).abs() < 1e-6);
+
+        let mut out = [f64::NAN; 4];
+        proj.cumulative_distances_into(&line, &mut out);
+        assert_eq!(out.as_slice(), distances);
+        proj.cumulative_distances_into(&[], &mut []);
+
+        // across the antimeridian
+        let proj: PlaneProjection = PlaneProjection::new(0.0);
+        let line = [(0.0, 179.5), (0.0, -179.5), (0.0, -179.0)];
+        let distances = proj.cumulative_distances(&line).collect::<Vec<_>>();
+        assert_eq!(distances[1], proj.distance(line[0], line[1]));
+        assert!((distances[2] - proj.distance(line[0], line[2])).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "`line` and `out` lengths differ")]
+    fn cumulative_distances_into_length_mismatch_test() {
+        let proj = PlaneProjection::new(55.65);
+        proj.cumulative_distances_into(&[MALMO_C, LUND_C], &mut [0.0]);
+    }
+
+    #[test]
+    fn is_within_polyline_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(!proj.is_within_polyline(MALMO_C, &[], 1000.0));
+        assert!(proj.is_within_polyline(MALMO_C, &[MALMO_C], 0.0));
+        assert!(!proj.is_within_polyline(MALMO_C, &[LUND_C], 1000.0));
+
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3)];
+        let point = (55.67817981392954, 13.058789566271836);
+        assert!(proj.is_within_polyline(point, &line, 3616.0));
+        assert!(!proj.is_within_polyline(point, &line, 3615.0));
+        assert!(proj.is_within_polyline((55.71, 13.25), &line, 1000.0));
+        assert!(!proj.is_within_polyline((55.71, 13.35), &line, 1000.0));
+    }
+
+    #[test]
+    fn within_corridor_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(!proj.within_corridor(MALMO_C, &[], 1000.0));
+        assert!(proj.within_corridor(MALMO_C, &[MALMO_C], 0.0));
+        assert!(!proj.within_corridor(MALMO_C, &[LUND_C], 1000.0));
+
+        // the same as `is_within_polyline()` with the half width
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3)];
+        let point = (55.67817981392954, 13.058789566271836);
+        assert!(proj.within_corridor(point, &line, 2.0 * 3616.0));
+        assert!(!proj.within_corridor(point, &line, 2.0 * 3615.0));
+        for point in [
+            (55.71, 13.25),
+            (55.71, 13.35),
+            (55.65, 13.1),
+            MALMO_C,
+            (55.6, 13.0),
+        ] {
+            for width in [10.0, 1000.0, 2000.0, 10_000.0] {
+                assert_eq!(
+                    proj.within_corridor(point, &line, width),
+                    proj.is_within_polyline(point, &line, width * 0.5)
+                );
+            }
+        }
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let line = [(0.0, 179.9), (0.0, -179.9)];
+        assert!(proj.within_corridor((0.0004, 180.0), &line, 100.0));
+        assert!(!proj.within_corridor((0.0005, 180.0), &line, 100.0));
+    }
+
+    #[test]
+    fn along_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.along(&[MALMO_C], 100.0), MALMO_C);
+
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3)];
+        assert_eq!(proj.along(&line, -10.0), MALMO_C);
+        assert_eq!(proj.along(&line, 0.0), MALMO_C);
+        assert_eq!(proj.along(&line, 1_000_000.0), line[2]);
+
+        let point = proj.along(&line, 1000.0);
+        assert!((proj.distance(MALMO_C, point) - 1000.0).abs() < 1e-6);
+        assert!(proj.distance_to_segment(point, (MALMO_C, LUND_C)) < 1e-6);
+
+        let length = proj.distance(MALMO_C, LUND_C);
+        let point = proj.along(&line, length + 500.0);
+        assert_eq!(point.0, LUND_C.0);
+        assert!((proj.distance(LUND_C, point) - 500.0).abs() < 1e-6);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let point = proj.along(
+            &[(0.0, 179.5), (0.0, -179.5)],
+            proj.distance((0.0, 179.5), (0.0, 180.0)),
+        );
+        assert!((point.1.abs() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn distance_to_polyline_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.distance_to_polyline(LUND_C, &[MALMO_C]),
+            (proj.distance(LUND_C, MALMO_C), 0)
+        );
+
+        let line = [MALMO_C, LUND_C, STOCKHOLM_C];
+        for point in [MALMO_C, LUND_C, (55.65, 13.05), (57.0, 15.0), (60.0, 18.0)] {
+            let nearest = proj.point_on_line(point, &line);
+            assert_eq!(
+                proj.distance_to_polyline(point, &line),
+                (nearest.distance, nearest.index)
+            );
+        }
+        // the first of equally close segments
+        assert_eq!(proj.distance_to_polyline(LUND_C, &line), (0.0, 0));
+    }
+
+    #[test]
+    fn route_progress_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.route_progress(LUND_C, &[MALMO_C]),
+            (proj.point_on_line(LUND_C, &[MALMO_C]), 0.0)
+        );
+
+        let line = [MALMO_C, LUND_C, STOCKHOLM_C];
+        let first = proj.distance(MALMO_C, LUND_C);
+        for point in [
+            MALMO_C,
+            LUND_C,
+            (55.65, 13.05),
+            (57.0, 15.0),
+            (60.0, 18.0),
+            (55.0, 12.0),
+        ] {
+            let nearest = proj.point_on_line(point, &line);
+            let (point_on_line, progress) = proj.route_progress(point, &line);
+            assert_eq!(point_on_line, nearest);
+            let expected = proj.polyline_length(&line[..=nearest.index])
+                + proj.distance(line[nearest.index], nearest.point);
+            assert!((progress - expected).abs() < 1e-6);
+        }
+        // start, the first of equally close segments and the end
+        assert_eq!(proj.route_progress(MALMO_C, &line).1, 0.0);
+        assert_eq!(proj.route_progress(LUND_C, &line).1, first);
+        assert_eq!(
+            proj.route_progress((60.0, 18.1), &line).1,
+            proj.polyline_length(&line)
+        );
+    }
+
+    #[test]
+    fn point_on_line_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.point_on_line(LUND_C, &[MALMO_C]),
+            PointOnLine {
+                point: MALMO_C,
+                index: 0,
+                t: 0.0,
+                distance: proj.distance(LUND_C, MALMO_C),
+            }
+        );
+
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3)];
+        let result = proj.point_on_line(MALMO_C, &line);
+        assert_eq!((result.point, result.index, result.t), (MALMO_C, 0, 0.0));
+        assert_eq!(result.distance, 0.0);
+
+        // the vertex between segments belongs to the first one
+        let result = proj.point_on_line((55.8, 13.191304107330561), &line);
+        assert_eq!((result.point, result.index, result.t), (LUND_C, 0, 1.0));
+
+        let point = (55.67817981392954, 13.058789566271836);
+        let result = proj.point_on_line(point, &line);
+        assert_eq!(result.index, 0);
+        assert_eq!(result.distance as u32, 3615);
+        assert!(result.t > 0.0 && result.t < 1.0);
+        assert!((proj.distance(point, result.point) - result.distance).abs() < 1e-6);
+
+        let result = proj.point_on_line((55.71, 13.25), &line);
+        assert_eq!(result.index, 1);
+        assert!((result.point.0 - LUND_C.0).abs() < 1e-12);
+        assert!((result.point.1 - 13.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line_slice_test() {
+        let proj = PlaneProjection::new(55.65);
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3), (55.8, 13.3)];
+        assert_eq!(proj.line_slice(MALMO_C, (55.8, 13.3), &line), line);
+        assert_eq!(proj.line_slice((55.9, 13.3), (55.0, 12.0), &line), line);
+        assert_eq!(proj.line_slice(LUND_C, LUND_C, &line), [LUND_C, LUND_C]);
+        assert_eq!(proj.line_slice(MALMO_C, LUND_C, &line), [MALMO_C, LUND_C]);
+
+        let slice = proj.line_slice((55.71, 13.25), (55.75, 13.31), &line);
+        assert_eq!(slice.len(), 3);
+        assert!((slice[0].0 - LUND_C.0).abs() < 1e-12);
+        assert!((slice[0].1 - 13.25).abs() < 1e-12);
+        assert_eq!(slice[1], line[2]);
+        assert!((slice[2].0 - 55.75).abs() < 1e-12);
+        assert!((slice[2].1 - 13.3).abs() < 1e-12);
+        assert_eq!(
+            proj.line_slice((55.75, 13.31), (55.71, 13.25), &line),
+            slice
+        );
+
+        // both points on the same segment
+        let slice = proj.line_slice((55.71, 13.28), (55.71, 13.22), &line);
+        assert_eq!(slice.len(), 2);
+        assert!(slice[0].1 < slice[1].1);
+    }
+
+    #[test]
+    fn line_slice_along_test() {
+        let proj = PlaneProjection::new(55.65);
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3), (55.8, 13.3)];
+        let length = proj.polyline_length(&line);
+        assert_eq!(proj.line_slice_along(0.0, length, &line), line);
+        assert_eq!(proj.line_slice_along(-100.0, length + 100.0, &line), line);
+        assert_eq!(proj.line_slice_along(length, -100.0, &line), line);
+        assert!(
+            proj.line_slice_along(length + 1.0, length + 100.0, &line)
+                .is_empty()
+        );
+        assert!(proj.line_slice_along(0.0, 100.0, &[MALMO_C]).is_empty());
+
+        let slice = proj.line_slice_along(1000.0, 20_000.0, &line);
+        assert_eq!(slice.len(), 3);
+        assert!((proj.distance(MALMO_C, slice[0]) - 1000.0).abs() < 1e-6);
+        assert_eq!(slice[1], LUND_C);
+        assert!((proj.polyline_length(&slice) - 19_000.0).abs() < 1e-6);
+
+        // both cuts on the same segment
+        let slice = proj.line_slice_along(1000.0, 2000.0, &line);
+        assert_eq!(slice.len(), 2);
+        assert!((proj.polyline_length(&slice) - 1000.0).abs() < 1e-6);
+        assert_eq!(proj.line_slice_along(2000.0, 1000.0, &line), slice);
+
+        // zero-length slice at the vertex
+        let distance = proj.distance(MALMO_C, LUND_C);
+        assert_eq!(
+            proj.line_slice_along(distance, distance, &line),
+            [LUND_C, LUND_C]
+        );
+
+        // zero-length segments are skipped
+        let slice = proj.line_slice_along(0.0, 1000.0, &[MALMO_C, MALMO_C, LUND_C]);
+        assert_eq!(slice.len(), 2);
+        assert!((proj.polyline_length(&slice) - 1000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn simplify_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.simplify(&[], 10.0).is_empty());
+        assert_eq!(proj.simplify(&[MALMO_C], 10.0), [MALMO_C]);
+        assert_eq!(proj.simplify(&[MALMO_C, LUND_C], 10.0), [MALMO_C, LUND_C]);
+
+        // zigzag with 5m and 50m deviations from the straight line
+        let origin = (55.65, 13.0);
+        let line = [
+            origin,
+            proj.offset(origin, 100.0, 5.0),
+            proj.offset(origin, 200.0, -5.0),
+            proj.offset(origin, 300.0, 50.0),
+            proj.offset(origin, 400.0, 0.0),
+            proj.offset(origin, 500.0, -3.0),
+            proj.offset(origin, 600.0, 0.0),
+        ];
+        assert_eq!(proj.simplify(&line, 1.0), line);
+        assert_eq!(
+            proj.simplify(&line, 10.0),
+            [line[0], line[2], line[3], line[4], line[6]]
+        );
+        assert_eq!(proj.simplify(&line, 100.0), [line[0], line[6]]);
+
+        // closed ring keeps its shape
+        let ring = [origin, line[3], line[6], origin];
+        assert_eq!(proj.simplify(&ring, 10.0), ring);
+    }
+
+    #[test]
+    fn simplify_preserve_topology_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.simplify_preserve_topology(&[], 10.0, &[]).is_empty());
+        assert_eq!(
+            proj.simplify_preserve_topology(&[MALMO_C, LUND_C], 10.0, &[MALMO_C]),
+            [MALMO_C, LUND_C]
+        );
+
+        // Square ring with a 20m bump on the bottom edge and an inlet from the top edge into the bump
+        let ring = [
+            (0.0, 0.0),
+            (500.0, -20.0),
+            (1000.0, 0.0),
+            (1000.0, 1000.0),
+            (510.0, 1000.0),
+            (500.0, -10.0),
+            (490.0, 1000.0),
+            (0.0, 1000.0),
+            (0.0, 0.0),
+        ]
+        .map(|(east, north)| proj.offset(LUND_C, east, north));
+        // Douglas-Peucker drops the bump, so the inlet crosses the bottom edge
+        let simplified = proj.simplify(&ring, 30.0);
+        assert_eq!(simplified.len(), 8);
+        assert!(
+            proj.segments_intersect(
+                (simplified[0], simplified[1]),
+                (simplified[3], simplified[4])
+            )
+            .is_some()
+        );
+        assert_eq!(proj.simplify_preserve_topology(&ring, 30.0, &[]), ring);
+
+        // The bump is dropped if the inlet doesn't go into it, unless there is a reference point
+        let mut ring = ring;
+        ring[5] = proj.offset(LUND_C, 500.0, 10.0);
+        assert_eq!(proj.simplify_preserve_topology(&ring, 30.0, &[]).len(), 8);
+        let point = proj.offset(LUND_C, 400.0, -5.0);
+        assert!(proj.contains(&ring, point));
+        assert_eq!(proj.simplify_preserve_topology(&ring, 30.0, &[point]), ring);
+
+        // Rings keep enclosing some area
+        let simplified = proj.simplify_preserve_topology(&ring, 10_000.0, &[]);
+        assert!(simplified.len() >= 4);
+        assert_eq!(simplified[0], simplified[simplified.len() - 1]);
+        assert!(proj.area(&simplified) > 0.0);
+        assert_eq!(proj.simplify(&ring, 10_000.0).len(), 2);
+
+        // Collinear points are removed without looping forever
+        let line = [0.0, 100.0, 200.0, 300.0].map(|east| proj.offset(LUND_C, east, 0.0));
+        assert_eq!(
+            proj.simplify_preserve_topology(&line, 1.0, &[]),
+            [line[0], line[3]]
+        );
+
+        // NaN distances leave nothing to split at
+        let ring = [
+            (0.0, 0.0),
+            (f64::NAN, f64::NAN),
+            (f64::NAN, f64::NAN),
+            (0.0, 0.0),
+        ];
+        assert_eq!(proj.simplify_preserve_topology(&ring, 1.0, &[]).len(), 2);
+
+        // A noisy boundary with thousands of points and reference points close to it
+        let ring = (0..=20_000)
+            .map(|i| {
+                let angle = (i % 20_000) as f64 / 20_000.0 * core::f64::consts::TAU;
+                let radius =
+                    10_000.0 + 300.0 * (angle * 97.0).sin() + 50.0 * (angle * 1013.0).sin();
+                proj.offset(LUND_C, radius * angle.sin(), radius * angle.cos())
+            })
+            .collect::<Vec<_>>();
+        let points = (0..200)
+            .map(|i| {
+                let angle = i as f64 / 200.0 * core::f64::consts::TAU;
+                let radius =
+                    10_000.0 + 300.0 * (angle * 97.0).sin() + if i % 2 == 0 { 30.0 } else { -30.0 };
+                proj.offset(LUND_C, radius * angle.sin(), radius * angle.cos())
+            })
+            .collect::<Vec<_>>();
+        for tolerance in [50.0, 500.0, 5000.0] {
+            let simplified = proj.simplify_preserve_topology(&ring, tolerance, &points);
+            assert!(simplified.len() < ring.len() / 4);
+            assert!(proj.is_simple(&simplified));
+            for &point in &points {
+                assert_eq!(
+                    proj.contains(&simplified, point),
+                    proj.contains(&ring, point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn resample_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.resample(&[], 10.0), []);
+        assert_eq!(proj.resample(&[MALMO_C], 10.0), [MALMO_C]);
+        assert_eq!(proj.resample(&[MALMO_C, MALMO_C], 10.0), [MALMO_C, MALMO_C]);
+        // the step is longer than the polyline
+        assert_eq!(
+            proj.resample(&[MALMO_C, LUND_C], 20_000.0),
+            [MALMO_C, LUND_C]
+        );
+
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3)];
+        let resampled = proj.resample(&line, 1000.0);
+        let length = proj.polyline_length(&line);
+        assert_eq!(resampled.len(), 2 + (length / 1000.0) as usize);
+        assert_eq!(resampled[0], line[0]);
+        assert_eq!(resampled[resampled.len() - 1], line[2]);
+        for (i, &point) in resampled.iter().enumerate().take(resampled.len() - 1) {
+            let expected = proj.along(&line, 1000.0 * i as f64);
+            assert!(proj.distance(point, expected) < 1e-6);
+        }
+        // resampled points lie on the polyline
+        for &point in &resampled {
+            assert!(proj.point_on_line(point, &line).distance < 1e-6);
+        }
+
+        // exact multiple of the step doesn't duplicate the last point
+        let proj = PlaneProjection::new(0.0);
+        let line = [(0.0, 0.0), (0.0, 0.1)];
+        let length = proj.distance(line[0], line[1]);
+        assert_eq!(proj.resample(&line, length / 4.0).len(), 5);
+    }
+
+    #[test]
+    fn densify_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert!(proj.densify(&[], 100.0).is_empty());
+        assert_eq!(proj.densify(&[MALMO_C], 100.0), [MALMO_C]);
+        assert_eq!(
+            proj.densify(&[MALMO_C, LUND_C], 20_000.0),
+            [MALMO_C, LUND_C]
+        );
+
+        let line = [MALMO_C, LUND_C, MALMO_C, MALMO_C];
+        let dense = proj.densify(&line, 1000.0);
+        // 16.4km segments are split into 17 parts, the duplicate point is kept
+        assert_eq!(dense.len(), 1 + 17 + 17 + 1);
+        assert_eq!(dense[17], LUND_C);
+        assert_eq!(dense[34..], [MALMO_C, MALMO_C]);
+        for segment in dense.windows(2) {
+            assert!(proj.distance(segment[0], segment[1]) <= 1000.0);
+        }
+        assert!((proj.polyline_length(&dense) - proj.polyline_length(&line)).abs() < 1e-6);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let dense = proj.densify(&[(0.0, 179.9), (0.0, -179.9)], 10_000.0);
+        assert_eq!(dense.len(), 4);
+        assert!(dense.iter().all(|point| point.1.abs() > 179.8));
+    }
+
+    #[test]
+    fn resample_to_count_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.resample_to_count(&[], 10), []);
+        assert_eq!(proj.resample_to_count(&[MALMO_C, LUND_C], 0), []);
+        assert_eq!(proj.resample_to_count(&[MALMO_C, LUND_C], 1), [MALMO_C]);
+        assert_eq!(
+            proj.resample_to_count(&[MALMO_C, LUND_C], 2),
+            [MALMO_C, LUND_C]
+        );
+        assert_eq!(proj.resample_to_count(&[MALMO_C], 3), [MALMO_C; 3]);
+
+        let line = [MALMO_C, LUND_C, (55.704141722528554, 13.3)];
+        let length = proj.polyline_length(&line);
+        for count in [3, 10, 101] {
+            let resampled = proj.resample_to_count(&line, count);
+            assert_eq!(resampled.len(), count);
+            assert_eq!(resampled[0], line[0]);
+            assert_eq!(resampled[count - 1], line[2]);
+            for (i, &point) in resampled.iter().enumerate() {
+                let expected = proj.along(&line, length * i as f64 / (count - 1) as f64);
+                assert!(proj.distance(point, expected) < 1e-6);
+            }
+        }
+    }
+}
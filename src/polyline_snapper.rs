@@ -0,0 +1,174 @@
+use crate::projected_polyline::Aabb;
+use crate::{LatLon, PlaneProjection, PointOnLine, ProjectedPolyline};
+use alloc::vec::Vec;
+
+/// A polyline matched by [`PolylineSnapper`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapCandidate {
+    /// Index of the matched polyline.
+    pub polyline: usize,
+    /// The closest location on the matched polyline.
+    pub point_on_line: PointOnLine,
+    /// Heading in degrees of the matched segment in the range [0.0, 360.0), see [`PlaneProjection::heading()`].
+    pub heading: f32,
+}
+
+/// Snaps points to the closest of many polylines, like road segments in a map matcher.
+/// Polylines are projected once and skipped by their bounding boxes when they are too far away.
+///
+/// ```
+/// use plane_projection::{PlaneProjection, PolylineSnapper};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let roads = [
+///     vec![(55.6033090, 13.0019737), (55.7041417, 13.1913041)],
+///     vec![(55.60, 13.10), (55.60, 13.20)],
+/// ];
+/// let snapper = PolylineSnapper::new(&proj, &roads);
+/// let candidate = snapper.nearest((55.6781798, 13.0587896), 5000.0).unwrap();
+/// assert_eq!(candidate.polyline, 0);
+/// assert_eq!(candidate.point_on_line.distance as u32, 3615);
+/// assert_eq!(candidate.heading as u32, 46);
+/// ```
+#[derive(Clone)]
+pub struct PolylineSnapper {
+    projection: PlaneProjection,
+    /// Origin of the local Cartesian coordinates for the polyline boxes, which is the first polyline point.
+    origin: LatLon,
+    polylines: Vec<ProjectedPolyline>,
+    boxes: Vec<Aabb>,
+}
+
+impl PolylineSnapper {
+    /// Projects the polylines and builds their bounding boxes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the polylines is empty.
+    pub fn new(projection: &PlaneProjection, polylines: &[impl AsRef<[LatLon]>]) -> Self {
+        let origin = polylines
+            .first()
+            .map(|line| line.as_ref()[0])
+            .unwrap_or_default();
+        let boxes = polylines
+            .iter()
+            .map(|line| {
+                line.as_ref()
+                    .iter()
+                    .map(|&point| {
+                        let p = projection.project_relative(origin, point);
+                        Aabb::new(p, p)
+                    })
+                    .reduce(Aabb::union)
+                    .expect("polyline is empty")
+            })
+            .collect();
+        let polylines = polylines
+            .iter()
+            .map(|line| ProjectedPolyline::new(projection, line.as_ref()))
+            .collect();
+        Self {
+            projection: projection.clone(),
+            origin,
+            polylines,
+            boxes,
+        }
+    }
+
+    /// The closest polyline within `max_distance` meters from the point, if any.
+    pub fn nearest(&self, point: LatLon, max_distance: f64) -> Option<SnapCandidate> {
+        self.candidates(point, max_distance, 1).pop()
+    }
+
+    /// Up to `max_count` closest polylines within `max_distance` meters from the point, sorted by distance.
+    pub fn candidates(
+        &self,
+        point: LatLon,
+        max_distance: f64,
+        max_count: usize,
+    ) -> Vec<SnapCandidate> {
+        if max_count == 0 {
+            return Vec::new();
+        }
+        let p = self.projection.project_relative(self.origin, point);
+        let square_max_distance = max_distance * max_distance;
+        let mut order = self
+            .boxes
+            .iter()
+            .enumerate()
+            .map(|(i, aabb)| (i, aabb.square_distance(p)))
+            .filter(|&(_, square_distance)| square_distance <= square_max_distance)
+            .collect::<Vec<_>>();
+        order.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut candidates: Vec<SnapCandidate> = Vec::with_capacity(max_count.min(order.len()));
+        for (polyline, square_box_distance) in order {
+            if let Some(worst) = candidates.last().filter(|_| candidates.len() == max_count) {
+                let worst = worst.point_on_line.distance;
+                if square_box_distance > worst * worst {
+                    break;
+                }
+            }
+            let line = &self.polylines[polyline];
+            let point_on_line = line.nearest_segment(point);
+            if point_on_line.distance > max_distance {
+                continue;
+            }
+            let position =
+                candidates.partition_point(|c| c.point_on_line.distance <= point_on_line.distance);
+            if position < max_count {
+                candidates.truncate(max_count - 1);
+                candidates.insert(
+                    position,
+                    SnapCandidate {
+                        polyline,
+                        point_on_line,
+                        heading: line.segment_heading(point_on_line.index),
+                    },
+                );
+            }
+        }
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+    use alloc::vec;
+
+    #[test]
+    fn candidates_test() {
+        let proj = PlaneProjection::new(55.65);
+        let snapper = PolylineSnapper::new(&proj, &[] as &[Vec<LatLon>]);
+        assert_eq!(snapper.nearest(MALMO_C, 1000.0), None);
+
+        let roads = [
+            vec![MALMO_C, LUND_C],
+            vec![(55.60, 13.10), (55.60, 13.20)],
+            vec![(55.65, 13.00)],
+            vec![(56.0, 14.0), (56.1, 14.1)],
+        ];
+        let snapper = PolylineSnapper::new(&proj, &roads);
+        let point = (55.6781798, 13.0587896);
+        let candidates = snapper.candidates(point, 10_000.0, 10);
+        assert_eq!(
+            candidates.iter().map(|c| c.polyline).collect::<Vec<_>>(),
+            [0, 2, 1]
+        );
+        for candidate in &candidates {
+            let expected = proj.point_on_line(point, &roads[candidate.polyline]);
+            assert!((candidate.point_on_line.distance - expected.distance).abs() < 1e-6);
+            assert_eq!(candidate.point_on_line.index, expected.index);
+        }
+        assert_eq!(candidates[0].heading as u32, 46);
+        assert_eq!(candidates[2].heading as u32, 90);
+
+        assert_eq!(snapper.candidates(point, 10_000.0, 0), []);
+        assert_eq!(snapper.candidates(point, 10_000.0, 2), candidates[..2]);
+        assert_eq!(snapper.candidates(point, 3000.0, 10), []);
+        assert_eq!(snapper.nearest(point, 10_000.0), Some(candidates[0]));
+        assert_eq!(snapper.nearest((56.05, 14.05), 1000.0).unwrap().polyline, 3);
+    }
+}
@@ -0,0 +1,164 @@
+//! Exact geometric predicates in the projection space, following Shewchuk's
+//! [adaptive precision predicates](https://www.cs.cmu.edu/~quake/robust.html): the regular float result is used
+//! when it is certainly correct, and exact arithmetic on float expansions is the fallback for nearly-collinear cases.
+
+/// How zero-length segments and repeated ring vertices are handled by [`PlaneProjection::segments_intersect_with()`]
+/// and [`PlaneProjection::self_intersection_with()`].
+///
+/// [`PlaneProjection::segments_intersect_with()`]: crate::PlaneProjection::segments_intersect_with
+/// [`PlaneProjection::self_intersection_with()`]: crate::PlaneProjection::self_intersection_with
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DegeneratePolicy {
+    /// Zero-length segments are points, which intersect only segments passing through them,
+    /// and repeated ring vertices are merged. Used by the methods without an explicit policy.
+    #[default]
+    AsPoint,
+    /// Zero-length segments don't intersect anything and repeated ring vertices are merged.
+    Ignore,
+    /// Zero-length segments and repeated ring vertices are reported as [`DegenerateGeometry`] errors.
+    Reject,
+}
+
+/// Error returned for degenerate inputs with [`DegeneratePolicy::Reject`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DegenerateGeometry {
+    /// Index of the zero-length segment, or of the ring vertex that repeats the previous one.
+    pub index: usize,
+}
+
+impl core::fmt::Display for DegenerateGeometry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "degenerate geometry at index {}", self.index)
+    }
+}
+
+impl core::error::Error for DegenerateGeometry {}
+
+/// Half of the machine epsilon, i.e. the relative rounding error of a single operation.
+const EPSILON: f64 = f64::EPSILON * 0.5;
+
+/// Bound on the relative error of the regular float orientation, see `ccwerrboundA` in Shewchuk's paper.
+const ORIENTATION_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+
+/// Twice the signed area of the triangle `a`, `b`, `c` in the projection space with the exact sign,
+/// i.e. `cross(b - a, c - a)` evaluated without rounding errors. Positive values mean that the triangle
+/// goes clockwise, negative ones mean counterclockwise and 0.0 is returned only for exactly collinear points.
+pub(crate) fn orient2d(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    let left = (b.0 - a.0) * (c.1 - a.1);
+    let right = (b.1 - a.1) * (c.0 - a.0);
+    let det = left - right;
+    if det.abs() >= ORIENTATION_ERROR_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+
+    // Exact sum of the expanded determinant terms, whose most significant component has the sign of the sum
+    let mut expansion = [0.0; 12];
+    let mut len = 0;
+    for (x, y, sign) in [
+        (b.0, c.1, 1.0),
+        (b.0, a.1, -1.0),
+        (a.0, c.1, -1.0),
+        (b.1, c.0, -1.0),
+        (b.1, a.0, 1.0),
+        (a.1, c.0, 1.0),
+    ] {
+        let (product, error) = two_product(sign * x, y);
+        grow_expansion(&mut expansion, &mut len, error);
+        grow_expansion(&mut expansion, &mut len, product);
+    }
+    if len == 0 { 0.0 } else { expansion[len - 1] }
+}
+
+/// Checks if the point `p` collinear with the segment is within its bounding box, i.e. on the segment itself.
+#[inline(always)]
+pub(crate) fn within_box(p: (f64, f64), (a, b): ((f64, f64), (f64, f64))) -> bool {
+    p.0 >= a.0.min(b.0) && p.0 <= a.0.max(b.0) && p.1 >= a.1.min(b.1) && p.1 <= a.1.max(b.1)
+}
+
+/// Adds `value` to the nonoverlapping `expansion` of `len` components in increasing magnitude order,
+/// dropping zero components, see Grow-Expansion in Shewchuk's paper.
+fn grow_expansion(expansion: &mut [f64; 12], len: &mut usize, value: f64) {
+    let mut sum = value;
+    let mut out = 0;
+    for i in 0..*len {
+        let error;
+        (sum, error) = two_sum(sum, expansion[i]);
+        if error != 0.0 {
+            expansion[out] = error;
+            out += 1;
+        }
+    }
+    if sum != 0.0 {
+        expansion[out] = sum;
+        out += 1;
+    }
+    *len = out;
+}
+
+/// Sum of two floats and its exact rounding error.
+#[inline(always)]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Product of two floats and its exact rounding error, using Dekker's splitting.
+#[inline(always)]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = a_low * b_low - (((product - a_high * b_high) - a_low * b_high) - a_high * b_low);
+    (product, error)
+}
+
+/// Splits the float into two halves with 26 significant bits each, so their products are exact.
+#[inline(always)]
+fn split(a: f64) -> (f64, f64) {
+    let c = 134_217_729.0 * a; // 2^27 + 1
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cross;
+
+    #[test]
+    fn orient2d_test() {
+        assert_eq!(orient2d((0.0, 0.0), (1.0, 0.0), (2.0, 0.0)), 0.0);
+        assert_eq!(orient2d((0.0, 0.0), (0.0, 0.0), (1.0, 1.0)), 0.0);
+        // (north, east) order, so going North and then turning East is clockwise
+        assert!(orient2d((0.0, 0.0), (1.0, 0.0), (1.0, 1.0)) > 0.0);
+        assert!(orient2d((0.0, 0.0), (1.0, 0.0), (1.0, -1.0)) < 0.0);
+
+        // Shewchuk's example of nearly-collinear points, where the regular float result is often wrong.
+        // All coordinates are multiples of 2^-53, so the exact sign is calculated with integers.
+        let (b, c) = ((12.0, 12.0), (24.0, 24.0));
+        let scale = (1u64 << 53) as f64;
+        let exact = |a: (f64, f64)| {
+            let [a0, a1, b0, b1, c0, c1] =
+                [a.0, a.1, b.0, b.1, c.0, c.1].map(|value| (value * scale) as i128);
+            ((b0 - a0) * (c1 - a1) - (b1 - a1) * (c0 - a0)).signum()
+        };
+        let sign = |value: f64| (value > 0.0) as i128 - (value < 0.0) as i128;
+        let mut naive_errors = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = (0.5 + i as f64 / scale, 0.5 + j as f64 / scale);
+                assert_eq!(sign(orient2d(a, b, c)), exact(a), "{i}, {j}");
+                let naive = cross((b.0 - a.0, b.1 - a.1), (c.0 - a.0, c.1 - a.1));
+                if sign(naive) != exact(a) {
+                    naive_errors += 1;
+                }
+                // consistent for any order of points
+                assert_eq!(sign(orient2d(b, a, c)), -exact(a));
+                assert_eq!(sign(orient2d(b, c, a)), exact(a));
+            }
+        }
+        assert!(naive_errors > 0);
+    }
+}
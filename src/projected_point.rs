@@ -0,0 +1,117 @@
+use crate::{Float, PlaneProjection};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A point in the plane projection space returned by [`PlaneProjection::project()`], where the first value
+/// is the North coordinate and the second is the East one, both in meters.
+/// Differences between points are vectors in meters, so [`ProjectedPoint::norm()`] of a difference is the distance.
+/// Note that the projection space doesn't wrap around the antimeridian, so both points should be on the same side.
+///
+/// ```
+/// use plane_projection::PlaneProjection;
+///
+/// let proj: PlaneProjection = PlaneProjection::new(55.65);
+/// let (a, b) = (proj.project((55.7041417, 13.1913041)), proj.project((55.6033090, 13.0019737)));
+/// assert_eq!((b - a).norm() as u32, 16373);
+///
+/// let middle = (a + (b - a) * 0.5).unproject(&proj);
+/// assert!((proj.distance(middle, (55.7041417, 13.1913041)) - 16373.0 / 2.0).abs() < 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ProjectedPoint<T = f64>(pub T, pub T);
+
+impl<T: Float> ProjectedPoint<T> {
+    /// Dot product of two vectors.
+    #[inline(always)]
+    pub fn dot(self, other: Self) -> T {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    /// Length of the vector.
+    #[inline(always)]
+    pub fn norm(self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    /// Converts the point back to (latitude, longitude), see [`PlaneProjection::unproject()`].
+    #[inline(always)]
+    pub fn unproject(self, projection: &PlaneProjection<T>) -> (T, T) {
+        projection.unproject(self)
+    }
+}
+
+impl<T: Float> Add for ProjectedPoint<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl<T: Float> Sub for ProjectedPoint<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl<T: Float> Mul<T> for ProjectedPoint<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, scale: T) -> Self {
+        Self(self.0 * scale, self.1 * scale)
+    }
+}
+
+impl<T: Float> Neg for ProjectedPoint<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl<T> From<(T, T)> for ProjectedPoint<T> {
+    #[inline(always)]
+    fn from(point: (T, T)) -> Self {
+        Self(point.0, point.1)
+    }
+}
+
+impl<T> From<ProjectedPoint<T>> for (T, T) {
+    #[inline(always)]
+    fn from(point: ProjectedPoint<T>) -> Self {
+        (point.0, point.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn projected_point_test() {
+        let (a, b) = (ProjectedPoint(1.0, 2.0), ProjectedPoint(3.0, -4.0));
+        assert_eq!(a + b, ProjectedPoint(4.0, -2.0));
+        assert_eq!(a - b, ProjectedPoint(-2.0, 6.0));
+        assert_eq!(a * 2.0, ProjectedPoint(2.0, 4.0));
+        assert_eq!(-a, ProjectedPoint(-1.0, -2.0));
+        assert_eq!(a.dot(b), -5.0);
+        assert_eq!(b.norm(), 5.0);
+        assert_eq!(ProjectedPoint::from((1.0, 2.0)), a);
+        assert_eq!(<(f64, f64)>::from(a), (1.0, 2.0));
+
+        let proj = PlaneProjection::new(55.65);
+        let (malmo, lund) = (proj.project(MALMO_C), proj.project(LUND_C));
+        assert!(((lund - malmo).norm() - proj.distance(MALMO_C, LUND_C)).abs() < 1e-9);
+        let unprojected = malmo.unproject(&proj);
+        assert!(
+            (unprojected.0 - MALMO_C.0).abs() < 1e-12 && (unprojected.1 - MALMO_C.1).abs() < 1e-12
+        );
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(not(feature = "std"))]
+use crate::{Float as _, math::Float as _};
+use crate::{LatLon, PlaneProjection, PointOnLine, project_to_origin_segment};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Number of consecutive segments grouped under a single bounding box.
+const SEGMENTS_PER_CHUNK: usize = 16;
+
+/// Axis-aligned bounding box in the projection space.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Aabb {
+    min: (f64, f64),
+    max: (f64, f64),
+}
+
+impl Aabb {
+    pub(crate) fn new(a: (f64, f64), b: (f64, f64)) -> Self {
+        Self {
+            min: (a.0.min(b.0), a.1.min(b.1)),
+            max: (a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    pub(crate) fn union(self, other: Self) -> Self {
+        Self {
+            min: (self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: (self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
+
+    /// Square distance from the point to the box, which is 0.0 for points inside.
+    #[inline(always)]
+    pub(crate) fn square_distance(&self, p: (f64, f64)) -> f64 {
+        let d0 = (self.min.0 - p.0).max(p.0 - self.max.0).max(0.0);
+        let d1 = (self.min.1 - p.1).max(p.1 - self.max.1).max(0.0);
+        d0 * d0 + d1 * d1
+    }
+}
+
+/// A polyline projected once with its segments indexed by bounding boxes,
+/// useful for matching many points against the same, potentially long, polyline.
+///
+/// ```
+/// use plane_projection::{PlaneProjection, ProjectedPolyline};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let line = ProjectedPolyline::new(&proj, &[(55.6033090, 13.0019737), (55.7041417, 13.1913041)]);
+/// let nearest = line.nearest_segment((55.6781798, 13.0587896));
+/// assert_eq!((nearest.index, nearest.distance as u32), (0, 3615));
+/// ```
+#[derive(Clone)]
+pub struct ProjectedPolyline {
+    projection: PlaneProjection,
+    /// Origin of the local Cartesian coordinates, which is the first point of the polyline.
+    origin: LatLon,
+    /// Polyline points in the local Cartesian coordinates.
+    points: Vec<(f64, f64)>,
+    segment_boxes: Vec<Aabb>,
+    chunk_boxes: Vec<Aabb>,
+}
+
+impl ProjectedPolyline {
+    /// Projects the polyline and builds the bounding boxes over its segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn new(projection: &PlaneProjection, line: &[LatLon]) -> Self {
+        let origin = line[0];
+        let points = line
+            .iter()
+            .map(|&point| projection.project_relative(origin, point))
+            .collect::<Vec<_>>();
+        Self::from_projected(projection, origin, points)
+    }
+
+    /// Builds the bounding boxes over the non-empty polyline already projected relative to `origin`.
+    pub(crate) fn from_projected(
+        projection: &PlaneProjection,
+        origin: LatLon,
+        points: Vec<(f64, f64)>,
+    ) -> Self {
+        // A single point polyline is treated as a zero-length segment
+        let segment_boxes = match points.len() {
+            1 => vec![Aabb::new(points[0], points[0])],
+            _ => points.windows(2).map(|s| Aabb::new(s[0], s[1])).collect(),
+        };
+        let chunk_boxes = segment_boxes
+            .chunks(SEGMENTS_PER_CHUNK)
+            .map(|chunk| chunk.iter().copied().reduce(Aabb::union).unwrap())
+            .collect();
+        Self {
+            projection: projection.clone(),
+            origin,
+            points,
+            segment_boxes,
+            chunk_boxes,
+        }
+    }
+
+    /// Finds the closest to `point` location on the polyline, like [`PlaneProjection::point_on_line()`] does,
+    /// but skipping segments that are too far away.
+    pub fn nearest_segment(&self, point: LatLon) -> PointOnLine {
+        let p = self.projection.project_relative(self.origin, point);
+
+        // Start from the closest chunk to get a good initial estimate
+        let (closest_chunk, _) = self
+            .chunk_boxes
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| (i, chunk.square_distance(p)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        let mut best = (usize::MAX, 0.0, f64::INFINITY);
+        self.search_chunk(closest_chunk, p, &mut best);
+        for (i, chunk) in self.chunk_boxes.iter().enumerate() {
+            if i != closest_chunk && chunk.square_distance(p) <= best.2 {
+                self.search_chunk(i, p, &mut best);
+            }
+        }
+
+        let (index, t, square_distance) = best;
+        let a = self.points[index];
+        let b = self.points.get(index + 1).copied().unwrap_or(a);
+        let snapped = (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+        PointOnLine {
+            point: self.projection.unproject_relative(self.origin, snapped),
+            index,
+            t,
+            distance: square_distance.sqrt(),
+        }
+    }
+
+    /// Heading in degrees of the segment `index` in the range [0.0, 360.0), see [`PlaneProjection::heading()`].
+    pub(crate) fn segment_heading(&self, index: usize) -> f32 {
+        let a = self.points[index];
+        let b = self.points.get(index + 1).copied().unwrap_or(a);
+        let dx = (a.0 - b.0) as f32;
+        let dy = (b.1 - a.1) as f32;
+        180.0 - dy.atan2(dx).to_degrees()
+    }
+
+    /// Updates `best` (index, t, square distance) with the segments from the chunk.
+    fn search_chunk(&self, chunk: usize, p: (f64, f64), best: &mut (usize, f64, f64)) {
+        let start = chunk * SEGMENTS_PER_CHUNK;
+        let end = (start + SEGMENTS_PER_CHUNK).min(self.segment_boxes.len());
+        for index in start..end {
+            if self.segment_boxes[index].square_distance(p) > best.2 {
+                continue;
+            }
+            let a = self.points[index];
+            let b = self.points.get(index + 1).copied().unwrap_or(a);
+            let (t, square_distance) =
+                project_to_origin_segment((p.0 - a.0, p.1 - a.1), (b.0 - a.0, b.1 - a.1));
+            // tie-break by index to always pick the first segment
+            if square_distance < best.2 || (square_distance == best.2 && index < best.0) {
+                *best = (index, t, square_distance);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BBox;
+    use crate::tests::{LUND_C, MALMO_C, pseudo_random_points};
+
+    #[test]
+    fn nearest_segment_test() {
+        let proj = PlaneProjection::new(55.65);
+        let line = ProjectedPolyline::new(&proj, &[MALMO_C]);
+        let nearest = line.nearest_segment(LUND_C);
+        assert_eq!((nearest.point, nearest.index, nearest.t), (MALMO_C, 0, 0.0));
+        assert!((nearest.distance - proj.distance(MALMO_C, LUND_C)).abs() < 1e-6);
+
+        // compare with the full scan on a long zigzag polyline
+        let points = (0..1000)
+            .map(|i| {
+                let i = i as f64;
+                (55.6 + i * 0.001, 13.0 + (i * 0.3).sin() * 0.01)
+            })
+            .collect::<Vec<_>>();
+        let line = ProjectedPolyline::new(&proj, &points);
+        let area = BBox {
+            min: (55.5, 12.9),
+            max: (56.7, 13.1),
+        };
+        for point in pseudo_random_points(200, area) {
+            let expected = proj.point_on_line(point, &points);
+            let nearest = line.nearest_segment(point);
+            // segments can be equally close at the shared vertex up to rounding errors
+            if nearest.index != expected.index {
+                assert_eq!(nearest.index.abs_diff(expected.index), 1);
+                let vertex = points[nearest.index.max(expected.index)];
+                assert!(proj.distance(nearest.point, vertex) < 1e-6);
+            } else {
+                assert!((nearest.t - expected.t).abs() < 1e-9);
+            }
+            assert!((nearest.distance - expected.distance).abs() < 1e-6);
+            assert!(proj.distance(nearest.point, expected.point) < 1e-6);
+        }
+
+        // the vertex between segments belongs to the first one
+        let nearest = line.nearest_segment(points[100]);
+        assert_eq!((nearest.index, nearest.t, nearest.distance), (99, 1.0, 0.0));
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let line = ProjectedPolyline::new(&proj, &[(0.0, 179.0), (0.0, -179.0)]);
+        let nearest = line.nearest_segment((0.1, 180.0));
+        assert!((nearest.t - 0.5).abs() < 1e-9);
+        assert!((nearest.point.1.abs() - 180.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::PlaneProjection;
+
+/// A coordinate in single-precision (latitude, longitude) format.
+pub type LatLonF32 = (f32, f32);
+
+/// Single-precision variant of [`PlaneProjection`] for memory-constrained and SIMD-heavy workloads,
+/// which halves the memory bandwidth per point. Since `f32` has about 7 significant digits,
+/// coordinates are represented with roughly 1 meter precision.
+///
+/// Only the generic part of the API is available in `f32`:
+/// - construction with [`PlaneProjection::new()`], [`PlaneProjection::with_ellipsoid()`],
+///   [`PlaneProjection::cast()`] or `From<PlaneProjection>`, and the scale and latitude accessors;
+/// - [`PlaneProjection::project()`] and [`PlaneProjection::unproject()`];
+/// - point distances and headings, such as [`PlaneProjection::distance()`], [`PlaneProjection::is_within()`],
+///   [`PlaneProjection::heading()`] and [`PlaneProjection::distance_in()`];
+/// - segment queries, such as [`PlaneProjection::distance_to_segment()`] and
+///   [`PlaneProjection::project_to_segment()`];
+/// - [`PlaneProjection::interpolate()`], [`PlaneProjection::midpoint()`] and [`PlaneProjection::offset()`];
+/// - batch functions such as [`PlaneProjection::distances()`], [`PlaneProjection::headings()`]
+///   and [`PlaneProjection::project_slice()`];
+/// - [`PlaneProjection::polyline_length()`] and [`PlaneProjection::cumulative_distances()`].
+///
+/// Everything else, including the other constructors, [`PlaneProjection::destination()`], polygons,
+/// segment intersections, nearest neighbour searches and polyline matching or simplification,
+/// is defined for the default `f64` projection only.
+///
+/// ```
+/// use plane_projection::PlaneProjectionF32;
+///
+/// let proj = PlaneProjectionF32::new(55.65);
+/// let distance = proj.distance((55.70414, 13.1913), (55.60331, 13.00197));
+/// assert_eq!(distance as u32, 16373);
+/// ```
+pub type PlaneProjectionF32 = PlaneProjection<f32>;
+
+impl From<PlaneProjection> for PlaneProjectionF32 {
+    fn from(proj: PlaneProjection) -> Self {
+        proj.cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LatLon;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn f32_point(point: LatLon) -> LatLonF32 {
+        (point.0 as f32, point.1 as f32)
+    }
+
+    #[test]
+    fn projection_f32_test() {
+        let proj = PlaneProjection::new(55.65);
+        let proj_f32 = PlaneProjection::new(55.65).cast::<f32>();
+        let (malmo, lund, stockholm) = (
+            f32_point(MALMO_C),
+            f32_point(LUND_C),
+            f32_point(STOCKHOLM_C),
+        );
+
+        assert!(
+            (proj_f32.distance(malmo, lund) as f64 - proj.distance(MALMO_C, LUND_C)).abs() < 1.0
+        );
+        assert!(proj_f32.is_within(malmo, lund, 16400.0));
+        assert!(!proj_f32.is_within(malmo, lund, 16300.0));
+        assert!(
+            (proj_f32.heading(malmo, stockholm) - proj.heading(MALMO_C, STOCKHOLM_C)).abs() < 0.01
+        );
+        assert!(
+            (proj_f32.distance_to_segment(stockholm, (malmo, lund)) as f64
+                - proj.distance_to_segment(STOCKHOLM_C, (MALMO_C, LUND_C)))
+            .abs()
+                < 10.0
+        );
+        assert_eq!(proj_f32.distance_to_segment(malmo, (malmo, lund)), 0.0);
+        assert_eq!(
+            proj_f32.polyline_length(&[malmo, lund, malmo]),
+            2.0 * proj_f32.distance(malmo, lund)
+        );
+
+        let unprojected = proj_f32.unproject(proj_f32.project(lund));
+        assert!((unprojected.0 - lund.0).abs() < 1e-5 && (unprojected.1 - lund.1).abs() < 1e-5);
+
+        let points = (0..37)
+            .map(|i| (55.0 + i as f32 * 0.05, 12.0 + i as f32 * 0.1))
+            .collect::<Vec<_>>();
+        let mut out = vec![0.0; points.len()];
+        proj_f32.distances(malmo, &points, &mut out);
+        for (&point, &distance) in points.iter().zip(&out) {
+            assert!((distance - proj_f32.distance(malmo, point)).abs() < 1e-3);
+        }
+
+        // across the antimeridian
+        let proj_f32 = PlaneProjectionF32::from(PlaneProjection::new(0.0));
+        assert_eq!(
+            proj_f32.distance((0.0, 179.5), (0.0, -179.5)),
+            proj_f32.distance((0.0, -0.5), (0.0, 0.5))
+        );
+        assert_eq!(proj_f32.heading((0.0, 179.5), (0.0, -179.5)), 90.0);
+        let mut out = [0.0; 9];
+        proj_f32.distances((0.0, 179.5), &[(0.0, -179.5); 9], &mut out);
+        assert!(out.iter().all(|&distance| distance == out[8]));
+    }
+}
@@ -0,0 +1,85 @@
+//! [PyO3](https://pyo3.rs) bindings, built as a Python extension module with
+//! [maturin](https://www.maturin.rs). Points are `(lat, lon)` tuples and batches are numpy arrays,
+//! which are read without copying.
+//!
+//! ```python
+//! import numpy as np
+//! from plane_projection import PlaneProjection
+//!
+//! proj = PlaneProjection(55.65)
+//! distance = proj.distance((55.7041417, 13.1913041), (55.6033090, 13.0019737))
+//! distances = proj.distances((55.7041417, 13.1913041), np.array([55.60, 55.61]), np.array([13.00, 13.01]))
+//! ```
+
+use crate::LatLon;
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Python wrapper of [`crate::PlaneProjection`].
+#[pyclass(name = "PlaneProjection", module = "plane_projection", frozen)]
+pub struct PyPlaneProjection(crate::PlaneProjection);
+
+#[pymethods]
+impl PyPlaneProjection {
+    /// Creates a plane projection to the Earth at provided latitude, see [`crate::PlaneProjection::new()`].
+    #[new]
+    pub fn new(latitude: f64) -> Self {
+        Self(crate::PlaneProjection::new(latitude))
+    }
+
+    /// Distance in meters between two points, see [`crate::PlaneProjection::distance()`].
+    pub fn distance(&self, a: LatLon, b: LatLon) -> f64 {
+        self.0.distance(a, b)
+    }
+
+    /// Heading in degrees from `a` to `b`, see [`crate::PlaneProjection::heading()`].
+    pub fn heading(&self, a: LatLon, b: LatLon) -> f32 {
+        self.0.heading(a, b)
+    }
+
+    /// Distances in meters from the `origin` to every point given in separate `lats` and `lons` arrays,
+    /// see [`crate::PlaneProjection::distances_columnar()`]. Raises `ValueError` if the arrays have
+    /// different lengths or are not contiguous.
+    pub fn distances<'py>(
+        &self,
+        py: Python<'py>,
+        origin: LatLon,
+        lats: PyReadonlyArray1<'py, f64>,
+        lons: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let (lats, lons) = (lats.as_slice()?, lons.as_slice()?);
+        if lats.len() != lons.len() {
+            return Err(PyValueError::new_err("`lats` and `lons` lengths differ"));
+        }
+        let mut out = vec![0.0; lats.len()];
+        self.0.distances_columnar(origin, lats, lons, &mut out);
+        Ok(PyArray1::from_vec(py, out))
+    }
+}
+
+/// Python module with the [`PyPlaneProjection`] class.
+#[pymodule]
+fn plane_projection(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyPlaneProjection>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn python_test() {
+        let proj = PyPlaneProjection::new(55.65);
+        let expected = crate::PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.distance(MALMO_C, LUND_C),
+            expected.distance(MALMO_C, LUND_C)
+        );
+        assert_eq!(
+            proj.heading(MALMO_C, LUND_C),
+            expected.heading(MALMO_C, LUND_C)
+        );
+    }
+}
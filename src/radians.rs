@@ -0,0 +1,147 @@
+use crate::{Ellipsoid, PlaneProjection};
+#[cfg(not(feature = "std"))]
+use crate::{Float as _, math::Float as _};
+use core::f64::consts::{PI, TAU};
+
+/// Degrees in one radian, which turns meters per degree into meters per radian.
+const DEGREES_PER_RADIAN: f64 = 180.0 / PI;
+
+/// Variants of the projection methods for points in (lat, lon) format in radians, for data that is already
+/// in radians, so there is no need to convert every coordinate to degrees and back.
+///
+/// ```
+/// use plane_projection::PlaneProjection;
+///
+/// let (a, b) = ((55.7041417f64, 13.1913041f64), (55.6033090f64, 13.0019737f64));
+/// let (a_rad, b_rad) = ((a.0.to_radians(), a.1.to_radians()), (b.0.to_radians(), b.1.to_radians()));
+///
+/// let proj = PlaneProjection::new_rad(55.65f64.to_radians());
+/// assert_eq!(proj.distance_rad(a_rad, b_rad) as u32, 16373);
+/// assert_eq!(proj.heading_rad(a_rad, b_rad).to_degrees() as u32, 226);
+/// ```
+impl PlaneProjection {
+    /// Creates a plane projection to the Earth at provided latitude in radians, see [`PlaneProjection::new()`].
+    pub fn new_rad(latitude: f64) -> Self {
+        let cos_lat = (latitude as f32).cos() as f64;
+        Self::from_cos_lat(latitude.to_degrees(), cos_lat, Ellipsoid::WGS84)
+    }
+
+    /// Square distance in meters between two points in (lat, lon) format in radians.
+    #[inline(always)]
+    pub fn square_distance_rad(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        let lat_dist = (a.0 - b.0) * self.lat_scale * DEGREES_PER_RADIAN;
+        let lon_dist = lon_diff_rad(a.1, b.1) * self.lon_scale * DEGREES_PER_RADIAN;
+        lat_dist * lat_dist + lon_dist * lon_dist
+    }
+
+    /// Distance in meters between two points in (lat, lon) format in radians.
+    #[inline(always)]
+    pub fn distance_rad(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        self.square_distance_rad(a, b).sqrt()
+    }
+
+    /// Heading in radians from point `a` to point `b` in (lat, lon) format in radians, in the range [0.0, 2 * PI)
+    /// measured clockwise from North, see [`PlaneProjection::heading()`].
+    #[inline(always)]
+    pub fn heading_rad(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        let dx = (a.0 - b.0) * self.lat_scale;
+        let dy = lon_diff_rad(b.1, a.1) * self.lon_scale;
+        PI - dy.atan2(dx)
+    }
+
+    /// Destination point in (lat, lon) format in radians, reached by moving `distance` meters from `origin`
+    /// in radians along the `heading` in radians, see [`PlaneProjection::destination()`].
+    #[inline(always)]
+    pub fn destination_rad(&self, origin: (f64, f64), heading: f64, distance: f64) -> (f64, f64) {
+        let (sin, cos) = heading.sin_cos();
+        (
+            origin.0 + distance * cos / (self.lat_scale * DEGREES_PER_RADIAN),
+            wrap_lon_rad(origin.1 + distance * sin / (self.lon_scale * DEGREES_PER_RADIAN)),
+        )
+    }
+}
+
+/// Returns the difference between two longitudes in range [-PI, PI] radians.
+#[inline(always)]
+fn lon_diff_rad(a: f64, b: f64) -> f64 {
+    wrap_lon_rad(a - b)
+}
+
+/// Wraps longitude into range [-PI, PI] radians.
+#[inline(always)]
+fn wrap_lon_rad(lon: f64) -> f64 {
+    if lon > PI {
+        lon - TAU
+    } else if lon < -PI {
+        lon + TAU
+    } else {
+        lon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LatLon;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    fn to_radians(point: LatLon) -> (f64, f64) {
+        (point.0.to_radians(), point.1.to_radians())
+    }
+
+    #[test]
+    fn new_rad_test() {
+        let proj = PlaneProjection::new_rad(55.65f64.to_radians());
+        assert!((proj.latitude() - 55.65).abs() < 1e-12);
+        assert!((proj.lat_scale() - PlaneProjection::new(55.65).lat_scale()).abs() < 1e-6);
+        assert!((proj.lon_scale() - PlaneProjection::new(55.65).lon_scale()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn distance_rad_test() {
+        let proj = PlaneProjection::new(55.65);
+        for (a, b) in [(MALMO_C, LUND_C), (LUND_C, STOCKHOLM_C), (MALMO_C, MALMO_C)] {
+            let distance = proj.distance_rad(to_radians(a), to_radians(b));
+            assert!((distance - proj.distance(a, b)).abs() < 1e-6);
+        }
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let (a, b) = ((0.0, 179.5), (0.0, -179.5));
+        assert!(
+            (proj.distance_rad(to_radians(a), to_radians(b)) - proj.distance(a, b)).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn heading_rad_test() {
+        let proj = PlaneProjection::new(55.65);
+        for (a, b) in [(MALMO_C, LUND_C), (LUND_C, MALMO_C), (LUND_C, STOCKHOLM_C)] {
+            let heading = proj.heading_rad(to_radians(a), to_radians(b));
+            assert!((heading.to_degrees() - proj.heading_f64(a, b)).abs() < 1e-9);
+            assert!((0.0..TAU).contains(&heading));
+        }
+        let north = proj.heading_rad(to_radians(MALMO_C), to_radians((56.0, MALMO_C.1)));
+        assert_eq!(north, 0.0);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let east = proj.heading_rad(to_radians((0.0, 179.5)), to_radians((0.0, -179.5)));
+        assert!((east - PI / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn destination_rad_test() {
+        let proj = PlaneProjection::new(55.65);
+        let heading = proj.heading_rad(to_radians(MALMO_C), to_radians(LUND_C));
+        let distance = proj.distance(MALMO_C, LUND_C);
+        let destination = proj.destination_rad(to_radians(MALMO_C), heading, distance);
+        assert!((destination.0 - LUND_C.0.to_radians()).abs() < 1e-12);
+        assert!((destination.1 - LUND_C.1.to_radians()).abs() < 1e-12);
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let destination = proj.destination_rad(to_radians((0.0, 179.9)), PI / 2.0, 20_000.0);
+        assert!(destination.1 < -PI + 0.01);
+    }
+}
@@ -0,0 +1,117 @@
+//! [`rstar`] integration, so an [`rstar::RTree`] answers nearest neighbor queries with
+//! plane projection distances instead of euclidean distances between degrees.
+//!
+//! ```
+//! use plane_projection::PlaneProjection;
+//! use rstar::RTree;
+//!
+//! let proj = PlaneProjection::new(55.65);
+//! let tree = RTree::bulk_load(vec![
+//!     proj.rtree_point((55.6033090, 13.0019737), "Malmö"),
+//!     proj.rtree_point((55.7041417, 13.1913041), "Lund"),
+//! ]);
+//! let nearest = tree.nearest_neighbor(&proj.rtree_query((55.68, 13.15))).unwrap();
+//! assert_eq!(nearest.data, "Lund");
+//! ```
+
+use crate::{LatLon, PlaneProjection};
+use rstar::{AABB, PointDistance, RTreeObject};
+
+/// A point with attached `data` stored in the plane projection space,
+/// created with [`PlaneProjection::rtree_point()`].
+///
+/// Squared distances reported by the tree are in square meters. Since points are projected with
+/// [`PlaneProjection::project()`], the indexed area should not span the antimeridian.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RTreePoint<T = ()> {
+    /// Original (latitude, longitude) coordinate.
+    pub point: LatLon,
+    /// User data attached to the point.
+    pub data: T,
+    projected: [f64; 2],
+}
+
+impl<T> RTreeObject for RTreePoint<T> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point(self.projected)
+    }
+}
+
+impl<T> PointDistance for RTreePoint<T> {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let d0 = self.projected[0] - point[0];
+        let d1 = self.projected[1] - point[1];
+        d0 * d0 + d1 * d1
+    }
+}
+
+impl PlaneProjection {
+    /// Creates a point with attached `data` to be stored in [`rstar::RTree`].
+    pub fn rtree_point<T>(&self, point: LatLon, data: T) -> RTreePoint<T> {
+        let projected = self.project(point);
+        RTreePoint {
+            point,
+            data,
+            projected: [projected.0, projected.1],
+        }
+    }
+
+    /// Converts a point to the query point for [`rstar::RTree`] of [`RTreePoint`]s.
+    #[inline(always)]
+    pub fn rtree_query(&self, point: LatLon) -> [f64; 2] {
+        let projected = self.project(point);
+        [projected.0, projected.1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+    use rstar::RTree;
+
+    #[test]
+    fn rtree_test() {
+        let proj = PlaneProjection::new(55.65);
+        let tree = RTree::bulk_load(
+            [MALMO_C, LUND_C, STOCKHOLM_C]
+                .into_iter()
+                .enumerate()
+                .map(|(i, point)| proj.rtree_point(point, i))
+                .collect(),
+        );
+        let nearest = tree
+            .nearest_neighbor(&proj.rtree_query((55.65, 13.1)))
+            .unwrap();
+        assert_eq!(nearest.data, 0);
+
+        let query = proj.rtree_query(MALMO_C);
+        let within = tree
+            .locate_within_distance(query, 20_000.0 * 20_000.0)
+            .map(|point| point.data)
+            .collect::<Vec<_>>();
+        assert_eq!(within.len(), 2);
+        let distance = tree
+            .nearest_neighbor_iter_with_distance_2(&query)
+            .nth(1)
+            .unwrap()
+            .1;
+        assert!((distance.sqrt() - proj.distance(MALMO_C, LUND_C)).abs() < 1e-6);
+
+        // At high latitudes degrees of longitude are much shorter than degrees of latitude,
+        // so the nearest point in degrees is not the nearest one in meters
+        let proj = PlaneProjection::new(70.0);
+        let points = [(70.0, 10.5), (70.3, 10.0)];
+        let tree = RTree::bulk_load(
+            points
+                .iter()
+                .map(|&point| proj.rtree_point(point, ()))
+                .collect(),
+        );
+        let query = (70.0, 10.0);
+        let nearest = tree.nearest_neighbor(&proj.rtree_query(query)).unwrap();
+        assert_eq!(nearest.point, points[0]);
+    }
+}
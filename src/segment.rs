@@ -0,0 +1,353 @@
+#[cfg(not(feature = "std"))]
+use crate::Float as _;
+use crate::predicates::{orient2d, within_box};
+use crate::{DegenerateGeometry, DegeneratePolicy, LatLon, PlaneProjection, cross, dot};
+
+impl PlaneProjection {
+    /// Intersection point of two segments, if any.
+    /// For overlapping collinear segments the overlap point closest to the start of `a` is returned.
+    /// Whether the segments intersect is decided exactly for the projected points, so nearly-collinear
+    /// and touching segments give consistent results, and zero-length segments are treated as points.
+    pub fn segments_intersect(&self, a: (LatLon, LatLon), b: (LatLon, LatLon)) -> Option<LatLon> {
+        // Transform to local Cartesian coordinates with `a` segment start as origin
+        let origin = (0.0, 0.0);
+        let r = self.project_relative(a.0, a.1);
+        let q = self.project_relative(a.0, b.0);
+        let q_end = self.project_relative(a.0, b.1);
+
+        // Both ends of each segment must not be strictly on the same side of the other one
+        let (b_start_side, b_end_side) = (orient2d(origin, r, q), orient2d(origin, r, q_end));
+        let (a_start_side, a_end_side) = (orient2d(q, q_end, origin), orient2d(q, q_end, r));
+        if b_start_side * b_end_side > 0.0 || a_start_side * a_end_side > 0.0 {
+            return None;
+        }
+
+        let r_square_length = dot(r, r);
+        let t = if b_start_side == 0.0 && b_end_side == 0.0 {
+            if r_square_length == 0.0 {
+                // `a` is a point, so it either lies on `b` or not
+                return within_box(origin, (q, q_end)).then_some(a.0);
+            }
+            // collinear, so check if `b` projection over `a` overlaps with the [0.0, 1.0] range
+            let t0 = dot(q, r) / r_square_length;
+            let t1 = dot(q_end, r) / r_square_length;
+            let (start, end) = (t0.min(t1), t0.max(t1));
+            if start > 1.0 || end < 0.0 {
+                return None;
+            }
+            start.max(0.0)
+        } else if a_start_side == 0.0 {
+            0.0
+        } else if a_end_side == 0.0 {
+            1.0
+        } else if b_start_side == 0.0 {
+            dot(q, r) / r_square_length
+        } else if b_end_side == 0.0 {
+            dot(q_end, r) / r_square_length
+        } else {
+            let s = (q_end.0 - q.0, q_end.1 - q.1);
+            cross(q, s) / cross(r, s)
+        };
+        Some(self.interpolate(a.0, a.1, t.clamp(0.0, 1.0)))
+    }
+
+    /// Same as [`PlaneProjection::segments_intersect()`], but zero-length segments are handled according
+    /// to the `policy`, so [`DegeneratePolicy::Reject`] reports the index of the zero-length segment,
+    /// 0 for `a` and 1 for `b`.
+    ///
+    /// ```
+    /// use plane_projection::{DegenerateGeometry, DegeneratePolicy, PlaneProjection};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let (a, point) = (((55.6, 13.0), (55.6, 13.2)), ((55.6, 13.1), (55.6, 13.1)));
+    /// assert_eq!(proj.segments_intersect_with(a, point, DegeneratePolicy::AsPoint), Ok(Some((55.6, 13.1))));
+    /// assert_eq!(proj.segments_intersect_with(a, point, DegeneratePolicy::Ignore), Ok(None));
+    /// assert_eq!(
+    ///     proj.segments_intersect_with(a, point, DegeneratePolicy::Reject),
+    ///     Err(DegenerateGeometry { index: 1 })
+    /// );
+    /// ```
+    pub fn segments_intersect_with(
+        &self,
+        a: (LatLon, LatLon),
+        b: (LatLon, LatLon),
+        policy: DegeneratePolicy,
+    ) -> Result<Option<LatLon>, DegenerateGeometry> {
+        let zero_length = [a, b]
+            .iter()
+            .position(|segment| self.project_relative(segment.0, segment.1) == (0.0, 0.0));
+        match (zero_length, policy) {
+            (Some(index), DegeneratePolicy::Reject) => Err(DegenerateGeometry { index }),
+            (Some(_), DegeneratePolicy::Ignore) => Ok(None),
+            _ => Ok(self.segments_intersect(a, b)),
+        }
+    }
+
+    /// Square minimum distance in meters between two segments, which is 0.0 if they intersect.
+    pub fn square_distance_between_segments(
+        &self,
+        a: (LatLon, LatLon),
+        b: (LatLon, LatLon),
+    ) -> f64 {
+        if self.segments_intersect(a, b).is_some() {
+            return 0.0;
+        }
+        // Otherwise the closest points pair always includes one of the segment ends
+        self.square_distance_to_segment(a.0, b)
+            .min(self.square_distance_to_segment(a.1, b))
+            .min(self.square_distance_to_segment(b.0, a))
+            .min(self.square_distance_to_segment(b.1, a))
+    }
+
+    /// Minimum distance in meters between two segments, which is 0.0 if they intersect.
+    #[inline(always)]
+    pub fn distance_between_segments(&self, a: (LatLon, LatLon), b: (LatLon, LatLon)) -> f64 {
+        self.square_distance_between_segments(a, b).sqrt()
+    }
+
+    /// Signed distance in meters from `point` to the line through the `segment`, positive when the point
+    /// is to the right of the path and negative when to the left. Unlike [`PlaneProjection::distance_to_segment()`]
+    /// the line is not limited by the segment ends, so this is the lateral deviation from the route.
+    /// For a zero-length segment this is the distance to the segment point.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(0.0);
+    /// // heading North, so East is to the right
+    /// let segment = ((0.0, 0.0), (1.0, 0.0));
+    /// assert!(proj.cross_track_distance((0.5, 0.001), segment) > 0.0);
+    /// assert!(proj.cross_track_distance((0.5, -0.001), segment) < 0.0);
+    /// ```
+    pub fn cross_track_distance(&self, point: LatLon, segment: (LatLon, LatLon)) -> f64 {
+        let r = self.project_relative(segment.0, segment.1);
+        let q = self.project_relative(segment.0, point);
+        let length = dot(r, r).sqrt();
+        if length == 0.0 {
+            return dot(q, q).sqrt();
+        }
+        cross(r, q) / length
+    }
+
+    /// Signed distance in meters from the `segment` start to the projection of `point` onto the line through
+    /// the `segment`, which is the progress along the path. It is negative before the segment start and exceeds
+    /// the segment length past its end. For a zero-length segment this is 0.0.
+    pub fn along_track_distance(&self, point: LatLon, segment: (LatLon, LatLon)) -> f64 {
+        let r = self.project_relative(segment.0, segment.1);
+        let q = self.project_relative(segment.0, point);
+        let length = dot(r, r).sqrt();
+        if length == 0.0 {
+            return 0.0;
+        }
+        dot(r, q) / length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn segments_intersect_with_test() {
+        let proj = PlaneProjection::new(0.0);
+        let (a, b) = (((-1.0, 0.0), (1.0, 0.0)), ((0.0, -1.0), (0.0, 1.0)));
+        let point = ((0.0, 0.5), (0.0, 0.5));
+        for policy in [
+            DegeneratePolicy::AsPoint,
+            DegeneratePolicy::Ignore,
+            DegeneratePolicy::Reject,
+        ] {
+            assert_eq!(
+                proj.segments_intersect_with(a, b, policy),
+                Ok(Some((0.0, 0.0)))
+            );
+        }
+        assert_eq!(
+            proj.segments_intersect_with(point, b, DegeneratePolicy::default()),
+            Ok(Some((0.0, 0.5)))
+        );
+        assert_eq!(
+            proj.segments_intersect_with(point, a, DegeneratePolicy::AsPoint),
+            Ok(None)
+        );
+        assert_eq!(
+            proj.segments_intersect_with(point, b, DegeneratePolicy::Ignore),
+            Ok(None)
+        );
+        assert_eq!(
+            proj.segments_intersect_with(point, a, DegeneratePolicy::Reject),
+            Err(DegenerateGeometry { index: 0 })
+        );
+        assert_eq!(
+            proj.segments_intersect_with(b, point, DegeneratePolicy::Reject),
+            Err(DegenerateGeometry { index: 1 })
+        );
+    }
+
+    #[test]
+    fn segments_intersect_test() {
+        let proj = PlaneProjection::new(0.0);
+        assert_eq!(
+            proj.segments_intersect(((-1.0, 0.0), (1.0, 0.0)), ((0.0, -1.0), (0.0, 1.0))),
+            Some((0.0, 0.0))
+        );
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (1.0, 1.0)), ((1.0, 1.0), (2.0, 0.0))),
+            Some((1.0, 1.0))
+        );
+        // T-shape, touching
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (0.0, 2.0)), ((0.0, 1.0), (1.0, 1.0))),
+            Some((0.0, 1.0))
+        );
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (0.0, 2.0)), ((0.1, 1.0), (1.0, 1.0))),
+            None
+        );
+        // parallel
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (0.0, 2.0)), ((1.0, 0.0), (1.0, 2.0))),
+            None
+        );
+        // collinear
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (0.0, 2.0)), ((0.0, 3.0), (0.0, 4.0))),
+            None
+        );
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (0.0, 2.0)), ((0.0, 3.0), (0.0, 1.0))),
+            Some((0.0, 1.0))
+        );
+        assert_eq!(
+            proj.segments_intersect(((0.0, 1.0), (0.0, 2.0)), ((0.0, 0.0), (0.0, 3.0))),
+            Some((0.0, 1.0))
+        );
+        // zero-length segments
+        assert_eq!(
+            proj.segments_intersect(((0.0, 1.0), (0.0, 1.0)), ((0.0, 0.0), (0.0, 3.0))),
+            Some((0.0, 1.0))
+        );
+        assert_eq!(
+            proj.segments_intersect(((0.0, 0.0), (0.0, 3.0)), ((0.0, 1.0), (0.0, 1.0))),
+            Some((0.0, 1.0))
+        );
+        assert_eq!(
+            proj.segments_intersect(((1.0, 1.0), (1.0, 1.0)), ((0.0, 0.0), (0.0, 3.0))),
+            None
+        );
+
+        // across the antimeridian
+        let point = proj
+            .segments_intersect(
+                ((-1.0, 179.0), (1.0, -179.0)),
+                ((1.0, 179.0), (-1.0, -179.0)),
+            )
+            .unwrap();
+        assert!(point.0.abs() < 1e-12 && (point.1.abs() - 180.0).abs() < 1e-12);
+
+        let proj = PlaneProjection::new(55.65);
+        let point = proj
+            .segments_intersect((MALMO_C, LUND_C), ((55.6, 13.2), (55.7, 13.0)))
+            .unwrap();
+        assert!(proj.distance_to_segment(point, (MALMO_C, LUND_C)) < 1e-6);
+        assert!(proj.distance_to_segment(point, ((55.6, 13.2), (55.7, 13.0))) < 1e-6);
+    }
+
+    #[test]
+    fn distance_between_segments_test() {
+        let proj = PlaneProjection::new(0.0);
+        // crossing
+        assert_eq!(
+            proj.distance_between_segments(((-1.0, 0.0), (1.0, 0.0)), ((0.0, -1.0), (0.0, 1.0))),
+            0.0
+        );
+        // parallel
+        assert_eq!(
+            proj.distance_between_segments(((0.0, 0.0), (0.0, 2.0)), ((1.0, 1.0), (1.0, 3.0))),
+            proj.distance((0.0, 0.0), (1.0, 0.0))
+        );
+        // collinear
+        assert_eq!(
+            proj.distance_between_segments(((0.0, 0.0), (0.0, 1.0)), ((0.0, 3.0), (0.0, 2.0))),
+            proj.distance((0.0, 1.0), (0.0, 2.0))
+        );
+        // T-shape, not touching
+        assert_eq!(
+            proj.distance_between_segments(((0.0, 0.0), (0.0, 2.0)), ((1.0, 1.0), (3.0, 1.0))),
+            proj.distance((0.0, 1.0), (1.0, 1.0))
+        );
+        // zero-length segments
+        assert_eq!(
+            proj.distance_between_segments(((1.0, 1.0), (1.0, 1.0)), ((0.0, 0.0), (0.0, 3.0))),
+            proj.distance((1.0, 1.0), (0.0, 1.0))
+        );
+        assert_eq!(
+            proj.distance_between_segments(((1.0, 1.0), (1.0, 1.0)), ((0.0, 0.0), (0.0, 0.0))),
+            proj.distance((1.0, 1.0), (0.0, 0.0))
+        );
+
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.distance_between_segments(
+                (MALMO_C, LUND_C),
+                ((55.67817981392954, 13.058789566271836), (55.7, 12.9))
+            ) as u32,
+            3615
+        );
+    }
+
+    #[test]
+    fn cross_track_distance_test() {
+        let proj = PlaneProjection::new(0.0);
+        let segment = ((0.0, 0.0), (1.0, 0.0));
+        let offset = proj.distance((0.0, 0.0), (0.0, 0.01));
+        assert!((proj.cross_track_distance((0.5, 0.01), segment) - offset).abs() < 1e-6);
+        assert!((proj.cross_track_distance((0.5, -0.01), segment) + offset).abs() < 1e-6);
+        // not limited by the segment ends
+        assert!((proj.cross_track_distance((2.0, 0.01), segment) - offset).abs() < 1e-6);
+        assert!((proj.cross_track_distance((-1.0, -0.01), segment) + offset).abs() < 1e-6);
+        assert_eq!(proj.cross_track_distance((0.5, 0.0), segment), 0.0);
+        // reversed segment flips the sign
+        assert!(
+            (proj.cross_track_distance((0.5, 0.01), (segment.1, segment.0)) + offset).abs() < 1e-6
+        );
+        // zero-length segment
+        assert_eq!(
+            proj.cross_track_distance((0.0, 0.01), ((0.0, 0.0), (0.0, 0.0))),
+            offset
+        );
+        // across the antimeridian, heading East with North on the left
+        assert!(proj.cross_track_distance((0.01, 180.0), ((0.0, 179.0), (0.0, -179.0))) < 0.0);
+
+        let proj = PlaneProjection::new(55.65);
+        let point = (55.67817981392954, 13.058789566271836);
+        assert_eq!(
+            proj.cross_track_distance(point, (MALMO_C, LUND_C)) as i32,
+            -3615
+        );
+        assert_eq!(
+            proj.cross_track_distance(point, (LUND_C, MALMO_C)) as i32,
+            3615
+        );
+    }
+
+    #[test]
+    fn along_track_distance_test() {
+        let proj = PlaneProjection::new(0.0);
+        let segment = ((0.0, 0.0), (1.0, 0.0));
+        let length = proj.distance(segment.0, segment.1);
+        assert_eq!(proj.along_track_distance((0.0, 0.01), segment), 0.0);
+        assert!((proj.along_track_distance((0.5, 0.01), segment) - length * 0.5).abs() < 1e-6);
+        assert!((proj.along_track_distance((2.0, -0.01), segment) - length * 2.0).abs() < 1e-6);
+        assert!((proj.along_track_distance((-0.5, 0.0), segment) + length * 0.5).abs() < 1e-6);
+        assert_eq!(
+            proj.along_track_distance((0.0, 0.01), ((0.0, 0.0), (0.0, 0.0))),
+            0.0
+        );
+
+        let proj = PlaneProjection::new(55.65);
+        let along = proj.along_track_distance(LUND_C, (MALMO_C, LUND_C));
+        assert!((along - proj.distance(MALMO_C, LUND_C)).abs() < 1e-6);
+    }
+}
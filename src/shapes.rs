@@ -0,0 +1,229 @@
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{LatLon, PlaneProjection, normalize_heading};
+use alloc::vec::Vec;
+
+impl PlaneProjection {
+    /// Polygon ring with `segments` vertices approximating the circle of `radius` meters around the `center`,
+    /// which stays round on the map at any latitude, unlike circles built in degrees. The ring starts at the North,
+    /// goes counterclockwise and is not closed, i.e. the first vertex is not repeated at the end.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(60.0);
+    /// let circle = proj.circle((60.0, 10.0), 1000.0, 64);
+    /// assert_eq!(circle.len(), 64);
+    /// assert!(circle.iter().all(|&point| (proj.distance((60.0, 10.0), point) - 1000.0).abs() < 1e-6));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is less than 3.
+    pub fn circle(&self, center: LatLon, radius: f64, segments: usize) -> Vec<LatLon> {
+        assert!(segments >= 3, "`segments` must be at least 3");
+        (0..segments)
+            .map(|i| self.destination(center, -360.0 * i as f64 / segments as f64, radius))
+            .collect()
+    }
+
+    /// Polyline with `segments + 1` points approximating the arc of `radius` meters around the `center`,
+    /// going clockwise from `start_heading` to `end_heading` in degrees, like a radar sweep.
+    /// Equal headings give a full circle with the first point repeated at the end.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let arc = proj.arc((55.65, 13.0), 1000.0, 350.0, 10.0, 8);
+    /// assert_eq!(arc.len(), 9);
+    /// assert!((proj.heading((55.65, 13.0), arc[4]) as f64 - 0.0).abs() < 1e-3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is 0.
+    pub fn arc(
+        &self,
+        center: LatLon,
+        radius: f64,
+        start_heading: f64,
+        end_heading: f64,
+        segments: usize,
+    ) -> Vec<LatLon> {
+        assert!(segments > 0, "`segments` must be positive");
+        let mut sweep = normalize_heading(end_heading - start_heading);
+        if sweep == 0.0 {
+            sweep = 360.0;
+        }
+        (0..=segments)
+            .map(|i| {
+                let heading = start_heading + sweep * i as f64 / segments as f64;
+                self.destination(center, heading, radius)
+            })
+            .collect()
+    }
+
+    /// Polygon ring of the pie sector of `radius` meters around the `center` between `start_heading` and
+    /// `end_heading` in degrees going clockwise, like an antenna coverage. The ring consists of the `center`
+    /// followed by the [`PlaneProjection::arc()`] in reverse, so it goes counterclockwise like
+    /// [`PlaneProjection::circle()`] and is not closed.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let sector = proj.sector((55.65, 13.0), 1000.0, 0.0, 90.0, 16);
+    /// assert_eq!(sector.len(), 18);
+    /// assert!(proj.contains(&sector, proj.offset((55.65, 13.0), 500.0, 500.0)));
+    /// assert!(!proj.contains(&sector, proj.offset((55.65, 13.0), -500.0, 500.0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is 0.
+    pub fn sector(
+        &self,
+        center: LatLon,
+        radius: f64,
+        start_heading: f64,
+        end_heading: f64,
+        segments: usize,
+    ) -> Vec<LatLon> {
+        let mut ring = Vec::with_capacity(segments + 2);
+        ring.push(center);
+        ring.extend(
+            self.arc(center, radius, start_heading, end_heading, segments)
+                .into_iter()
+                .rev(),
+        );
+        ring
+    }
+
+    /// Polygon ring with `segments` vertices approximating the ellipse around the `center` with semi-axes in meters,
+    /// where the major one is directed along the `orientation` heading in degrees, for example a position
+    /// uncertainty ellipse. The ring starts at the end of the major semi-axis, goes counterclockwise
+    /// and is not closed.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let ellipse = proj.ellipse((55.65, 13.0), 200.0, 50.0, 45.0, 32);
+    /// assert!(proj.contains(&ellipse, proj.offset((55.65, 13.0), 100.0, 100.0)));
+    /// assert!(!proj.contains(&ellipse, proj.offset((55.65, 13.0), -100.0, 100.0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is less than 3.
+    pub fn ellipse(
+        &self,
+        center: LatLon,
+        semi_major: f64,
+        semi_minor: f64,
+        orientation: f64,
+        segments: usize,
+    ) -> Vec<LatLon> {
+        assert!(segments >= 3, "`segments` must be at least 3");
+        let (sin, cos) = orientation.to_radians().sin_cos();
+        (0..segments)
+            .map(|i| {
+                let (sin_t, cos_t) =
+                    (core::f64::consts::TAU * i as f64 / segments as f64).sin_cos();
+                let (major, minor) = (semi_major * cos_t, semi_minor * sin_t);
+                // The minor axis points to the left of the major one, so the ring goes counterclockwise
+                let north = major * cos + minor * sin;
+                let east = major * sin - minor * cos;
+                self.offset(center, east, north)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, STOCKHOLM_C};
+
+    #[test]
+    fn circle_test() {
+        let proj = PlaneProjection::new(55.65);
+        let circle = proj.circle(LUND_C, 100.0, 4);
+        let expected = [
+            proj.offset(LUND_C, 0.0, 100.0),
+            proj.offset(LUND_C, -100.0, 0.0),
+            proj.offset(LUND_C, 0.0, -100.0),
+            proj.offset(LUND_C, 100.0, 0.0),
+        ];
+        for (point, expected) in circle.iter().zip(expected) {
+            assert!(proj.distance(*point, expected) < 1e-9);
+        }
+
+        // a regular polygon with the same area in square meters and the same number of vertices everywhere
+        let proj = PlaneProjection::new(STOCKHOLM_C.0);
+        let circle = proj.circle(STOCKHOLM_C, 1000.0, 360);
+        assert_eq!(circle.len(), 360);
+        let expected = 0.5 * 360.0 * 1000.0 * 1000.0 * (core::f64::consts::TAU / 360.0).sin();
+        assert!((proj.area(&circle) - expected).abs() < 1e-3);
+        assert!(proj.contains(&circle, proj.offset(STOCKHOLM_C, 990.0, 0.0)));
+        assert!(!proj.contains(&circle, proj.offset(STOCKHOLM_C, 1010.0, 0.0)));
+
+        // across the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let circle = proj.circle((0.0, 180.0), 1000.0, 16);
+        assert!(circle.iter().all(|point| point.1.abs() > 179.99));
+    }
+
+    #[test]
+    fn arc_test() {
+        let proj = PlaneProjection::new(55.65);
+        let arc = proj.arc(LUND_C, 100.0, 0.0, 90.0, 2);
+        let expected = [
+            proj.destination(LUND_C, 0.0, 100.0),
+            proj.destination(LUND_C, 45.0, 100.0),
+            proj.destination(LUND_C, 90.0, 100.0),
+        ];
+        assert_eq!(arc, expected);
+
+        // clockwise over the North and a full circle
+        let arc = proj.arc(LUND_C, 100.0, 270.0, -270.0, 2);
+        assert!(proj.distance(arc[1], proj.destination(LUND_C, 0.0, 100.0)) < 1e-9);
+        let arc = proj.arc(LUND_C, 100.0, 10.0, 10.0, 4);
+        assert_eq!(arc.len(), 5);
+        assert!(proj.distance(arc[0], arc[4]) < 1e-9);
+        assert!(proj.distance(arc[2], proj.destination(LUND_C, 190.0, 100.0)) < 1e-9);
+    }
+
+    #[test]
+    fn sector_test() {
+        let proj = PlaneProjection::new(55.65);
+        let sector = proj.sector(LUND_C, 1000.0, 45.0, 135.0, 32);
+        assert_eq!(sector.len(), 34);
+        assert_eq!(sector[0], LUND_C);
+        // a quarter of the circle area
+        let expected = core::f64::consts::PI * 1000.0 * 1000.0 / 4.0;
+        assert!((proj.area(&sector) - expected).abs() / expected < 1e-3);
+        assert!(proj.contains(&sector, proj.destination(LUND_C, 90.0, 900.0)));
+        assert!(!proj.contains(&sector, proj.destination(LUND_C, 0.0, 500.0)));
+        assert!(!proj.contains(&sector, proj.destination(LUND_C, 90.0, 1100.0)));
+    }
+
+    #[test]
+    fn ellipse_test() {
+        let proj = PlaneProjection::new(STOCKHOLM_C.0);
+        let ellipse = proj.ellipse(STOCKHOLM_C, 300.0, 100.0, 90.0, 360);
+        assert_eq!(ellipse.len(), 360);
+        assert!(proj.distance(ellipse[0], proj.offset(STOCKHOLM_C, 300.0, 0.0)) < 1e-9);
+        // counterclockwise, so the next quarter is to the North of the East major axis
+        assert!(proj.distance(ellipse[90], proj.offset(STOCKHOLM_C, 0.0, 100.0)) < 1e-9);
+        let expected = core::f64::consts::PI * 300.0 * 100.0;
+        assert!((proj.area(&ellipse) - expected).abs() / expected < 1e-3);
+
+        // a circle if both semi-axes are equal
+        let ellipse = proj.ellipse(STOCKHOLM_C, 100.0, 100.0, 30.0, 16);
+        for point in &ellipse {
+            assert!((proj.distance(STOCKHOLM_C, *point) - 100.0).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(not(feature = "std"))]
+use crate::Float as _;
+use crate::{LatLon, PlaneProjection};
+use alloc::vec::Vec;
+
+impl PlaneProjection {
+    /// Discrete Fréchet distance in meters between two tracks, i.e. the minimum "leash length"
+    /// required to walk both tracks point by point without going back.
+    /// Returns `None` if any of the tracks is empty.
+    pub fn frechet_distance(&self, a: &[LatLon], b: &[LatLon]) -> Option<f64> {
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+        // Dynamic programming over the coupling matrix, keeping only the previous row
+        let mut prev_row = Vec::with_capacity(b.len());
+        let mut row = Vec::with_capacity(b.len());
+        for (i, &pa) in a.iter().enumerate() {
+            row.clear();
+            for (j, &pb) in b.iter().enumerate() {
+                let square_distance = self.square_distance(pa, pb);
+                let coupling = match (i, j) {
+                    (0, 0) => square_distance,
+                    (0, _) => row[j - 1],
+                    (_, 0) => prev_row[0],
+                    _ => f64::min(f64::min(prev_row[j], prev_row[j - 1]), row[j - 1]),
+                };
+                row.push(square_distance.max(coupling));
+            }
+            core::mem::swap(&mut prev_row, &mut row);
+        }
+        Some(prev_row[b.len() - 1].sqrt())
+    }
+
+    /// Hausdorff distance in meters between two tracks, i.e. the maximum distance
+    /// from a point of one track to the closest point of the other one.
+    /// Returns `None` if any of the tracks is empty.
+    pub fn hausdorff_distance(&self, a: &[LatLon], b: &[LatLon]) -> Option<f64> {
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+        let square_distance = self
+            .directed_square_hausdorff_distance(a, b)
+            .max(self.directed_square_hausdorff_distance(b, a));
+        Some(square_distance.sqrt())
+    }
+
+    /// Square maximum distance from points in `a` to the closest point in `b`.
+    fn directed_square_hausdorff_distance(&self, a: &[LatLon], b: &[LatLon]) -> f64 {
+        let mut max_square_distance = 0.0;
+        for &pa in a {
+            let mut min_square_distance = f64::INFINITY;
+            for &pb in b {
+                min_square_distance = min_square_distance.min(self.square_distance(pa, pb));
+                if min_square_distance <= max_square_distance {
+                    // this point can't increase the maximum anymore
+                    break;
+                }
+            }
+            max_square_distance = min_square_distance.max(max_square_distance);
+        }
+        max_square_distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn frechet_distance_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.frechet_distance(&[], &[MALMO_C]), None);
+        assert_eq!(proj.frechet_distance(&[MALMO_C], &[]), None);
+        assert_eq!(
+            proj.frechet_distance(&[MALMO_C, LUND_C], &[MALMO_C, LUND_C]),
+            Some(0.0)
+        );
+        assert_eq!(
+            proj.frechet_distance(&[MALMO_C], &[LUND_C]),
+            Some(proj.distance(MALMO_C, LUND_C))
+        );
+
+        // the same path with different sampling
+        let origin = (55.65, 13.0);
+        let a = [
+            origin,
+            proj.offset(origin, 100.0, 0.0),
+            proj.offset(origin, 200.0, 0.0),
+        ];
+        let b = [origin, proj.offset(origin, 200.0, 0.0)];
+        assert!((proj.frechet_distance(&a, &b).unwrap() - 100.0).abs() < 1e-6);
+
+        // reversed path is as far from the original one as its length
+        let reversed = [b[1], b[0]];
+        assert!((proj.frechet_distance(&b, &reversed).unwrap() - 200.0).abs() < 1e-6);
+        // while having zero Hausdorff distance
+        assert_eq!(proj.hausdorff_distance(&b, &reversed), Some(0.0));
+
+        // parallel paths
+        let c = [
+            proj.offset(origin, 0.0, 30.0),
+            proj.offset(origin, 100.0, 40.0),
+            proj.offset(origin, 200.0, 30.0),
+        ];
+        assert!((proj.frechet_distance(&a, &c).unwrap() - 40.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hausdorff_distance_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(proj.hausdorff_distance(&[], &[MALMO_C]), None);
+        assert_eq!(proj.hausdorff_distance(&[MALMO_C], &[]), None);
+        assert_eq!(
+            proj.hausdorff_distance(&[MALMO_C, LUND_C], &[LUND_C, MALMO_C]),
+            Some(0.0)
+        );
+
+        let origin = (55.65, 13.0);
+        let a = [
+            origin,
+            proj.offset(origin, 100.0, 0.0),
+            proj.offset(origin, 200.0, 0.0),
+        ];
+        let b = [origin, proj.offset(origin, 200.0, 0.0)];
+        assert!((proj.hausdorff_distance(&a, &b).unwrap() - 100.0).abs() < 1e-6);
+        assert_eq!(
+            proj.hausdorff_distance(&a, &b),
+            proj.hausdorff_distance(&b, &a)
+        );
+
+        let c = [
+            proj.offset(origin, 0.0, 30.0),
+            proj.offset(origin, 100.0, 40.0),
+            proj.offset(origin, 500.0, 30.0),
+        ];
+        let expected = proj.distance(a[2], c[2]);
+        assert!((proj.hausdorff_distance(&a, &c).unwrap() - expected).abs() < 1e-6);
+    }
+}
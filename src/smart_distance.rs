@@ -0,0 +1,132 @@
+use crate::{Ellipsoid, LatLon, PlaneProjection, lon_diff};
+#[cfg(not(feature = "std"))]
+use crate::{Float as _, math::Float as _};
+
+/// Great-circle distance in meters between two points on a sphere with the mean Earth radius,
+/// using the haversine formula. Slower than [`PlaneProjection::distance()`], but with bounded
+/// 0.5% error at any distance. See [`Ellipsoid::SPHERE`].
+pub fn haversine_distance(a: LatLon, b: LatLon) -> f64 {
+    let sin_lat = ((b.0 - a.0).to_radians() * 0.5).sin();
+    let sin_lon = (lon_diff(b.1, a.1).to_radians() * 0.5).sin();
+    let h = sin_lat * sin_lat + a.0.to_radians().cos() * b.0.to_radians().cos() * sin_lon * sin_lon;
+    2.0 * Ellipsoid::SPHERE.equatorial_radius * h.sqrt().min(1.0).asin()
+}
+
+/// Distance calculator that uses the plane projection for local pairs of points and transparently
+/// falls back to [`haversine_distance()`] for the rare pairs that are too far apart or too far
+/// from the projection latitude, where the plane projection error grows to several percents.
+///
+/// ```
+/// use plane_projection::SmartDistance;
+///
+/// let smart = SmartDistance::new(55.65, 100_000.0, 1.0);
+/// // Malmo - Lund uses the plane projection
+/// let distance = smart.distance((55.704141722528554, 13.191304107330561), (55.60330902847681, 13.001973666557435));
+/// assert_eq!(distance as u32, 16373);
+/// // Malmo - Stockholm falls back to haversine, close to the 513_861m geodesic distance
+/// let distance = smart.distance((55.60330902847681, 13.001973666557435), (59.33036105663399, 18.058682977850953));
+/// assert_eq!(distance as u32, 512_716);
+/// ```
+#[derive(Clone)]
+pub struct SmartDistance {
+    projection: PlaneProjection,
+    max_square_distance: f64,
+    max_lat_offset: f64,
+}
+
+impl SmartDistance {
+    /// Creates a calculator with the projection at `latitude`, that falls back to haversine when the distance
+    /// exceeds `max_distance` meters or the pair midpoint is more than `max_lat_offset` degrees away from
+    /// `latitude`. Use [`PlaneProjection::error_estimate()`] to pick thresholds for the acceptable error.
+    pub fn new(latitude: f64, max_distance: f64, max_lat_offset: f64) -> Self {
+        Self {
+            projection: PlaneProjection::new(latitude),
+            max_square_distance: max_distance * max_distance,
+            max_lat_offset,
+        }
+    }
+
+    /// The underlying plane projection.
+    pub fn projection(&self) -> &PlaneProjection {
+        &self.projection
+    }
+
+    /// Checks if the pair of points is within the thresholds the plane projection is used for.
+    #[inline(always)]
+    pub fn is_local(&self, a: LatLon, b: LatLon) -> bool {
+        self.local_square_distance(a, b).is_some()
+    }
+
+    /// Distance in meters between two points, see [`PlaneProjection::distance()`] and [`haversine_distance()`].
+    #[inline(always)]
+    pub fn distance(&self, a: LatLon, b: LatLon) -> f64 {
+        match self.local_square_distance(a, b) {
+            Some(square_distance) => square_distance.sqrt(),
+            None => haversine_distance(a, b),
+        }
+    }
+
+    /// Square distance in meters between two points in the plane projection,
+    /// or `None` if the pair is outside of the thresholds, see [`SmartDistance::is_local()`].
+    #[inline(always)]
+    fn local_square_distance(&self, a: LatLon, b: LatLon) -> Option<f64> {
+        let lat_offset = (a.0 + b.0) * 0.5 - self.projection.latitude();
+        if lat_offset.abs() > self.max_lat_offset {
+            return None;
+        }
+        let square_distance = self.projection.square_distance(a, b);
+        (square_distance <= self.max_square_distance).then_some(square_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C, STOCKHOLM_C};
+
+    #[test]
+    fn haversine_distance_test() {
+        assert_eq!(haversine_distance(MALMO_C, MALMO_C), 0.0);
+        // geodesic distances are 16_373m and 513_861m
+        assert_eq!(haversine_distance(MALMO_C, LUND_C).round() as u32, 16_334);
+        assert_eq!(
+            haversine_distance(MALMO_C, STOCKHOLM_C).round() as u32,
+            512_717
+        );
+        assert_eq!(
+            haversine_distance(LUND_C, MALMO_C),
+            haversine_distance(MALMO_C, LUND_C)
+        );
+
+        // across the antimeridian and between the poles
+        assert_eq!(
+            haversine_distance((0.0, 179.5), (0.0, -179.5)),
+            haversine_distance((0.0, -0.5), (0.0, 0.5))
+        );
+        let half_circumference = Ellipsoid::SPHERE.equatorial_radius * core::f64::consts::PI;
+        assert!((haversine_distance((90.0, 0.0), (-90.0, 0.0)) - half_circumference).abs() < 1e-6);
+        assert!((haversine_distance((0.0, 0.0), (0.0, 180.0)) - half_circumference).abs() < 1e-6);
+    }
+
+    #[test]
+    fn smart_distance_test() {
+        let smart = SmartDistance::new(55.65, 100_000.0, 1.0);
+        assert!(smart.is_local(MALMO_C, LUND_C));
+        assert_eq!(
+            smart.distance(MALMO_C, LUND_C),
+            smart.projection().distance(MALMO_C, LUND_C)
+        );
+
+        // too far
+        assert!(!smart.is_local(MALMO_C, STOCKHOLM_C));
+        assert_eq!(
+            smart.distance(MALMO_C, STOCKHOLM_C),
+            haversine_distance(MALMO_C, STOCKHOLM_C)
+        );
+
+        // close to each other, but too far from the projection latitude
+        let (a, b) = ((57.0, 13.0), (57.01, 13.01));
+        assert!(!smart.is_local(a, b));
+        assert_eq!(smart.distance(a, b), haversine_distance(a, b));
+    }
+}
@@ -0,0 +1,445 @@
+//! Slippy map tile math for XYZ tiles in the Web Mercator projection, as used by OpenStreetMap and most web maps.
+//! Tile (0, 0) is the North-West corner of the map and at zoom `z` there are `2^z` tiles in every direction.
+//!
+//! ```
+//! use plane_projection::tiles::Tile;
+//!
+//! let tile = Tile::from_lat_lon((55.7041417, 13.1913041), 12);
+//! assert_eq!(tile, Tile { x: 2198, y: 1281, zoom: 12 });
+//! assert!(tile.bbox().contains((55.7041417, 13.1913041)));
+//! ```
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float as _;
+use crate::{BBox, LatLon, PlaneProjection, wrap_lon};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// Maximum latitude in degrees covered by the Web Mercator tiles.
+pub const MAX_LATITUDE: f64 = 85.0511287798066;
+
+/// Maximum supported zoom level, so tile columns and rows fit into `u32`.
+pub const MAX_ZOOM: u8 = 31;
+
+/// An XYZ map tile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// Column from West to East in range [0, 2^zoom).
+    pub x: u32,
+    /// Row from North to South in range [0, 2^zoom).
+    pub y: u32,
+    /// Zoom level in range [0, [`MAX_ZOOM`]].
+    pub zoom: u8,
+}
+
+impl Tile {
+    /// The tile containing the point at `zoom` level. Latitudes beyond [`MAX_LATITUDE`] are clamped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zoom` is above [`MAX_ZOOM`].
+    pub fn from_lat_lon(point: LatLon, zoom: u8) -> Self {
+        let (x, y) = tile_position(point, zoom);
+        Self::clamped(x.floor(), y.floor(), zoom)
+    }
+
+    /// Bounding box of the tile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile zoom is above [`MAX_ZOOM`].
+    pub fn bbox(&self) -> BBox {
+        let tiles = tiles_count(self.zoom);
+        let lon = |x: u32| x as f64 / tiles * 360.0 - 180.0;
+        let lat = |y: u32| {
+            let mercator = PI * (1.0 - 2.0 * y as f64 / tiles);
+            (2.0 * mercator.exp().atan() - PI * 0.5).to_degrees()
+        };
+        BBox {
+            min: (lat(self.y + 1), lon(self.x)),
+            max: (lat(self.y), lon(self.x + 1)),
+        }
+    }
+
+    /// Tiles at `zoom` level that intersect the bounding box, including boxes that span the antimeridian.
+    /// Tiles that only touch the East or South edges of the box are skipped, so the covering of a tile bbox
+    /// is the tile itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zoom` is above [`MAX_ZOOM`].
+    pub fn covering(bbox: BBox, zoom: u8) -> Vec<Tile> {
+        let north_west = Tile::from_lat_lon((bbox.max.0, bbox.min.1), zoom);
+        let (x, y) = tile_position((bbox.min.0, bbox.max.1), zoom);
+        let south_east = Tile::clamped(
+            x.ceil() - 1.0,
+            (y.ceil() - 1.0).max(north_west.y as f64),
+            zoom,
+        );
+        // Single column boxes and boxes that end exactly at the West edge of the column
+        let south_east = if bbox.min.1 <= bbox.max.1 || south_east.x == north_west.x {
+            Tile {
+                x: south_east.x.max(north_west.x),
+                ..south_east
+            }
+        } else {
+            south_east
+        };
+        let columns = if north_west.x <= south_east.x {
+            (north_west.x..=south_east.x).chain(0..0)
+        } else {
+            (north_west.x..=(tiles_count(zoom) as u32 - 1)).chain(0..south_east.x + 1)
+        };
+        columns
+            .flat_map(|x| (north_west.y..=south_east.y).map(move |y| Tile { x, y, zoom }))
+            .collect()
+    }
+}
+
+impl Tile {
+    /// [Bing Maps quadkey](https://learn.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system) of the tile,
+    /// with one digit per zoom level, so tiles share the quadkey prefix with their parents.
+    ///
+    /// ```
+    /// use plane_projection::tiles::Tile;
+    ///
+    /// assert_eq!(Tile { x: 3, y: 5, zoom: 3 }.quadkey(), "213");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile zoom is above [`MAX_ZOOM`].
+    pub fn quadkey(&self) -> String {
+        check_zoom(self.zoom);
+        (1..=self.zoom)
+            .rev()
+            .map(|level| {
+                let mask = 1 << (level - 1);
+                let digit = (self.x & mask != 0) as u8 + 2 * (self.y & mask != 0) as u8;
+                (b'0' + digit) as char
+            })
+            .collect()
+    }
+
+    /// Tile of the quadkey, the inverse of [`Tile::quadkey()`].
+    /// Returns `None` if the quadkey has characters other than `0`-`3` or encodes zoom level above [`MAX_ZOOM`].
+    pub fn from_quadkey(quadkey: &str) -> Option<Tile> {
+        if quadkey.len() > MAX_ZOOM as usize {
+            return None;
+        }
+        let (mut x, mut y) = (0, 0);
+        for byte in quadkey.bytes() {
+            let digit = byte.checked_sub(b'0').filter(|&digit| digit < 4)? as u32;
+            x = x << 1 | digit & 1;
+            y = y << 1 | digit >> 1;
+        }
+        Some(Tile {
+            x,
+            y,
+            zoom: quadkey.len() as u8,
+        })
+    }
+
+    /// Tile at the integer position, clamped to the map edges.
+    #[inline(always)]
+    fn clamped(x: f64, y: f64, zoom: u8) -> Self {
+        let max = tiles_count(zoom) - 1.0;
+        Self {
+            x: x.clamp(0.0, max) as u32,
+            y: y.clamp(0.0, max) as u32,
+            zoom,
+        }
+    }
+}
+
+impl PlaneProjection {
+    /// Tiles at `zoom` level that intersect the circle of `radius` meters around the `center`.
+    ///
+    /// ```
+    /// use plane_projection::PlaneProjection;
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let tiles = proj.tiles_within_radius((55.7041417, 13.1913041), 1000.0, 14);
+    /// assert_eq!(tiles.len(), 6);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `zoom` is above [`MAX_ZOOM`].
+    pub fn tiles_within_radius(&self, center: LatLon, radius: f64, zoom: u8) -> Vec<Tile> {
+        let square_radius = radius * radius;
+        let mut tiles = Tile::covering(self.buffer_point(center, radius), zoom);
+        tiles.retain(|tile| self.square_distance_to_bbox(center, tile.bbox()) <= square_radius);
+        tiles
+    }
+}
+
+/// Fractional tile position of the point at `zoom` level. Latitudes beyond [`MAX_LATITUDE`] are clamped.
+#[inline(always)]
+fn tile_position(point: LatLon, zoom: u8) -> (f64, f64) {
+    let tiles = tiles_count(zoom);
+    let x = (wrap_lon(point.1) + 180.0) / 360.0 * tiles;
+    let sin_lat = point
+        .0
+        .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+        .to_radians()
+        .sin();
+    let y = (0.5 - ((1.0 + sin_lat) / (1.0 - sin_lat)).ln() / (4.0 * PI)) * tiles;
+    (x, y)
+}
+
+/// Number of tiles in every direction at `zoom` level.
+#[inline(always)]
+fn tiles_count(zoom: u8) -> f64 {
+    check_zoom(zoom);
+    (1u64 << zoom) as f64
+}
+
+/// Panics if `zoom` is above [`MAX_ZOOM`], consistently for all tile functions.
+#[inline(always)]
+fn check_zoom(zoom: u8) {
+    assert!(zoom <= MAX_ZOOM, "zoom level {zoom} is above {MAX_ZOOM}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn from_lat_lon_test() {
+        assert_eq!(
+            Tile::from_lat_lon(LUND_C, 0),
+            Tile {
+                x: 0,
+                y: 0,
+                zoom: 0
+            }
+        );
+        assert_eq!(
+            Tile::from_lat_lon((0.0, 0.0), 1),
+            Tile {
+                x: 1,
+                y: 1,
+                zoom: 1
+            }
+        );
+        assert_eq!(
+            Tile::from_lat_lon((0.1, -0.1), 1),
+            Tile {
+                x: 0,
+                y: 0,
+                zoom: 1
+            }
+        );
+        // map corners and beyond
+        assert_eq!(
+            Tile::from_lat_lon((90.0, -180.0), 2),
+            Tile {
+                x: 0,
+                y: 0,
+                zoom: 2
+            }
+        );
+        assert_eq!(
+            Tile::from_lat_lon((-90.0, 180.0), 2),
+            Tile {
+                x: 3,
+                y: 3,
+                zoom: 2
+            }
+        );
+        assert_eq!(
+            Tile::from_lat_lon((0.0, 190.0), 2),
+            Tile {
+                x: 0,
+                y: 2,
+                zoom: 2
+            }
+        );
+
+        assert_eq!(
+            Tile::from_lat_lon(MALMO_C, 16),
+            Tile {
+                x: 35134,
+                y: 20535,
+                zoom: 16
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "zoom level 32 is above 31")]
+    fn from_lat_lon_zoom_test() {
+        Tile::from_lat_lon(LUND_C, 32);
+    }
+
+    #[test]
+    fn bbox_test() {
+        let bbox = Tile {
+            x: 0,
+            y: 0,
+            zoom: 0,
+        }
+        .bbox();
+        assert!((bbox.max.0 - MAX_LATITUDE).abs() < 1e-9);
+        assert!((bbox.min.0 + MAX_LATITUDE).abs() < 1e-9);
+        assert_eq!((bbox.min.1, bbox.max.1), (-180.0, 180.0));
+
+        let bbox = Tile {
+            x: 1,
+            y: 1,
+            zoom: 1,
+        }
+        .bbox();
+        assert!(bbox.max.0.abs() < 1e-12);
+        assert_eq!((bbox.min.1, bbox.max.1), (0.0, 180.0));
+
+        // the tile contains the points it's created from
+        for zoom in [5, 10, 15, 20, 25] {
+            for point in [LUND_C, MALMO_C, (-33.86, 151.21), (40.71, -74.01)] {
+                let tile = Tile::from_lat_lon(point, zoom);
+                assert!(tile.bbox().contains(point));
+                assert_eq!(Tile::from_lat_lon(tile.bbox().center(), zoom), tile);
+            }
+        }
+    }
+
+    #[test]
+    fn quadkey_test() {
+        let tile = Tile {
+            x: 0,
+            y: 0,
+            zoom: 0,
+        };
+        assert_eq!(tile.quadkey(), "");
+        assert_eq!(Tile::from_quadkey(""), Some(tile));
+        assert_eq!(
+            Tile {
+                x: 1,
+                y: 0,
+                zoom: 1
+            }
+            .quadkey(),
+            "1"
+        );
+        assert_eq!(
+            Tile {
+                x: 0,
+                y: 1,
+                zoom: 1
+            }
+            .quadkey(),
+            "2"
+        );
+        assert_eq!(
+            Tile {
+                x: 3,
+                y: 5,
+                zoom: 3
+            }
+            .quadkey(),
+            "213"
+        );
+
+        for zoom in [1, 8, 16, 31] {
+            let tile = Tile::from_lat_lon(LUND_C, zoom);
+            let quadkey = tile.quadkey();
+            assert_eq!(quadkey.len(), zoom as usize);
+            assert_eq!(Tile::from_quadkey(&quadkey), Some(tile));
+            // parent tiles are prefixes
+            assert_eq!(
+                Tile::from_lat_lon(LUND_C, zoom - 1).quadkey(),
+                quadkey[..quadkey.len() - 1]
+            );
+        }
+
+        assert_eq!(Tile::from_quadkey("1234"), None);
+        assert_eq!(Tile::from_quadkey("12a"), None);
+        assert_eq!(Tile::from_quadkey(&"0".repeat(32)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "zoom level 40 is above 31")]
+    fn quadkey_zoom_test() {
+        Tile {
+            x: 0,
+            y: 0,
+            zoom: 40,
+        }
+        .quadkey();
+    }
+
+    #[test]
+    fn covering_test() {
+        let tile = Tile::from_lat_lon(LUND_C, 12);
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(
+            Tile::covering(proj.buffer_bbox(tile.bbox(), -1.0), 12),
+            [tile]
+        );
+        assert_eq!(
+            Tile::covering(proj.buffer_bbox(tile.bbox(), 1.0), 12).len(),
+            9
+        );
+        let center = tile.bbox().center();
+        let bbox = BBox {
+            min: center,
+            max: center,
+        };
+        assert_eq!(Tile::covering(bbox, 12), [tile]);
+
+        let bbox = BBox::from_points(&[MALMO_C, LUND_C]).unwrap();
+        let tiles = Tile::covering(bbox, 12);
+        let (min, max) = (
+            Tile::from_lat_lon(MALMO_C, 12),
+            Tile::from_lat_lon(LUND_C, 12),
+        );
+        let expected = (max.x - min.x + 1) * (min.y - max.y + 1);
+        assert_eq!(tiles.len(), expected as usize);
+        assert!(tiles.contains(&min) && tiles.contains(&max));
+
+        // spans the antimeridian
+        let bbox = BBox {
+            min: (-1.0, 179.0),
+            max: (1.0, -179.0),
+        };
+        let tiles = Tile::covering(bbox, 4);
+        assert_eq!(
+            tiles.iter().map(|t| (t.x, t.y)).collect::<Vec<_>>(),
+            [(15, 7), (15, 8), (0, 7), (0, 8)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "zoom level 64 is above 31")]
+    fn covering_zoom_test() {
+        Tile::covering(BBox::from_points(&[MALMO_C, LUND_C]).unwrap(), 64);
+    }
+
+    #[test]
+    fn tiles_within_radius_test() {
+        let proj = PlaneProjection::new(55.65);
+        let tile = Tile::from_lat_lon(LUND_C, 10);
+        assert_eq!(
+            proj.tiles_within_radius(tile.bbox().center(), 10.0, 10),
+            [tile]
+        );
+
+        // corner tiles are skipped if the circle doesn't reach them
+        let bbox = tile.bbox();
+        let corner = proj.offset(bbox.max, -1.0, -1.0);
+        assert_eq!(proj.tiles_within_radius(corner, 0.9, 10), [tile]);
+        assert_eq!(proj.tiles_within_radius(corner, 1.2, 10).len(), 3);
+        assert_eq!(proj.tiles_within_radius(corner, 10.0, 10).len(), 4);
+
+        // spans the antimeridian
+        let proj = PlaneProjection::new(0.0);
+        let tiles = proj.tiles_within_radius((0.5, 180.0), 1000.0, 4);
+        assert_eq!(
+            tiles.iter().map(|t| (t.x, t.y)).collect::<Vec<_>>(),
+            [(15, 7), (0, 7)]
+        );
+    }
+}
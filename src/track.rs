@@ -0,0 +1,455 @@
+#[cfg(not(feature = "std"))]
+use crate::Float as _;
+use crate::{LatLon, PlaneProjection};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A position fix with its timestamp in seconds.
+pub type Fix = (LatLon, f64);
+
+/// Size of the moving average window for [`Track::smooth()`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmoothingWindow {
+    /// Averages up to `n / 2` fixes on each side of every fix.
+    Samples(usize),
+    /// Averages the fixes within half of the given distance in meters along the track on each side of every fix.
+    Meters(f64),
+}
+
+/// A part of the track where the device stayed in place, see [`Track::stops()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stop {
+    /// Range of the fixes that belong to the stop.
+    pub fixes: Range<usize>,
+    /// Average position of the fixes.
+    pub center: LatLon,
+    /// Timestamp of the first fix.
+    pub start: f64,
+    /// Timestamp of the last fix.
+    pub end: f64,
+}
+
+/// A view over timestamped position fixes, like a GPS track, with the speed and course bookkeeping.
+/// Fixes are expected to be sorted by timestamp, legs with non-positive duration have no speed.
+///
+/// ```
+/// use plane_projection::{PlaneProjection, Track};
+///
+/// let proj = PlaneProjection::new(55.65);
+/// let fixes = [((55.60, 13.00), 0.0), ((55.60, 13.01), 60.0), ((55.61, 13.01), 120.0)];
+/// let track = Track::new(&proj, &fixes);
+/// assert_eq!(track.duration(), 120.0);
+/// assert_eq!(track.speeds().map(|v| v.unwrap() as u32).collect::<Vec<_>>(), [10, 18]);
+/// assert_eq!(track.courses().map(|c| c.unwrap() as u32).collect::<Vec<_>>(), [90, 0]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Track<'a> {
+    projection: &'a PlaneProjection,
+    fixes: &'a [Fix],
+}
+
+impl<'a> Track<'a> {
+    /// Creates a track over the fixes.
+    pub fn new(projection: &'a PlaneProjection, fixes: &'a [Fix]) -> Self {
+        Self { projection, fixes }
+    }
+
+    /// The underlying fixes.
+    pub fn fixes(&self) -> &'a [Fix] {
+        self.fixes
+    }
+
+    /// Length in meters of the track.
+    pub fn length(&self) -> f64 {
+        self.fixes
+            .windows(2)
+            .map(|leg| self.projection.distance(leg[0].0, leg[1].0))
+            .sum()
+    }
+
+    /// Time between the first and the last fixes, which is 0.0 for tracks with less than two fixes.
+    pub fn duration(&self) -> f64 {
+        match self.fixes {
+            [first, .., last] => last.1 - first.1,
+            _ => 0.0,
+        }
+    }
+
+    /// Speed in meters per second of every leg between consecutive fixes,
+    /// `None` for legs with non-positive duration.
+    pub fn speeds(&self) -> impl Iterator<Item = Option<f64>> + 'a {
+        let projection = self.projection;
+        self.fixes
+            .windows(2)
+            .map(move |leg| leg_speed(projection, leg[0], leg[1]))
+    }
+
+    /// Course over ground in degrees of every leg between consecutive fixes, see [`PlaneProjection::heading()`].
+    /// `None` for legs without movement, where the course is undefined.
+    pub fn courses(&self) -> impl Iterator<Item = Option<f32>> + 'a {
+        let projection = self.projection;
+        self.fixes
+            .windows(2)
+            .map(move |leg| (leg[0].0 != leg[1].0).then(|| projection.heading(leg[0].0, leg[1].0)))
+    }
+
+    /// Acceleration in meters per second squared between every pair of consecutive legs, with leg speeds
+    /// attributed to the middle of the legs. `None` if the speed of either leg is unknown.
+    pub fn accelerations(&self) -> impl Iterator<Item = Option<f64>> + 'a {
+        let projection = self.projection;
+        self.fixes.windows(3).map(move |legs| {
+            let v0 = leg_speed(projection, legs[0], legs[1])?;
+            let v1 = leg_speed(projection, legs[1], legs[2])?;
+            // Both legs have positive duration, so does the time between their middles
+            Some((v1 - v0) * 2.0 / (legs[2].1 - legs[0].1))
+        })
+    }
+
+    /// Total duration of legs with speed at least `min_speed` meters per second,
+    /// which excludes stops and GPS jitter while standing still.
+    pub fn moving_time(&self, min_speed: f64) -> f64 {
+        self.fixes
+            .windows(2)
+            .filter(|leg| {
+                leg_speed(self.projection, leg[0], leg[1]).is_some_and(|v| v >= min_speed)
+            })
+            .map(|leg| leg[1].1 - leg[0].1)
+            .sum()
+    }
+
+    /// Smooths the track positions with a centered moving average, keeping the timestamps.
+    /// Averaging is done in the projection space, so East-West movement is not distorted at high latitudes
+    /// like it would be with raw degrees. Windows are truncated at the track ends.
+    ///
+    /// ```
+    /// use plane_projection::{PlaneProjection, SmoothingWindow, Track};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let fixes = [((55.60, 13.00), 0.0), ((55.601, 13.01), 1.0), ((55.60, 13.02), 2.0)];
+    /// let smoothed = Track::new(&proj, &fixes).smooth(SmoothingWindow::Samples(3));
+    /// assert_eq!(smoothed[1].0.0.to_string(), "55.60033333333333");
+    /// ```
+    pub fn smooth(&self, window: SmoothingWindow) -> Vec<Fix> {
+        let Some(&(origin, _)) = self.fixes.first() else {
+            return Vec::new();
+        };
+        // Prefix sums of the positions in the local Cartesian coordinates to average any window in O(1)
+        let mut sums = Vec::with_capacity(self.fixes.len() + 1);
+        sums.push((0.0, 0.0));
+        let mut along = Vec::with_capacity(self.fixes.len());
+        let (mut sum, mut prev, mut traveled) = ((0.0, 0.0), (0.0, 0.0), 0.0);
+        for &(point, _) in self.fixes {
+            let p = self.projection.project_relative(origin, point);
+            traveled += ((p.0 - prev.0) * (p.0 - prev.0) + (p.1 - prev.1) * (p.1 - prev.1)).sqrt();
+            along.push(traveled);
+            sum = (sum.0 + p.0, sum.1 + p.1);
+            sums.push(sum);
+            prev = p;
+        }
+
+        let (mut start, mut end) = (0, 0);
+        let last = self.fixes.len() - 1;
+        self.fixes
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, timestamp))| {
+                (start, end) = match window {
+                    SmoothingWindow::Samples(n) => (i.saturating_sub(n / 2), (i + n / 2).min(last)),
+                    SmoothingWindow::Meters(meters) => {
+                        while along[i] - along[start] > meters * 0.5 {
+                            start += 1;
+                        }
+                        end = end.max(i);
+                        while end < last && along[end + 1] - along[i] <= meters * 0.5 {
+                            end += 1;
+                        }
+                        (start, end)
+                    }
+                };
+                let count = (end + 1 - start) as f64;
+                let mean = (
+                    (sums[end + 1].0 - sums[start].0) / count,
+                    (sums[end + 1].1 - sums[start].1) / count,
+                );
+                (self.projection.unproject_relative(origin, mean), timestamp)
+            })
+            .collect()
+    }
+
+    /// Finds stops, where consecutive fixes stay within `radius` meters from the first one of them
+    /// for at least `min_duration` seconds. Stops don't overlap and are sorted by time.
+    ///
+    /// ```
+    /// use plane_projection::{PlaneProjection, Track};
+    ///
+    /// let proj = PlaneProjection::new(55.65);
+    /// let fixes = [
+    ///     ((55.6000, 13.0000), 0.0),
+    ///     ((55.6100, 13.0000), 60.0),
+    ///     ((55.6101, 13.0001), 360.0),
+    ///     ((55.6100, 13.0002), 660.0),
+    ///     ((55.6200, 13.0000), 720.0),
+    /// ];
+    /// let stops = Track::new(&proj, &fixes).stops(50.0, 300.0);
+    /// assert_eq!(stops.len(), 1);
+    /// assert_eq!((stops[0].fixes.clone(), stops[0].end - stops[0].start), (1..4, 600.0));
+    /// ```
+    pub fn stops(&self, radius: f64, min_duration: f64) -> Vec<Stop> {
+        let square_radius = radius * radius;
+        let mut stops = Vec::new();
+        let mut start = 0;
+        while start < self.fixes.len() {
+            let anchor = self.fixes[start].0;
+            let end = self.fixes[start + 1..]
+                .iter()
+                .position(|fix| self.projection.square_distance(anchor, fix.0) > square_radius)
+                .map_or(self.fixes.len(), |offset| start + 1 + offset);
+            let fixes = &self.fixes[start..end];
+            if fixes[fixes.len() - 1].1 - fixes[0].1 < min_duration {
+                start += 1;
+                continue;
+            }
+            let (north, east) = fixes.iter().fold((0.0, 0.0), |sum, fix| {
+                let p = self.projection.project_relative(anchor, fix.0);
+                (sum.0 + p.0, sum.1 + p.1)
+            });
+            let count = fixes.len() as f64;
+            stops.push(Stop {
+                fixes: start..end,
+                center: self.projection.offset(anchor, east / count, north / count),
+                start: fixes[0].1,
+                end: fixes[fixes.len() - 1].1,
+            });
+            start = end;
+        }
+        stops
+    }
+
+    /// Simplifies the track using Douglas-Peucker algorithm with the synchronized Euclidean distance, the distance
+    /// from a fix to the position interpolated by time between the kept fixes. Unlike [`PlaneProjection::simplify()`]
+    /// this keeps fixes where the speed changes, like stops, and not only the geometry.
+    /// The first and the last fixes are always kept.
+    pub fn simplify(&self, tolerance: f64) -> Vec<Fix> {
+        let fixes = self.fixes;
+        if fixes.len() < 3 {
+            return fixes.to_vec();
+        }
+        let square_tolerance = tolerance * tolerance;
+        let mut keep = vec![false; fixes.len()];
+        keep[0] = true;
+        keep[fixes.len() - 1] = true;
+
+        let mut stack = vec![(0, fixes.len() - 1)];
+        while let Some((first, last)) = stack.pop() {
+            let (a, b) = (fixes[first], fixes[last]);
+            let duration = b.1 - a.1;
+            let (mut farthest, mut max_square_distance) = (first, square_tolerance);
+            for (i, &(point, timestamp)) in fixes.iter().enumerate().take(last).skip(first + 1) {
+                let t = if duration > 0.0 {
+                    (timestamp - a.1) / duration
+                } else {
+                    0.0
+                };
+                let synchronized = self.projection.interpolate(a.0, b.0, t);
+                let square_distance = self.projection.square_distance(point, synchronized);
+                if square_distance > max_square_distance {
+                    (farthest, max_square_distance) = (i, square_distance);
+                }
+            }
+            if farthest != first {
+                keep[farthest] = true;
+                stack.push((first, farthest));
+                stack.push((farthest, last));
+            }
+        }
+        fixes
+            .iter()
+            .zip(keep)
+            .filter_map(|(&fix, keep)| keep.then_some(fix))
+            .collect()
+    }
+}
+
+/// Speed in meters per second between two fixes, `None` for non-positive duration.
+#[inline(always)]
+fn leg_speed(projection: &PlaneProjection, a: Fix, b: Fix) -> Option<f64> {
+    let duration = b.1 - a.1;
+    (duration > 0.0).then(|| projection.distance(a.0, b.0) / duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn track_test() {
+        let proj = PlaneProjection::new(55.65);
+        let track = Track::new(&proj, &[]);
+        assert_eq!((track.length(), track.duration()), (0.0, 0.0));
+        assert_eq!(track.speeds().count(), 0);
+        assert_eq!(track.moving_time(0.0), 0.0);
+
+        let fixes = [
+            (MALMO_C, 0.0),
+            (LUND_C, 1000.0),
+            (LUND_C, 1100.0),
+            (LUND_C, 1100.0),
+            (MALMO_C, 2100.0),
+            (proj.offset(MALMO_C, 1000.0, 0.0), 2200.0),
+        ];
+        let track = Track::new(&proj, &fixes);
+        let distance = proj.distance(MALMO_C, LUND_C);
+        assert!((track.length() - 2.0 * distance - 1000.0).abs() < 1e-6);
+        assert_eq!(track.duration(), 2200.0);
+
+        let speeds = track.speeds().collect::<Vec<_>>();
+        assert_eq!(speeds.len(), 5);
+        assert_eq!(speeds[0], Some(distance / 1000.0));
+        assert_eq!(speeds[1], Some(0.0));
+        assert_eq!(speeds[2], None);
+        assert_eq!(speeds[3], Some(distance / 1000.0));
+        assert!((speeds[4].unwrap() - 10.0).abs() < 1e-9);
+
+        let courses = track.courses().collect::<Vec<_>>();
+        assert_eq!(courses[0].map(|c| c as u32), Some(46));
+        assert_eq!((courses[1], courses[2]), (None, None));
+        assert_eq!(courses[3].map(|c| c as u32), Some(180 + 46));
+        assert_eq!(courses[4], Some(90.0));
+
+        let accelerations = track.accelerations().collect::<Vec<_>>();
+        assert_eq!(accelerations.len(), 4);
+        assert_eq!(accelerations[0], Some(-speeds[0].unwrap() * 2.0 / 1100.0));
+        assert_eq!((accelerations[1], accelerations[2]), (None, None));
+        let expected = (speeds[4].unwrap() - speeds[3].unwrap()) * 2.0 / 1100.0;
+        assert!((accelerations[3].unwrap() - expected).abs() < 1e-12);
+
+        assert_eq!(track.moving_time(0.0), 2200.0);
+        assert_eq!(track.moving_time(1.0), 2100.0);
+        assert_eq!(track.moving_time(100.0), 0.0);
+    }
+
+    #[test]
+    fn smooth_test() {
+        let proj = PlaneProjection::new(55.65);
+        let track = Track::new(&proj, &[]);
+        assert_eq!(track.smooth(SmoothingWindow::Samples(5)), []);
+
+        // zigzag along the East direction smooths to a straight line
+        let fixes = (0..10)
+            .map(|i| {
+                let north = if i % 2 == 0 { 10.0 } else { -10.0 };
+                (proj.offset(MALMO_C, 100.0 * i as f64, north), i as f64)
+            })
+            .collect::<Vec<_>>();
+        let track = Track::new(&proj, &fixes);
+        assert_eq!(track.smooth(SmoothingWindow::Samples(0)), fixes);
+        assert_eq!(track.smooth(SmoothingWindow::Samples(1)), fixes);
+        assert_eq!(track.smooth(SmoothingWindow::Meters(0.0)), fixes);
+        let smoothed = track.smooth(SmoothingWindow::Samples(2));
+        assert_eq!(smoothed.len(), fixes.len());
+        for (i, (point, timestamp)) in smoothed.iter().enumerate().skip(1).take(8) {
+            assert_eq!(*timestamp, i as f64);
+            // (10 - 10 + 10) / 3 or (-10 + 10 - 10) / 3 meters off the straight line
+            let expected = proj.offset(MALMO_C, 100.0 * i as f64, 0.0);
+            assert!((proj.distance(*point, expected) - 10.0 / 3.0).abs() < 1e-6);
+            assert!((point.1 - expected.1).abs() < 1e-9);
+        }
+        // truncated at the ends
+        let expected = proj.offset(MALMO_C, 50.0, 0.0);
+        assert!(proj.distance(smoothed[0].0, expected) < 1e-6);
+
+        // the same window in meters, as legs are a bit longer than 100 meters
+        let by_meters = track.smooth(SmoothingWindow::Meters(250.0));
+        for (a, b) in by_meters.iter().zip(&smoothed) {
+            assert!(proj.distance(a.0, b.0) < 1e-6);
+        }
+    }
+
+    #[test]
+    fn stops_test() {
+        let proj = PlaneProjection::new(55.65);
+        assert_eq!(Track::new(&proj, &[]).stops(50.0, 60.0), []);
+        let single = [(MALMO_C, 0.0)];
+        assert_eq!(Track::new(&proj, &single).stops(50.0, 60.0), []);
+        assert_eq!(
+            Track::new(&proj, &single).stops(50.0, 0.0),
+            [Stop {
+                fixes: 0..1,
+                center: MALMO_C,
+                start: 0.0,
+                end: 0.0
+            }]
+        );
+
+        // drive, stop with GPS jitter, drive, stop at the end
+        let fixes = [
+            (proj.offset(MALMO_C, -1000.0, 0.0), 0.0),
+            (MALMO_C, 100.0),
+            (proj.offset(MALMO_C, 10.0, 0.0), 200.0),
+            (proj.offset(MALMO_C, -10.0, 5.0), 300.0),
+            (proj.offset(MALMO_C, 0.0, -5.0), 400.0),
+            (proj.offset(MALMO_C, 500.0, 0.0), 450.0),
+            (LUND_C, 1000.0),
+            (proj.offset(LUND_C, 0.0, 20.0), 1100.0),
+            (LUND_C, 1200.0),
+        ];
+        let track = Track::new(&proj, &fixes);
+        let stops = track.stops(30.0, 200.0);
+        assert_eq!(stops.len(), 2);
+        assert_eq!(
+            (stops[0].fixes.clone(), stops[0].start, stops[0].end),
+            (1..5, 100.0, 400.0)
+        );
+        assert!(proj.distance(stops[0].center, MALMO_C) < 1e-6);
+        assert_eq!(
+            (stops[1].fixes.clone(), stops[1].start, stops[1].end),
+            (6..9, 1000.0, 1200.0)
+        );
+        let expected = proj.offset(LUND_C, 0.0, 20.0 / 3.0);
+        assert!(proj.distance(stops[1].center, expected) < 1e-6);
+
+        // too short stops are ignored
+        assert_eq!(track.stops(30.0, 301.0), []);
+        // and large radius merges everything
+        assert_eq!(track.stops(100_000.0, 0.0).len(), 1);
+    }
+
+    #[test]
+    fn simplify_test() {
+        let proj = PlaneProjection::new(55.65);
+        let fixes = [(MALMO_C, 0.0), (LUND_C, 1000.0)];
+        assert_eq!(Track::new(&proj, &fixes).simplify(10.0), fixes);
+
+        // constant speed along a straight line collapses to the ends
+        let at = |east: f64, timestamp: f64| (proj.offset(MALMO_C, east, 0.0), timestamp);
+        let fixes = (0..10)
+            .map(|i| at(100.0 * i as f64, 10.0 * i as f64))
+            .collect::<Vec<_>>();
+        let track = Track::new(&proj, &fixes);
+        assert_eq!(track.simplify(1.0), [fixes[0], fixes[9]]);
+
+        // the same geometry with a stop in the middle keeps the stop
+        let fixes = [
+            at(0.0, 0.0),
+            at(100.0, 10.0),
+            at(200.0, 20.0),
+            at(200.0, 300.0),
+            at(300.0, 310.0),
+            at(400.0, 320.0),
+        ];
+        let track = Track::new(&proj, &fixes);
+        assert_eq!(proj.simplify(&fixes.map(|f| f.0), 1.0).len(), 2);
+        assert_eq!(
+            track.simplify(1.0),
+            [fixes[0], fixes[2], fixes[3], fixes[5]]
+        );
+        assert_eq!(track.simplify(1000.0), [fixes[0], fixes[5]]);
+
+        // zero-duration span compares against the first fix
+        let fixes = [at(0.0, 0.0), at(100.0, 0.0), at(0.0, 0.0)];
+        assert_eq!(Track::new(&proj, &fixes).simplify(10.0), fixes);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Coordinate, Float, PlaneProjection};
+
+/// Length units for converting distances from meters, which all the projection methods use.
+///
+/// ```
+/// use plane_projection::{LengthUnit, PlaneProjection};
+///
+/// let proj: PlaneProjection = PlaneProjection::new(55.65);
+/// let distance = proj.distance_in((55.7041417, 13.1913041), (55.6033090, 13.0019737), LengthUnit::NauticalMiles);
+/// assert_eq!((distance * 100.0).round() / 100.0, 8.84);
+///
+/// let heading = proj.heading_f64((55.7041417, 13.1913041), (55.6033090, 13.0019737));
+/// let destination = proj.destination((55.7041417, 13.1913041), heading, LengthUnit::NauticalMiles.to_meters(8.84));
+/// assert!(proj.distance(destination, (55.6033090, 13.0019737)) < 10.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// Meters, returned by all the projection methods.
+    Meters,
+    /// Kilometers, 1000 meters.
+    Kilometers,
+    /// International statute mile, 1609.344 meters.
+    Miles,
+    /// International nautical mile, 1852 meters.
+    NauticalMiles,
+    /// International foot, 0.3048 meters.
+    Feet,
+}
+
+impl LengthUnit {
+    /// Length of the unit in meters.
+    #[inline(always)]
+    pub const fn meters(self) -> f64 {
+        match self {
+            Self::Meters => 1.0,
+            Self::Kilometers => 1000.0,
+            Self::Miles => 1609.344,
+            Self::NauticalMiles => 1852.0,
+            Self::Feet => 0.3048,
+        }
+    }
+
+    /// Converts `meters` to this unit.
+    #[inline(always)]
+    pub fn from_meters<T: Float>(self, meters: T) -> T {
+        meters / T::from_f64(self.meters())
+    }
+
+    /// Converts `value` in this unit to meters, for example to pass it to [`PlaneProjection::destination()`].
+    #[inline(always)]
+    pub fn to_meters<T: Float>(self, value: T) -> T {
+        value * T::from_f64(self.meters())
+    }
+}
+
+impl<T: Float> PlaneProjection<T> {
+    /// Distance between two points in the given `unit`, see [`PlaneProjection::distance()`].
+    #[inline(always)]
+    pub fn distance_in(&self, a: impl Coordinate<T>, b: impl Coordinate<T>, unit: LengthUnit) -> T {
+        unit.from_meters(self.distance(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn length_unit_test() {
+        assert_eq!(LengthUnit::Kilometers.from_meters(1500.0), 1.5);
+        assert_eq!(LengthUnit::NauticalMiles.from_meters(3704.0), 2.0);
+        assert_eq!(LengthUnit::Miles.to_meters(2.0), 3218.688);
+        assert_eq!(LengthUnit::Kilometers.to_meters(1.5f32), 1500.0);
+        assert!((LengthUnit::Feet.from_meters(LengthUnit::Miles.meters()) - 5280.0).abs() < 1e-9);
+
+        let proj = PlaneProjection::new(55.65);
+        let distance = proj.distance(MALMO_C, LUND_C);
+        assert_eq!(
+            proj.distance_in(MALMO_C, LUND_C, LengthUnit::Meters),
+            distance
+        );
+        assert_eq!(
+            proj.distance_in(MALMO_C, LUND_C, LengthUnit::Kilometers),
+            distance / 1000.0
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! [`wasm_bindgen`](mod@wasm_bindgen) bindings, so web front-ends get bit-identical results with the Rust code.
+//! Coordinates are passed as separate latitude and longitude numbers, polylines as flat
+//! `[lat0, lon0, lat1, lon1, ...]` arrays, which map to `Float64Array` in JavaScript.
+//!
+//! ```js
+//! import { PlaneProjection } from "plane-projection";
+//!
+//! const proj = new PlaneProjection(55.65);
+//! const distance = proj.distance(55.7041417, 13.1913041, 55.6033090, 13.0019737);
+//! ```
+
+use crate::LatLon;
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// JavaScript wrapper of [`crate::PlaneProjection`].
+#[wasm_bindgen(js_name = PlaneProjection)]
+pub struct WasmPlaneProjection(crate::PlaneProjection);
+
+/// JavaScript version of [`crate::PointOnLine`].
+#[wasm_bindgen(js_name = PointOnLine)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WasmPointOnLine {
+    /// Latitude of the closest point on the polyline.
+    pub lat: f64,
+    /// Longitude of the closest point on the polyline.
+    pub lon: f64,
+    /// Index of the segment the closest point belongs to.
+    pub index: usize,
+    /// Position of the closest point on the segment, where 0.0=start, 1.0=end of segment.
+    pub t: f64,
+    /// Distance in meters from the original point to the closest one.
+    pub distance: f64,
+}
+
+#[wasm_bindgen(js_class = PlaneProjection)]
+impl WasmPlaneProjection {
+    /// Creates a plane projection to the Earth at provided latitude, see [`crate::PlaneProjection::new()`].
+    #[wasm_bindgen(constructor)]
+    pub fn new(latitude: f64) -> Self {
+        Self(crate::PlaneProjection::new(latitude))
+    }
+
+    /// Distance in meters between two points, see [`crate::PlaneProjection::distance()`].
+    pub fn distance(&self, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+        self.0.distance((lat1, lon1), (lat2, lon2))
+    }
+
+    /// Heading in degrees from the first point to the second, see [`crate::PlaneProjection::heading()`].
+    pub fn heading(&self, lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f32 {
+        self.0.heading((lat1, lon1), (lat2, lon2))
+    }
+
+    /// Point `distance` meters away at `heading` degrees as `[lat, lon]`,
+    /// see [`crate::PlaneProjection::destination()`].
+    pub fn destination(&self, lat: f64, lon: f64, heading: f64, distance: f64) -> Vec<f64> {
+        let (lat, lon) = self.0.destination((lat, lon), heading, distance);
+        alloc::vec![lat, lon]
+    }
+
+    /// The closest location on the flat `[lat0, lon0, lat1, lon1, ...]` polyline,
+    /// see [`crate::PlaneProjection::point_on_line()`]. Returns `undefined` if the polyline is empty.
+    #[wasm_bindgen(js_name = pointOnLine)]
+    pub fn point_on_line(&self, lat: f64, lon: f64, line: &[f64]) -> Option<WasmPointOnLine> {
+        let line = line
+            .chunks_exact(2)
+            .map(|point| (point[0], point[1]))
+            .collect::<Vec<LatLon>>();
+        if line.is_empty() {
+            return None;
+        }
+        let point_on_line = self.0.point_on_line((lat, lon), &line);
+        Some(WasmPointOnLine {
+            lat: point_on_line.point.0,
+            lon: point_on_line.point.1,
+            index: point_on_line.index,
+            t: point_on_line.t,
+            distance: point_on_line.distance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LUND_C, MALMO_C};
+
+    #[test]
+    fn wasm_test() {
+        let proj = WasmPlaneProjection::new(55.65);
+        let expected = crate::PlaneProjection::new(55.65);
+        assert_eq!(
+            proj.distance(MALMO_C.0, MALMO_C.1, LUND_C.0, LUND_C.1),
+            expected.distance(MALMO_C, LUND_C)
+        );
+        assert_eq!(
+            proj.heading(MALMO_C.0, MALMO_C.1, LUND_C.0, LUND_C.1),
+            expected.heading(MALMO_C, LUND_C)
+        );
+        let destination = expected.destination(MALMO_C, 45.0, 1000.0);
+        assert_eq!(
+            proj.destination(MALMO_C.0, MALMO_C.1, 45.0, 1000.0),
+            [destination.0, destination.1]
+        );
+
+        let point = (55.67817981392954, 13.058789566271836);
+        let line = [MALMO_C.0, MALMO_C.1, LUND_C.0, LUND_C.1];
+        let expected = expected.point_on_line(point, &[MALMO_C, LUND_C]);
+        assert_eq!(
+            proj.point_on_line(point.0, point.1, &line),
+            Some(WasmPointOnLine {
+                lat: expected.point.0,
+                lon: expected.point.1,
+                index: expected.index,
+                t: expected.t,
+                distance: expected.distance,
+            })
+        );
+        assert_eq!(proj.point_on_line(point.0, point.1, &[]), None);
+        // a dangling coordinate is ignored
+        assert_eq!(proj.point_on_line(point.0, point.1, &[MALMO_C.0]), None);
+    }
+}